@@ -0,0 +1,103 @@
+-- full-text index over bookmark attributes; rows share their rowid with the
+-- bookmark they index, and are kept in sync by the triggers below
+
+CREATE VIRTUAL TABLE IF NOT EXISTS bookmarks_fts USING fts5 (
+    uri,
+    title,
+    tags,
+    notes,
+    prefix = '2 3'
+);
+
+-- rank results by bm25, weighing matches on titles and tags over the rest
+INSERT INTO bookmarks_fts (bookmarks_fts, rank) VALUES ('rank', 'bm25(2.0, 10.0, 5.0, 1.0)');
+
+INSERT INTO bookmarks_fts (rowid, uri, title, tags, notes)
+SELECT
+    b.id,
+    b.uri,
+    b.title,
+    (
+        SELECT GROUP_CONCAT(t.name, ' ')
+        FROM tags t
+        JOIN bookmark_tags bt ON t.id = bt.tag_id
+        WHERE bt.bookmark_id = b.id
+    ),
+    b.notes
+FROM
+    bookmarks b;
+
+-- keep uri, title, and notes in sync with bookmarks
+CREATE TRIGGER IF NOT EXISTS bookmarks_fts_after_bookmark_insert
+AFTER INSERT ON bookmarks
+BEGIN
+    INSERT INTO bookmarks_fts (rowid, uri, title, tags, notes)
+    VALUES (new.id, new.uri, new.title, NULL, new.notes);
+END;
+
+CREATE TRIGGER IF NOT EXISTS bookmarks_fts_after_bookmark_update
+AFTER UPDATE OF uri, title, notes ON bookmarks
+BEGIN
+    UPDATE bookmarks_fts
+    SET uri = new.uri, title = new.title, notes = new.notes
+    WHERE rowid = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS bookmarks_fts_after_bookmark_delete
+AFTER DELETE ON bookmarks
+BEGIN
+    DELETE FROM bookmarks_fts WHERE rowid = old.id;
+END;
+
+-- keep tags in sync with the tags attached to bookmarks, and with renamed tags
+CREATE TRIGGER IF NOT EXISTS bookmarks_fts_after_bookmark_tag_insert
+AFTER INSERT ON bookmark_tags
+BEGIN
+    UPDATE bookmarks_fts
+    SET tags = (
+        SELECT GROUP_CONCAT(t.name, ' ')
+        FROM tags t
+        JOIN bookmark_tags bt ON t.id = bt.tag_id
+        WHERE bt.bookmark_id = new.bookmark_id
+    )
+    WHERE rowid = new.bookmark_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS bookmarks_fts_after_bookmark_tag_update
+AFTER UPDATE ON bookmark_tags
+BEGIN
+    UPDATE bookmarks_fts
+    SET tags = (
+        SELECT GROUP_CONCAT(t.name, ' ')
+        FROM tags t
+        JOIN bookmark_tags bt ON t.id = bt.tag_id
+        WHERE bt.bookmark_id = bookmarks_fts.rowid
+    )
+    WHERE rowid IN (old.bookmark_id, new.bookmark_id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS bookmarks_fts_after_bookmark_tag_delete
+AFTER DELETE ON bookmark_tags
+BEGIN
+    UPDATE bookmarks_fts
+    SET tags = (
+        SELECT GROUP_CONCAT(t.name, ' ')
+        FROM tags t
+        JOIN bookmark_tags bt ON t.id = bt.tag_id
+        WHERE bt.bookmark_id = old.bookmark_id
+    )
+    WHERE rowid = old.bookmark_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS bookmarks_fts_after_tag_rename
+AFTER UPDATE OF name ON tags
+BEGIN
+    UPDATE bookmarks_fts
+    SET tags = (
+        SELECT GROUP_CONCAT(t.name, ' ')
+        FROM tags t
+        JOIN bookmark_tags bt ON t.id = bt.tag_id
+        WHERE bt.bookmark_id = bookmarks_fts.rowid
+    )
+    WHERE rowid IN (SELECT bookmark_id FROM bookmark_tags WHERE tag_id = new.id);
+END;
@@ -0,0 +1,4 @@
+CREATE TABLE tag_aliases (
+    alias TEXT PRIMARY KEY NOT NULL,
+    tag TEXT NOT NULL
+);
@@ -1,6 +1,9 @@
 use crate::common::IMPORT_FILE_FORMATS;
+use crate::domain::{format_timestamp, parse_time_bound};
+use crate::persistence::{SortField, SortOrder};
+use clap::builder::PossibleValuesParser;
 use clap::{Parser, Subcommand, ValueEnum};
-use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 const NOT_PROVIDED: &str = "<not provided>";
 const LONG_ABOUT: &str = include_str!("static/long-about.txt");
@@ -25,9 +28,12 @@ pub enum BmmCommand {
     /// Import bookmarks from various sources
     #[command(long_about = IMPORT_HELP.trim())]
     Import {
-        #[arg(value_name = "FILE", value_parser=validate_import_file)]
-        #[arg(help = format!("File to import from; the file's extension will be used to infer file format; supported formats: {:?}", IMPORT_FILE_FORMATS))]
+        #[arg(value_name = "FILE")]
+        #[arg(help = format!("File to import from (\"-\" to read from stdin, which needs --format); unless --format is provided, the file's extension will be used to infer file format; supported formats: {:?}", IMPORT_FILE_FORMATS))]
         file: String,
+        /// Format of the file (overrides inference from the file's extension)
+        #[arg(short = 'f', long = "format", value_name = "FORMAT", value_parser = PossibleValuesParser::new(IMPORT_FILE_FORMATS))]
+        format: Option<String>,
         /// Display bookmarks that will be imported without actually importing them
         #[arg(short = 'd', long = "dry-run")]
         dry_run: bool,
@@ -37,6 +43,50 @@ pub enum BmmCommand {
         /// Ignore errors related to bookmark title and tags; if title is too long, it'll be trimmed, some invalid tags will be corrected
         #[arg(short = 'i', long = "ignore-attribute-errors")]
         ignore_attribute_errors: bool,
+        /// Add the names of the folders a bookmark is nested in as tags (HTML, Chromium, and Firefox bookmarks only)
+        #[arg(short = 'F', long = "folders-as-tags")]
+        folders_as_tags: bool,
+        /// List what happened to each bookmark (created, updated, or unchanged) instead of just the count
+        #[arg(long = "report", value_name = "FORMAT")]
+        report: Option<ReportFormat>,
+    },
+    /// Export bookmarks to a file that browsers and other tools can import
+    #[command(after_help = r#"Examples:
+  Export all bookmarks as a browser-importable HTML file:
+    bmm export --output bookmarks.html
+
+  Export bookmarks matching a query to stdout:
+    bmm export --uri github.com --tags tools
+
+  Back up all bookmarks as JSON Lines (can be imported back via "bmm import"):
+    bmm export --format jsonl --output bookmarks.jsonl
+"#)]
+    Export {
+        /// Pattern to match bookmark URIs on
+        #[arg(short = 'u', long = "uri", value_name = "URI")]
+        uri: Option<String>,
+        /// Pattern to match bookmark titles on
+        #[arg(short = 'd', long = "title", value_name = "STRING")]
+        title: Option<String>,
+        /// Tags to match (tags nested under them, eg. "lang/rust" for "lang", match as well)
+        #[arg(
+            short = 't',
+            long = "tags",
+            value_name = "STRING,STRING..",
+            value_delimiter = ','
+        )]
+        tags: Vec<String>,
+        /// Format to export in
+        #[arg(
+            short = 'f',
+            long = "format",
+            value_name = "STRING",
+            default_value = "html"
+        )]
+        format: ExportFormat,
+        /// File to write to (bookmarks will be written to stdout if not provided)
+        #[arg(short = 'o', long = "output", value_name = "FILE")]
+        output: Option<String>,
     },
     /// Delete bookmarks
     #[command(after_help = r#"Examples:
@@ -46,6 +96,9 @@ pub enum BmmCommand {
   Delete bookmarks matching URI patterns:
     bmm delete --pattern example.com github.com
 
+  Delete bookmarks whose URIs match regular expressions:
+    bmm delete --regex '^https://docs\.rs/[^/]+/latest'
+
   Delete without confirmation:
     bmm delete --yes https://example.com
 "#)]
@@ -56,6 +109,9 @@ pub enum BmmCommand {
         /// Treat provided values as URI patterns instead of exact URIs
         #[arg(short = 'p', long = "pattern")]
         match_pattern: bool,
+        /// Treat provided values as regular expressions to match URIs on instead of exact URIs
+        #[arg(short = 'r', long = "regex", conflicts_with = "match_pattern")]
+        match_regex: bool,
         /// Whether to skip confirmation
         #[arg(short = 'y', long = "yes")]
         skip_confirmation: bool,
@@ -68,7 +124,11 @@ pub enum BmmCommand {
         /// Pattern to match bookmark titles on
         #[arg(short = 'd', long = "title", value_name = "STRING")]
         title: Option<String>,
-        /// Tags to match (exactly)
+        /// Treat --uri and --title as (case-sensitive) regular expressions instead of
+        /// literal substrings
+        #[arg(short = 'r', long = "regex")]
+        regex: bool,
+        /// Tags to match (tags nested under them, eg. "lang/rust" for "lang", match as well)
         #[arg(
             short = 't',
             long = "tags",
@@ -76,6 +136,35 @@ pub enum BmmCommand {
             value_delimiter = ','
         )]
         tags: Vec<String>,
+        /// Match bookmarks with any (instead of all) of the tags provided via --tags
+        #[arg(long = "any-tag", requires = "tags")]
+        any_tag: bool,
+        /// Tags to exclude bookmarks by
+        #[arg(
+            long = "exclude-tags",
+            value_name = "STRING,STRING..",
+            value_delimiter = ','
+        )]
+        excluded_tags: Vec<String>,
+        /// Domains to match bookmark URIs on (subdomains are matched as well)
+        #[arg(long = "domain", value_name = "DOMAIN,DOMAIN..", value_delimiter = ',')]
+        domains: Vec<String>,
+        /// Only show bookmarks without any tags
+        #[arg(long = "untagged", conflicts_with_all = ["tags", "excluded_tags"])]
+        untagged: bool,
+        /// Only show bookmarks added (or modified, with --modified) at or after this time;
+        /// accepts dates, RFC 3339 date-times, unix timestamps, and relative times like
+        /// 12h, 3d, or 2w
+        #[arg(long = "since", value_name = "TIME", value_parser = parse_time_bound_arg)]
+        since: Option<i64>,
+        /// Only show bookmarks added (or modified, with --modified) before this time;
+        /// accepts the same values as --since
+        #[arg(long = "until", value_name = "TIME", value_parser = parse_time_bound_arg)]
+        until: Option<i64>,
+        /// Apply --since/--until to when bookmarks were last modified, instead of when
+        /// they were added
+        #[arg(long = "modified")]
+        modified: bool,
         /// Format to use
         #[arg(
             short = 'f',
@@ -92,6 +181,23 @@ pub enum BmmCommand {
             default_value_t = 500
         )]
         limit: u16,
+        /// Attribute to sort bookmarks by
+        #[arg(long = "sort", value_name = "FIELD", default_value = "updated")]
+        sort: SortBy,
+        /// Order to sort bookmarks in [default: desc for created, updated, and tag-count; asc otherwise]
+        #[arg(long = "order", value_name = "ORDER")]
+        order: Option<Order>,
+        /// Number of matching bookmarks to skip
+        #[arg(
+            long = "offset",
+            value_name = "INTEGER",
+            default_value_t = 0,
+            conflicts_with = "page"
+        )]
+        offset: u32,
+        /// Page of results to fetch, starting at 1 (each page holds up to --limit bookmarks)
+        #[arg(long = "page", value_name = "INTEGER", value_parser = clap::value_parser!(u32).range(1..))]
+        page: Option<u32>,
     },
     /// Save/update a bookmark
     Save {
@@ -109,6 +215,9 @@ pub enum BmmCommand {
             value_delimiter = ','
         )]
         tags: Vec<String>,
+        /// Notes for the bookmark (eg. why it's worth keeping)
+        #[arg(short = 'n', long = "notes", value_name = "STRING")]
+        notes: Option<String>,
         /// Provide details via a text editor
         #[arg(short = 'e', long = "editor")]
         use_editor: bool,
@@ -121,6 +230,11 @@ pub enum BmmCommand {
         /// Ignore errors related to bookmark title and tags; if title is too long, it'll be trimmed, some invalid tags will be corrected
         #[arg(short = 'i', long = "ignore-attribute-errors")]
         ignore_attribute_errors: bool,
+        /// Suggest tags based on bookmarks from the same domain and on tags that often appear
+        /// along with the chosen ones; suggestions are listed after saving, or pre-filled in
+        /// the text editor when used with --editor
+        #[arg(short = 's', long = "suggest")]
+        suggest: bool,
     },
     /// Save/update multiple bookmarks
     SaveAll {
@@ -144,12 +258,30 @@ pub enum BmmCommand {
         /// Ignore errors related to bookmark tags; some invalid tags will be corrected
         #[arg(short = 'i', long = "ignore-attribute-errors")]
         ignore_attribute_errors: bool,
+        /// List what happened to each bookmark (created, updated, or unchanged) instead of just the count
+        #[arg(long = "report", value_name = "FORMAT")]
+        report: Option<ReportFormat>,
     },
     /// Search bookmarks by matching over terms
     Search {
-        /// Query terms to search bookmarks with (will be matched over bookmark uri, title, and tags)
+        /// Search query; words are matched against the start of words in bookmark uri, title,
+        /// tags, and notes. Supports "quoted phrases", tag:, title:, uri:, site:, before:, and
+        /// after: qualifiers, negation via a leading -, OR, and (groups)
         #[arg(value_name = "TERM")]
         query_terms: Vec<String>,
+        /// Only show bookmarks added (or modified, with --modified) at or after this time;
+        /// accepts dates, RFC 3339 date-times, unix timestamps, and relative times like
+        /// 12h, 3d, or 2w
+        #[arg(long = "since", value_name = "TIME", value_parser = parse_time_bound_arg, conflicts_with = "tui")]
+        since: Option<i64>,
+        /// Only show bookmarks added (or modified, with --modified) before this time;
+        /// accepts the same values as --since
+        #[arg(long = "until", value_name = "TIME", value_parser = parse_time_bound_arg, conflicts_with = "tui")]
+        until: Option<i64>,
+        /// Apply --since/--until to when bookmarks were last modified, instead of when
+        /// they were added
+        #[arg(long = "modified", conflicts_with = "tui")]
+        modified: bool,
         /// Format to output in
         #[arg(
             short = 'f',
@@ -166,6 +298,37 @@ pub enum BmmCommand {
             default_value_t = 500
         )]
         limit: u16,
+        /// Attribute to sort bookmarks by [default: relevance to the query]
+        #[arg(long = "sort", value_name = "FIELD", conflicts_with = "tui")]
+        sort: Option<SortBy>,
+        /// Order to sort bookmarks in [default: desc for relevance, created, updated, and tag-count; asc otherwise]
+        #[arg(long = "order", value_name = "ORDER", conflicts_with = "tui")]
+        order: Option<Order>,
+        /// Number of matching bookmarks to skip
+        #[arg(
+            long = "offset",
+            value_name = "INTEGER",
+            default_value_t = 0,
+            conflicts_with = "page",
+            conflicts_with = "tui"
+        )]
+        offset: u32,
+        /// Page of results to fetch, starting at 1 (each page holds up to --limit bookmarks)
+        #[arg(long = "page", value_name = "INTEGER", value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "tui")]
+        page: Option<u32>,
+        /// Show the part of each bookmark that matched the query below its URI (only
+        /// applies to the plain format)
+        #[arg(long = "snippets", conflicts_with = "tui")]
+        snippets: bool,
+        /// Rank bookmarks by how closely their uri, title, or tags fuzzy match the query
+        /// (eg. "srde" matches "serde"), instead of treating it as a structured query
+        #[arg(
+            long = "fuzzy",
+            conflicts_with = "sort",
+            conflicts_with = "order",
+            conflicts_with = "snippets"
+        )]
+        fuzzy: bool,
         /// whether to show results in bmm's TUI
         #[arg(long = "tui")]
         tui: bool,
@@ -187,6 +350,44 @@ pub enum BmmCommand {
 
 #[derive(Subcommand, Debug)]
 pub enum TagsCommand {
+    /// Manage tag aliases (eg. "js" for "javascript"); aliases are replaced with the
+    /// tags they point to when saving bookmarks, and when filtering bookmarks by tags
+    Alias {
+        #[command(subcommand)]
+        alias_command: TagAliasCommand,
+    },
+    /// Add tags to every bookmark matching a query
+    Add {
+        /// Tags to add
+        #[arg(value_name = "STRING,STRING..", value_delimiter = ',', required = true)]
+        tags: Vec<String>,
+        /// Query selecting the bookmarks to change, in the same format as the one used
+        /// by "bmm search" (eg. "uri:github.com/dhth tag:tools", "rust -tag:old")
+        #[arg(short = 'w', long = "where", value_name = "QUERY")]
+        query: String,
+        /// Show the bookmarks that would change without changing them
+        #[arg(short = 'd', long = "dry-run")]
+        dry_run: bool,
+        /// Whether to skip confirmation
+        #[arg(short = 'y', long = "yes")]
+        skip_confirmation: bool,
+    },
+    /// Remove tags from every bookmark matching a query
+    Remove {
+        /// Tags to remove
+        #[arg(value_name = "STRING,STRING..", value_delimiter = ',', required = true)]
+        tags: Vec<String>,
+        /// Query selecting the bookmarks to change, in the same format as the one used
+        /// by "bmm search" (eg. "uri:github.com/dhth tag:tools", "rust -tag:old")
+        #[arg(short = 'w', long = "where", value_name = "QUERY")]
+        query: String,
+        /// Show the bookmarks that would change without changing them
+        #[arg(short = 'd', long = "dry-run")]
+        dry_run: bool,
+        /// Whether to skip confirmation
+        #[arg(short = 'y', long = "yes")]
+        skip_confirmation: bool,
+    },
     /// Delete tags
     Delete {
         /// Tags to delete
@@ -209,19 +410,127 @@ pub enum TagsCommand {
         /// whether to show tag stats
         #[arg(short = 's', long = "show-stats")]
         show_stats: bool,
+        /// Show nested tags (eg. "lang/rust") as a tree, along with the number of bookmarks
+        /// under each tag (including the ones of tags nested under it)
+        #[arg(long = "tree", conflicts_with = "tui")]
+        tree: bool,
         /// whether to show results in bmm's TUI
         #[arg(long = "tui")]
         tui: bool,
     },
-    /// Rename a tag
+    /// Merge several tags into one
+    Merge {
+        /// Tags to merge (must already exist)
+        #[arg(value_name = "SOURCE", required = true)]
+        source_tags: Vec<String>,
+        /// Tag to merge them into (can either be a new tag or an already existing one)
+        #[arg(long = "into", value_name = "TARGET")]
+        target_tag: String,
+        /// Show what would change without merging tags
+        #[arg(short = 'd', long = "dry-run")]
+        dry_run: bool,
+        /// Whether to skip confirmation
+        #[arg(short = 'y', long = "yes")]
+        skip_confirmation: bool,
+    },
+    /// Rename a tag, or every tag matching a regular expression
     Rename {
         /// Source tag (must already exist)
-        #[arg(value_name = "SOURCE")]
-        source_tag: String,
+        #[arg(
+            value_name = "SOURCE",
+            required_unless_present = "pattern",
+            conflicts_with = "pattern"
+        )]
+        source_tag: Option<String>,
         /// Target tag (can either be a new tag or an already existing one)
-        #[arg(value_name = "TARGET")]
-        target_tag: String,
+        #[arg(
+            value_name = "TARGET",
+            required_unless_present = "pattern",
+            conflicts_with = "pattern"
+        )]
+        target_tag: Option<String>,
+        /// Rename the tags nested under the source tag as well (eg. renaming "lang" to
+        /// "languages" renames "lang/rust" to "languages/rust")
+        #[arg(long = "subtree", conflicts_with = "pattern")]
+        subtree: bool,
+        /// Rename every tag matching this regular expression instead (eg. '^py-(.*)')
+        #[arg(long = "pattern", value_name = "REGEX", requires = "replacement")]
+        pattern: Option<String>,
+        /// What to rename tags matching --pattern to; can refer to the pattern's capture
+        /// groups (eg. 'python-$1')
+        #[arg(long = "to", value_name = "STRING", requires = "pattern")]
+        replacement: Option<String>,
+        /// Show what would change without renaming tags (only for --pattern)
+        #[arg(short = 'd', long = "dry-run", requires = "pattern")]
+        dry_run: bool,
+        /// Whether to skip confirmation (only for --pattern)
+        #[arg(short = 'y', long = "yes", requires = "pattern")]
+        skip_confirmation: bool,
+    },
+    /// Suggest tags for a URI, based on the tags of bookmarks from the same domain, and
+    /// on tags that often appear along with the ones already chosen
+    Suggest {
+        /// URI to suggest tags for
+        #[arg(value_name = "URI")]
+        uri: String,
+        /// Tags already chosen for the URI (used to suggest tags that often appear along
+        /// with them)
+        #[arg(
+            short = 't',
+            long = "tags",
+            value_name = "STRING,STRING..",
+            value_delimiter = ','
+        )]
+        tags: Vec<String>,
+        /// Maximum number of tags to suggest
+        #[arg(
+            short = 'l',
+            long = "limit",
+            value_name = "INTEGER",
+            default_value_t = 5
+        )]
+        limit: u16,
+        /// Format to output in
+        #[arg(
+            short = 'f',
+            long = "format",
+            value_name = "STRING",
+            default_value = "plain"
+        )]
+        format: OutputFormat,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TagAliasCommand {
+    /// Add an alias for a tag (or point an existing alias to another tag)
+    Add {
+        /// Alias for the tag
+        #[arg(value_name = "ALIAS")]
+        alias: String,
+        /// Tag the alias stands for
+        #[arg(value_name = "TAG")]
+        tag: String,
+    },
+    /// Remove tag aliases
+    Remove {
+        /// Aliases to remove
+        #[arg(value_name = "ALIAS", required = true)]
+        aliases: Vec<String>,
+    },
+    /// List tag aliases
+    List {
+        /// Format to output in
+        #[arg(
+            short = 'f',
+            long = "format",
+            value_name = "STRING",
+            default_value = "plain"
+        )]
+        format: OutputFormat,
     },
+    /// Replace already saved tags that are now aliases with the tags they point to
+    Apply,
 }
 
 #[derive(Clone, Debug, ValueEnum)]
@@ -248,85 +557,284 @@ impl std::fmt::Display for OutputFormat {
     }
 }
 
+#[derive(Clone, Debug, ValueEnum)]
+pub enum ReportFormat {
+    /// JSON output
+    Json,
+    /// Plain output
+    Plain,
+}
+
+impl std::fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            ReportFormat::Json => "json",
+            ReportFormat::Plain => "plain",
+        };
+
+        write!(f, "{value}")?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum ExportFormat {
+    /// Netscape bookmark file (NETSCAPE-Bookmark-file-1), as used by browsers
+    Html,
+    /// bmm's versioned JSON document, with full bookmark records
+    Json,
+    /// bmm's bookmark records, one JSON object per line
+    Jsonl,
+}
+
+impl std::fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Json => "json",
+            ExportFormat::Jsonl => "jsonl",
+        };
+
+        write!(f, "{value}")?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum SortBy {
+    /// Bookmark URI
+    Uri,
+    /// Bookmark title
+    Title,
+    /// Time the bookmark was added
+    Created,
+    /// Time the bookmark was last updated
+    Updated,
+    /// Number of tags on the bookmark
+    TagCount,
+}
+
+impl std::fmt::Display for SortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            SortBy::Uri => "uri",
+            SortBy::Title => "title",
+            SortBy::Created => "created",
+            SortBy::Updated => "updated",
+            SortBy::TagCount => "tag-count",
+        };
+
+        write!(f, "{value}")?;
+
+        Ok(())
+    }
+}
+
+impl From<SortBy> for SortField {
+    fn from(value: SortBy) -> Self {
+        match value {
+            SortBy::Uri => SortField::Uri,
+            SortBy::Title => SortField::Title,
+            SortBy::Created => SortField::Created,
+            SortBy::Updated => SortField::Updated,
+            SortBy::TagCount => SortField::TagCount,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Order {
+    /// Ascending order
+    Asc,
+    /// Descending order
+    Desc,
+}
+
+impl std::fmt::Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = match self {
+            Order::Asc => "asc",
+            Order::Desc => "desc",
+        };
+
+        write!(f, "{value}")?;
+
+        Ok(())
+    }
+}
+
+impl From<Order> for SortOrder {
+    fn from(value: Order) -> Self {
+        match value {
+            Order::Asc => SortOrder::Asc,
+            Order::Desc => SortOrder::Desc,
+        }
+    }
+}
+
 impl std::fmt::Display for Args {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let output = match &self.command {
             BmmCommand::Delete {
                 uris,
                 match_pattern,
+                match_regex,
                 skip_confirmation,
             } => format!(
                 r#"
 command           : Delete bookmark(s)
 URIs              : {}
 match pattern     : {}
+match regex       : {}
 skip confirmation : {}
 "#,
                 uris.join(", "),
                 match_pattern,
+                match_regex,
                 skip_confirmation,
             ),
             BmmCommand::List {
                 uri,
                 title,
+                regex,
                 tags,
+                any_tag,
+                excluded_tags,
+                domains,
+                untagged,
+                since,
+                until,
+                modified,
                 format,
                 limit,
+                sort,
+                order,
+                offset,
+                page,
             } => format!(
                 r#"
 command           : List bookmark(s)
 URI query         : {}
 title query       : {}
+regex             : {}
 tags              : {:?}
+any tag           : {}
+excluded tags     : {:?}
+domains           : {:?}
+untagged          : {}
+since             : {}
+until             : {}
+modified          : {}
 format            : {}
 limit             : {}
+sort              : {}
+order             : {}
+offset            : {}
+page              : {}
 "#,
                 uri.as_deref().unwrap_or(NOT_PROVIDED),
                 title.as_deref().unwrap_or(NOT_PROVIDED),
+                regex,
                 tags,
+                any_tag,
+                excluded_tags,
+                domains,
+                untagged,
+                since
+                    .map(format_timestamp)
+                    .as_deref()
+                    .unwrap_or(NOT_PROVIDED),
+                until
+                    .map(format_timestamp)
+                    .as_deref()
+                    .unwrap_or(NOT_PROVIDED),
+                modified,
                 format,
                 limit,
+                sort,
+                order.map_or(NOT_PROVIDED.into(), |o| o.to_string()),
+                offset,
+                page.map_or(NOT_PROVIDED.into(), |p| p.to_string()),
             ),
             BmmCommand::Import {
                 file,
+                format,
                 dry_run,
                 reset_missing,
                 ignore_attribute_errors,
+                folders_as_tags,
+                report,
             } => format!(
                 r#"
 command       : Import bookmarks
 file          : {file}
+format        : {}
 dry run       : {dry_run}
 reset missing : {reset_missing}
 ignore attribute errors   : {ignore_attribute_errors}
+folders as tags           : {folders_as_tags}
+report                    : {}
 "#,
+                format.as_deref().unwrap_or(NOT_PROVIDED),
+                report
+                    .as_ref()
+                    .map_or(NOT_PROVIDED.into(), |r| r.to_string()),
+            ),
+            BmmCommand::Export {
+                uri,
+                title,
+                tags,
+                format,
+                output,
+            } => format!(
+                r#"
+command           : Export bookmarks
+URI query         : {}
+title query       : {}
+tags              : {:?}
+format            : {}
+output            : {}
+"#,
+                uri.as_deref().unwrap_or(NOT_PROVIDED),
+                title.as_deref().unwrap_or(NOT_PROVIDED),
+                tags,
+                format,
+                output.as_deref().unwrap_or("stdout"),
             ),
             BmmCommand::Save {
                 uri,
                 title,
                 tags,
+                notes,
                 use_editor,
                 fail_if_uri_already_saved,
                 reset_missing,
                 ignore_attribute_errors,
+                suggest,
             } => format!(
                 r#"
 command                   : Save/update bookmark
 URI                       : {}
 title                     : {}
 tags                      : {}
+notes                     : {}
 use editor                : {}
 fail if URI already saved : {}
 reset missing             : {}
 ignore attribute errors   : {}
+suggest tags              : {}
 "#,
                 uri,
                 title.as_deref().unwrap_or(NOT_PROVIDED),
                 tags.join(" "),
+                notes.as_deref().unwrap_or(NOT_PROVIDED),
                 use_editor,
                 fail_if_uri_already_saved,
                 reset_missing,
                 ignore_attribute_errors,
+                suggest,
             ),
             BmmCommand::SaveAll {
                 uris,
@@ -334,6 +842,7 @@ ignore attribute errors   : {}
                 use_stdin,
                 reset_missing,
                 ignore_attribute_errors,
+                report,
             } => format!(
                 r#"
 command                   : Save/update bookmarks
@@ -342,26 +851,59 @@ tags                      : {}
 use stdin                 : {}
 reset missing             : {}
 ignore attribute errors   : {}
+report                    : {}
 "#,
                 uris.as_ref().map_or(NOT_PROVIDED.into(), |u| u.join(" ")),
                 tags.join(" "),
                 use_stdin,
                 reset_missing,
                 ignore_attribute_errors,
+                report
+                    .as_ref()
+                    .map_or(NOT_PROVIDED.into(), |r| r.to_string()),
             ),
             BmmCommand::Search {
                 query_terms,
+                since,
+                until,
+                modified,
                 format,
                 limit,
+                sort,
+                order,
+                offset,
+                page,
+                snippets,
+                fuzzy,
                 tui,
             } => format!(
                 r#"
 command     : Search bookmarks
 query terms : {query_terms:?}
+since       : {}
+until       : {}
+modified    : {modified}
 format      : {format}
 limit       : {limit}
+sort        : {}
+order       : {}
+offset      : {offset}
+page        : {}
+snippets    : {snippets}
+fuzzy       : {fuzzy}
 tui         : {tui}
-"#
+"#,
+                since
+                    .map(format_timestamp)
+                    .as_deref()
+                    .unwrap_or(NOT_PROVIDED),
+                until
+                    .map(format_timestamp)
+                    .as_deref()
+                    .unwrap_or(NOT_PROVIDED),
+                sort.map_or(NOT_PROVIDED.into(), |s| s.to_string()),
+                order.map_or(NOT_PROVIDED.into(), |o| o.to_string()),
+                page.map_or(NOT_PROVIDED.into(), |p| p.to_string()),
             ),
             BmmCommand::Show { uri } => format!(
                 r#"
@@ -373,23 +915,121 @@ URI         : {uri}
                 TagsCommand::List {
                     format,
                     show_stats,
+                    tree,
                     tui,
                 } => format!(
                     r#"
 command      : List Tags
 format       : {format}
 show stats   : {show_stats}
+tree         : {tree}
 run tui      : {tui}
+"#,
+                ),
+                TagsCommand::Merge {
+                    source_tags,
+                    target_tag,
+                    dry_run,
+                    skip_confirmation,
+                } => format!(
+                    r#"
+command          : Merge Tags
+source tags      : {source_tags:?}
+target tag       : {target_tag}
+dry run          : {dry_run}
+skip confirmation: {skip_confirmation}
 "#,
                 ),
                 TagsCommand::Rename {
                     source_tag,
                     target_tag,
+                    subtree,
+                    pattern,
+                    replacement,
+                    dry_run,
+                    skip_confirmation,
+                } => format!(
+                    r#"
+command          : Rename Tag
+source tag       : {}
+target tag       : {}
+subtree          : {subtree}
+pattern          : {}
+replacement      : {}
+dry run          : {dry_run}
+skip confirmation: {skip_confirmation}
+"#,
+                    source_tag.as_deref().unwrap_or(NOT_PROVIDED),
+                    target_tag.as_deref().unwrap_or(NOT_PROVIDED),
+                    pattern.as_deref().unwrap_or(NOT_PROVIDED),
+                    replacement.as_deref().unwrap_or(NOT_PROVIDED),
+                ),
+                TagsCommand::Suggest {
+                    uri,
+                    tags,
+                    limit,
+                    format,
+                } => format!(
+                    r#"
+command      : Suggest Tags
+URI          : {uri}
+tags         : {}
+limit        : {limit}
+format       : {format}
+"#,
+                    tags.join(" "),
+                ),
+                TagsCommand::Alias { alias_command } => match alias_command {
+                    TagAliasCommand::Add { alias, tag } => format!(
+                        r#"
+command      : Add Tag Alias
+alias        : {alias}
+tag          : {tag}
+"#,
+                    ),
+                    TagAliasCommand::Remove { aliases } => format!(
+                        r#"
+command      : Remove Tag Aliases
+aliases      : {aliases:?}
+"#,
+                    ),
+                    TagAliasCommand::List { format } => format!(
+                        r#"
+command      : List Tag Aliases
+format       : {format}
+"#,
+                    ),
+                    TagAliasCommand::Apply => r#"
+command      : Apply Tag Aliases
+"#
+                    .to_string(),
+                },
+                TagsCommand::Add {
+                    tags,
+                    query,
+                    dry_run,
+                    skip_confirmation,
+                } => format!(
+                    r#"
+command          : Add Tags
+tags             : {tags:?}
+where            : {query}
+dry run          : {dry_run}
+skip confirmation: {skip_confirmation}
+"#,
+                ),
+                TagsCommand::Remove {
+                    tags,
+                    query,
+                    dry_run,
+                    skip_confirmation,
                 } => format!(
                     r#"
-command      : Rename Tag
-source tag   : {source_tag}
-target tag   : {target_tag}
+command          : Remove Tags
+tags             : {tags:?}
+where            : {query}
+dry run          : {dry_run}
+skip confirmation: {skip_confirmation}
 "#,
                 ),
                 TagsCommand::Delete {
@@ -413,29 +1053,13 @@ command      : Open TUI
     }
 }
 
-fn validate_import_file(file: &str) -> Result<String, String> {
-    let path_buf = PathBuf::from(file);
-    match path_buf.extension() {
-        Some(e) => match e.to_str() {
-            Some(ext) => {
-                if !IMPORT_FILE_FORMATS.contains(&ext) {
-                    return Err(format!(
-                        "only the following file formats are supported for import: {IMPORT_FILE_FORMATS:?}"
-                    ));
-                }
-            }
-            None => {
-                return Err(format!(
-                    "file has invalid extension; supported extensions: {IMPORT_FILE_FORMATS:?}"
-                ));
-            }
-        },
-        None => {
-            return Err(format!(
-                "file has no extension; supported extensions: {IMPORT_FILE_FORMATS:?}"
-            ));
-        }
-    }
+fn parse_time_bound_arg(value: &str) -> Result<i64, String> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("system time error: {e}"))?
+        .as_secs() as i64;
 
-    Ok(file.to_string())
+    parse_time_bound(value, now).ok_or_else(|| {
+        "expected a date (eg. 2025-02-18), an RFC 3339 date-time, a unix timestamp, or a relative time (eg. 12h, 3d, 2w)".to_string()
+    })
 }
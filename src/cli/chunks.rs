@@ -0,0 +1,122 @@
+use crate::common::SAVE_CHUNK_SIZE;
+use crate::domain::{DraftBookmark, DraftBookmarkError};
+
+// An entry read from the input: its (zero based) position in the input, and
+// the result of validating it
+pub(super) type InputEntry = (usize, Result<DraftBookmark, DraftBookmarkError>);
+
+pub(super) type ValidatedChunk = Result<Vec<DraftBookmark>, Vec<(usize, DraftBookmarkError)>>;
+
+// Reads at most SAVE_CHUNK_SIZE entries from the input, so that large inputs
+// never have to be held in memory all at once. Returns None once the input is
+// exhausted.
+pub(super) fn next_chunk<I, E>(entries: &mut I) -> Result<Option<ValidatedChunk>, E>
+where
+    I: Iterator<Item = Result<InputEntry, E>>,
+{
+    let mut num_entries = 0;
+    let mut draft_bookmarks = Vec::new();
+    let mut validation_errors = Vec::new();
+    for entry in entries.by_ref().take(SAVE_CHUNK_SIZE) {
+        num_entries += 1;
+        let (index, result) = entry?;
+        match result {
+            Ok(db) => draft_bookmarks.push(db),
+            Err(e) => validation_errors.push((index, e)),
+        }
+    }
+
+    if num_entries == 0 {
+        return Ok(None);
+    }
+
+    if validation_errors.is_empty() {
+        Ok(Some(ValidatedChunk::Ok(draft_bookmarks)))
+    } else {
+        Ok(Some(ValidatedChunk::Err(validation_errors)))
+    }
+}
+
+// Progress is only reported after full chunks, ie. when more entries might
+// follow; small inputs don't generate any progress output.
+pub(super) fn report_progress(chunk_len: usize, num_saved: usize) {
+    if chunk_len == SAVE_CHUNK_SIZE {
+        eprintln!("saved {num_saved} bookmarks so far...");
+    }
+}
+
+// Each chunk is saved in its own transaction; when a later chunk fails, the
+// ones before it stay saved, which users need to know about.
+pub(super) fn report_partial_save(num_saved: usize) {
+    if num_saved > 0 {
+        eprintln!(
+            "{num_saved} bookmarks were saved before the error below occurred; they stay saved, and running the same command again once the error is fixed is safe"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::PotentialBookmark;
+
+    fn entries(num: usize, invalid: &[usize]) -> Vec<Result<InputEntry, String>> {
+        (0..num)
+            .map(|index| {
+                let uri = if invalid.contains(&index) {
+                    format!("github.com/dhth/{index}")
+                } else {
+                    format!("https://github.com/dhth/{index}")
+                };
+                let pb = PotentialBookmark::from((uri, None, &Vec::new()));
+                Ok((index, DraftBookmark::try_from((pb, false))))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reading_chunks_works() {
+        // GIVEN
+        let mut input = entries(SAVE_CHUNK_SIZE * 2 + 3, &[]).into_iter();
+
+        // WHEN
+        let mut chunk_lengths = Vec::new();
+        while let Some(chunk) = next_chunk(&mut input).expect("reading should've succeeded") {
+            chunk_lengths.push(chunk.expect("chunk should've been valid").len());
+        }
+
+        // THEN
+        assert_eq!(chunk_lengths, vec![SAVE_CHUNK_SIZE, SAVE_CHUNK_SIZE, 3]);
+    }
+
+    #[test]
+    fn reading_chunks_returns_validation_errors_with_their_positions() {
+        // GIVEN
+        let invalid = SAVE_CHUNK_SIZE + 1;
+        let mut input = entries(SAVE_CHUNK_SIZE * 2, &[invalid]).into_iter();
+
+        // WHEN
+        let first = next_chunk(&mut input).expect("reading should've succeeded");
+        let second = next_chunk(&mut input).expect("reading should've succeeded");
+
+        // THEN
+        assert!(matches!(first, Some(Ok(_))));
+        let errors = second
+            .expect("second chunk should've been present")
+            .expect_err("second chunk should've had validation errors");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, invalid);
+    }
+
+    #[test]
+    fn reading_chunks_stops_at_input_errors() {
+        // GIVEN
+        let mut input = vec![Err("couldn't read line".to_string())].into_iter();
+
+        // WHEN
+        let error = next_chunk(&mut input).expect_err("reading should've failed");
+
+        // THEN
+        assert_eq!(error, "couldn't read line");
+    }
+}
@@ -1,10 +1,13 @@
 use crate::persistence::DBError;
 use crate::persistence::{UriMatchMode, delete_bookmarks_with_uris, get_matching_bookmark_uris};
+use regex::Regex;
 use sqlx::{Pool, Sqlite};
 use std::io::{Error as IOError, Write};
 
 #[derive(thiserror::Error, Debug)]
 pub enum DeleteBookmarksError {
+    #[error("invalid regex: {0}")]
+    InvalidRegex(#[from] regex::Error),
     #[error(transparent)]
     CouldntDeleteBookmarksInDB(#[from] DBError),
     #[error("couldn't flush stdout: {0}")]
@@ -16,18 +19,19 @@ pub enum DeleteBookmarksError {
 pub async fn delete_bookmarks(
     pool: &Pool<Sqlite>,
     uris: Vec<String>,
-    match_pattern: bool,
+    match_mode: UriMatchMode,
     skip_confirmation: bool,
 ) -> Result<(), DeleteBookmarksError> {
     if uris.is_empty() {
         return Ok(());
     }
 
-    let match_mode = if match_pattern {
-        UriMatchMode::Pattern
-    } else {
-        UriMatchMode::Exact
-    };
+    if let UriMatchMode::Regex = match_mode {
+        for uri in &uris {
+            Regex::new(uri)?;
+        }
+    }
+
     let uris = get_matching_bookmark_uris(pool, &uris, match_mode).await?;
 
     if uris.is_empty() {
@@ -1,6 +1,8 @@
 use crate::args::Args;
-use crate::args::OutputFormat;
-use crate::domain::{SavedBookmark, TagStats};
+use crate::args::{OutputFormat, ReportFormat};
+use crate::domain::{
+    SaveReport, SavedBookmark, TagAlias, TagNode, TagStats, TagSuggestion, format_timestamp,
+};
 use csv::Error as CsvError;
 use serde_json::Error as SerdeJsonError;
 
@@ -43,17 +45,40 @@ pub fn display_bookmarks(
     Ok(())
 }
 
+pub fn display_search_results(bookmarks: &Vec<SavedBookmark>) {
+    for b in bookmarks {
+        println!("{}", b.uri);
+        if let Some(snippet) = b.snippet.as_deref() {
+            println!(
+                "    {}",
+                snippet.split_whitespace().collect::<Vec<_>>().join(" ")
+            );
+        }
+    }
+}
+
 pub fn display_bookmark_details(bookmark: &SavedBookmark) {
     println!(
         r#"Bookmark details
 ---
 
-Title: {}
-URI  : {}
-Tags : {}"#,
+Title  : {}
+URI    : {}
+Tags   : {}
+Notes  : {}
+Added  : {}
+Updated: {}"#,
         bookmark.title.as_deref().unwrap_or(NOT_SET),
         bookmark.uri,
         bookmark.tags.as_deref().unwrap_or(NOT_SET),
+        bookmark
+            .notes
+            .as_deref()
+            .map(|n| n.lines().collect::<Vec<_>>().join("\n         "))
+            .as_deref()
+            .unwrap_or(NOT_SET),
+        format_timestamp(bookmark.created_at),
+        format_timestamp(bookmark.updated_at),
     )
 }
 
@@ -104,6 +129,148 @@ pub fn display_tags_with_stats(
     Ok(())
 }
 
+pub fn display_tag_aliases(
+    aliases: &[TagAlias],
+    format: &OutputFormat,
+) -> Result<(), DisplayError> {
+    match format {
+        OutputFormat::Plain => {
+            for a in aliases {
+                println!("{} -> {}", a.alias, a.tag);
+            }
+        }
+        OutputFormat::Json => {
+            let output = serde_json::to_string_pretty(aliases)?;
+            println!("{output}");
+        }
+        OutputFormat::Delimited => {
+            let mut wtr = csv::Writer::from_writer(std::io::stdout());
+            for a in aliases {
+                wtr.serialize(a)?;
+            }
+            wtr.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+// plain output only has the suggested tags, so that it can be used in scripts
+pub fn display_tag_suggestions(
+    suggestions: &[TagSuggestion],
+    format: &OutputFormat,
+) -> Result<(), DisplayError> {
+    match format {
+        OutputFormat::Plain => {
+            for s in suggestions {
+                println!("{}", s.tag);
+            }
+        }
+        OutputFormat::Json => {
+            let output = serde_json::to_string_pretty(suggestions)?;
+            println!("{output}");
+        }
+        OutputFormat::Delimited => {
+            let mut wtr = csv::Writer::from_writer(std::io::stdout());
+            for s in suggestions {
+                wtr.serialize(s)?;
+            }
+            wtr.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn display_tag_tree(tree: &[TagNode], format: &OutputFormat) -> Result<(), DisplayError> {
+    match format {
+        OutputFormat::Plain => {
+            let mut lines = vec![];
+            for node in tree {
+                push_tag_tree_lines(node, "", None, &mut lines);
+            }
+            println!("{}", lines.join("\n"));
+        }
+        OutputFormat::Json => {
+            let output = serde_json::to_string_pretty(tree)?;
+            println!("{output}");
+        }
+        // every tag in the tree gets a row, with its rolled up number of
+        // bookmarks
+        OutputFormat::Delimited => {
+            let mut wtr = csv::Writer::from_writer(std::io::stdout());
+            let mut nodes = tree.iter().rev().collect::<Vec<_>>();
+            while let Some(node) = nodes.pop() {
+                wtr.serialize(TagStats {
+                    name: node.name.clone(),
+                    num_bookmarks: node.num_bookmarks,
+                })?;
+                nodes.extend(node.children.iter().rev());
+            }
+            wtr.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+// top level tags are printed as is; nested ones are drawn as branches of their
+// parents, eg.
+//
+// lang (3 bookmarks)
+// ├── go (1 bookmark)
+// └── rust (2 bookmarks)
+fn push_tag_tree_lines(
+    node: &TagNode,
+    indent: &str,
+    is_last: Option<bool>,
+    lines: &mut Vec<String>,
+) {
+    let stats = TagStats {
+        name: node.leaf_name().to_string(),
+        num_bookmarks: node.num_bookmarks,
+    };
+    let (branch, child_indent) = match is_last {
+        None => ("", String::new()),
+        Some(false) => ("├── ", format!("{indent}│   ")),
+        Some(true) => ("└── ", format!("{indent}    ")),
+    };
+    lines.push(format!("{indent}{branch}{stats}"));
+
+    for (i, child) in node.children.iter().enumerate() {
+        let is_last_child = i == node.children.len() - 1;
+        push_tag_tree_lines(child, &child_indent, Some(is_last_child), lines);
+    }
+}
+
+pub fn display_save_report(report: &SaveReport, format: &ReportFormat) -> Result<(), DisplayError> {
+    match format {
+        ReportFormat::Plain => {
+            for b in report.bookmarks.iter().flatten() {
+                println!("{:<9}  {}", b.outcome.as_str(), b.uri);
+                for c in &b.corrections {
+                    println!("{:<9}    - {c}", "");
+                }
+            }
+            let summary = &report.summary;
+            println!(
+                "\ncreated: {}, updated: {}, unchanged: {}, corrected: {}, skipped: {}",
+                summary.created,
+                summary.updated,
+                summary.unchanged,
+                summary.corrected,
+                summary.skipped
+            );
+        }
+        ReportFormat::Json => {
+            let output = serde_json::to_string_pretty(report)?;
+            println!("{output}");
+        }
+    }
+
+    Ok(())
+}
+
 pub fn display_debug_info(args: &Args, db_path: &str) {
     println!(
         r#"DEBUG INFO:
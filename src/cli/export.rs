@@ -0,0 +1,295 @@
+use crate::args::ExportFormat;
+use crate::domain::{BOOKMARKS_EXPORT_VERSION, BookmarkRecord, BookmarksExport, ExportedBookmark};
+use crate::persistence::{BookmarkFilter, DBError, get_bookmark_records};
+use sqlx::{Pool, Sqlite};
+use std::fs::File;
+use std::io::Error as IOError;
+use std::io::{BufWriter, Write};
+
+const NETSCAPE_HTML_HEADER: &str = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<!-- This is an automatically generated file.
+     It will be read and overwritten.
+     DO NOT EDIT! -->
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+"#;
+const NETSCAPE_HTML_FOOTER: &str = "</DL><p>\n";
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExportError {
+    #[error("couldn't get bookmarks from db: {0}")]
+    CouldntGetBookmarksFromDB(#[from] DBError),
+    #[error("couldn't create output file: {0}")]
+    CouldntCreateFile(#[source] IOError),
+    #[error("couldn't write output: {0}")]
+    CouldntWriteOutput(#[source] IOError),
+    #[error("couldn't serialize bookmarks to JSON: {0}")]
+    CouldntSerializeToJSON(#[from] serde_json::Error),
+}
+
+#[derive(Debug)]
+pub struct ExportStats {
+    pub num_bookmarks_exported: usize,
+}
+
+pub async fn export_bookmarks(
+    pool: &Pool<Sqlite>,
+    filter: &BookmarkFilter,
+    format: ExportFormat,
+    output: Option<String>,
+) -> Result<Option<ExportStats>, ExportError> {
+    let records = get_bookmark_records(pool, filter).await?;
+    let num_bookmarks_exported = records.len();
+
+    match output {
+        Some(path) => {
+            let file = File::create(path).map_err(ExportError::CouldntCreateFile)?;
+            let mut writer = BufWriter::new(file);
+            write_records(records, &format, &mut writer)?;
+
+            Ok(Some(ExportStats {
+                num_bookmarks_exported,
+            }))
+        }
+        None => {
+            let mut writer = std::io::stdout().lock();
+            write_records(records, &format, &mut writer)?;
+
+            Ok(None)
+        }
+    }
+}
+
+fn write_records<W: Write>(
+    records: Vec<BookmarkRecord>,
+    format: &ExportFormat,
+    writer: &mut W,
+) -> Result<(), ExportError> {
+    match format {
+        ExportFormat::Html => {
+            write_netscape_html(&records, writer).map_err(ExportError::CouldntWriteOutput)?
+        }
+        ExportFormat::Json => write_json(records, writer)?,
+        ExportFormat::Jsonl => write_jsonl(records, writer)?,
+    }
+
+    writer.flush().map_err(ExportError::CouldntWriteOutput)
+}
+
+fn write_json<W: Write>(records: Vec<BookmarkRecord>, writer: &mut W) -> Result<(), ExportError> {
+    let export = BookmarksExport {
+        version: BOOKMARKS_EXPORT_VERSION,
+        bookmarks: records.into_iter().map(ExportedBookmark::from).collect(),
+    };
+
+    serde_json::to_writer_pretty(&mut *writer, &export)?;
+    writeln!(writer).map_err(ExportError::CouldntWriteOutput)
+}
+
+fn write_jsonl<W: Write>(records: Vec<BookmarkRecord>, writer: &mut W) -> Result<(), ExportError> {
+    for record in records {
+        serde_json::to_writer(&mut *writer, &ExportedBookmark::from(record))?;
+        writeln!(writer).map_err(ExportError::CouldntWriteOutput)?;
+    }
+
+    Ok(())
+}
+
+fn write_netscape_html<W: Write>(
+    records: &[BookmarkRecord],
+    writer: &mut W,
+) -> Result<(), IOError> {
+    writer.write_all(NETSCAPE_HTML_HEADER.as_bytes())?;
+
+    for record in records {
+        write!(
+            writer,
+            r#"    <DT><A HREF="{}" ADD_DATE="{}" LAST_MODIFIED="{}""#,
+            escape_html(&record.uri),
+            record.created_at,
+            record.updated_at,
+        )?;
+
+        if let Some(tags) = record.tags.as_deref() {
+            write!(writer, r#" TAGS="{}""#, escape_html(tags))?;
+        }
+
+        writeln!(
+            writer,
+            ">{}</A>",
+            escape_html(record.title.as_deref().unwrap_or_default())
+        )?;
+
+        if let Some(notes) = record.notes.as_deref() {
+            writeln!(writer, "    <DD>{}", escape_html(notes))?;
+        }
+    }
+
+    writer.write_all(NETSCAPE_HTML_FOOTER.as_bytes())?;
+
+    Ok(())
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_snapshot;
+
+    #[test]
+    fn writing_netscape_html_works() {
+        // GIVEN
+        let records = vec![
+            BookmarkRecord {
+                uri: "https://github.com/dhth/bmm".into(),
+                title: Some("bmm: get to your bookmarks in a flash".into()),
+                tags: Some("productivity,tools".into()),
+                notes: Some("a bookmarks manager for the command line".into()),
+                created_at: 1739920697,
+                updated_at: 1739920739,
+            },
+            BookmarkRecord {
+                uri: "https://github.com/dhth/omm".into(),
+                title: None,
+                tags: None,
+                notes: None,
+                created_at: 1739920615,
+                updated_at: 1739920615,
+            },
+        ];
+        let mut buffer = Vec::new();
+
+        // WHEN
+        write_netscape_html(&records, &mut buffer).expect("writing html should've succeeded");
+
+        // THEN
+        let output = String::from_utf8(buffer).expect("output should be valid utf-8");
+        assert_snapshot!(output, @r#"
+        <!DOCTYPE NETSCAPE-Bookmark-file-1>
+        <!-- This is an automatically generated file.
+             It will be read and overwritten.
+             DO NOT EDIT! -->
+        <META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+        <TITLE>Bookmarks</TITLE>
+        <H1>Bookmarks</H1>
+        <DL><p>
+            <DT><A HREF="https://github.com/dhth/bmm" ADD_DATE="1739920697" LAST_MODIFIED="1739920739" TAGS="productivity,tools">bmm: get to your bookmarks in a flash</A>
+            <DD>a bookmarks manager for the command line
+            <DT><A HREF="https://github.com/dhth/omm" ADD_DATE="1739920615" LAST_MODIFIED="1739920615"></A>
+        </DL><p>
+        "#);
+    }
+
+    #[test]
+    fn writing_json_works() {
+        // GIVEN
+        let records = vec![BookmarkRecord {
+            uri: "https://github.com/dhth/bmm".into(),
+            title: Some("bmm: get to your bookmarks in a flash".into()),
+            tags: Some("productivity,tools".into()),
+            notes: None,
+            created_at: 1739920697,
+            updated_at: 1739920739,
+        }];
+        let mut buffer = Vec::new();
+
+        // WHEN
+        write_json(records, &mut buffer).expect("writing json should've succeeded");
+
+        // THEN
+        let output = String::from_utf8(buffer).expect("output should be valid utf-8");
+        assert_snapshot!(output, @r#"
+        {
+          "version": 1,
+          "bookmarks": [
+            {
+              "uri": "https://github.com/dhth/bmm",
+              "title": "bmm: get to your bookmarks in a flash",
+              "tags": [
+                "productivity",
+                "tools"
+              ],
+              "notes": null,
+              "created_at": 1739920697,
+              "updated_at": 1739920739
+            }
+          ]
+        }
+        "#);
+    }
+
+    #[test]
+    fn writing_jsonl_works() {
+        // GIVEN
+        let records = vec![
+            BookmarkRecord {
+                uri: "https://github.com/dhth/bmm".into(),
+                title: Some("bmm: get to your bookmarks in a flash".into()),
+                tags: Some("productivity,tools".into()),
+                notes: Some("a bookmarks manager for the command line".into()),
+                created_at: 1739920697,
+                updated_at: 1739920739,
+            },
+            BookmarkRecord {
+                uri: "https://github.com/dhth/omm".into(),
+                title: None,
+                tags: None,
+                notes: None,
+                created_at: 1739920615,
+                updated_at: 1739920615,
+            },
+        ];
+        let mut buffer = Vec::new();
+
+        // WHEN
+        write_jsonl(records, &mut buffer).expect("writing jsonl should've succeeded");
+
+        // THEN
+        let output = String::from_utf8(buffer).expect("output should be valid utf-8");
+        assert_snapshot!(output, @r#"
+        {"uri":"https://github.com/dhth/bmm","title":"bmm: get to your bookmarks in a flash","tags":["productivity","tools"],"notes":"a bookmarks manager for the command line","created_at":1739920697,"updated_at":1739920739}
+        {"uri":"https://github.com/dhth/omm","title":null,"tags":[],"notes":null,"created_at":1739920615,"updated_at":1739920615}
+        "#);
+    }
+
+    #[test]
+    fn writing_netscape_html_escapes_special_characters() {
+        // GIVEN
+        let records = vec![BookmarkRecord {
+            uri: "https://example.com/?a=1&b=\"2\"".into(),
+            title: Some("<script>alert('hi')</script> & more".into()),
+            tags: None,
+            notes: None,
+            created_at: 0,
+            updated_at: 0,
+        }];
+        let mut buffer = Vec::new();
+
+        // WHEN
+        write_netscape_html(&records, &mut buffer).expect("writing html should've succeeded");
+
+        // THEN
+        let output = String::from_utf8(buffer).expect("output should be valid utf-8");
+        let line = output
+            .lines()
+            .find(|l| l.contains("<DT>"))
+            .expect("output should contain a bookmark entry");
+        assert_snapshot!(line, @r#"    <DT><A HREF="https://example.com/?a=1&amp;b=&quot;2&quot;" ADD_DATE="0" LAST_MODIFIED="0">&lt;script&gt;alert(&#39;hi&#39;)&lt;/script&gt; &amp; more</A>"#);
+    }
+}
@@ -0,0 +1,175 @@
+use super::{ImportError, InputEntry};
+use crate::common::SAVE_CHUNK_SIZE;
+use crate::domain::{DraftBookmark, PotentialBookmark};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteConnection};
+use sqlx::{ConnectOptions, FromRow};
+use std::path::Path;
+
+// Bookmarks are read a page at a time, continuing after the last id read
+const BUKU_BOOKMARKS_QUERY: &str = "
+SELECT
+    id,
+    URL AS url,
+    metadata,
+    tags,
+    desc
+FROM
+    bookmarks
+WHERE
+    id > ?
+ORDER BY
+    id
+LIMIT
+    ?
+";
+
+#[derive(Debug, FromRow)]
+struct BukuBookmark {
+    id: i64,
+    url: String,
+    metadata: Option<String>,
+    tags: Option<String>,
+    desc: Option<String>,
+}
+
+pub(super) struct BukuReader {
+    conn: SqliteConnection,
+    last_id: i64,
+    num_read: usize,
+    ignore_attribute_errors: bool,
+}
+
+impl BukuReader {
+    pub(super) async fn open(
+        path: &Path,
+        ignore_attribute_errors: bool,
+    ) -> Result<Self, ImportError> {
+        let conn = SqliteConnectOptions::new()
+            .filename(path)
+            .read_only(true)
+            .immutable(true)
+            .connect()
+            .await
+            .map_err(ImportError::CouldntReadBukuDatabase)?;
+
+        Ok(Self {
+            conn,
+            last_id: 0,
+            num_read: 0,
+            ignore_attribute_errors,
+        })
+    }
+
+    /// Returns an empty page once all bookmarks have been read.
+    pub(super) async fn next_page(&mut self) -> Result<Vec<InputEntry>, ImportError> {
+        let entries = sqlx::query_as::<_, BukuBookmark>(BUKU_BOOKMARKS_QUERY)
+            .bind(self.last_id)
+            .bind(SAVE_CHUNK_SIZE as i64)
+            .fetch_all(&mut self.conn)
+            .await
+            .map_err(ImportError::CouldntReadBukuDatabase)?;
+
+        if let Some(last) = entries.last() {
+            self.last_id = last.id;
+        }
+
+        let page = entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let potential_bookmark = PotentialBookmark::from(entry);
+                (
+                    self.num_read + index,
+                    DraftBookmark::try_from((potential_bookmark, self.ignore_attribute_errors)),
+                )
+            })
+            .collect::<Vec<_>>();
+        self.num_read += page.len();
+
+        Ok(page)
+    }
+}
+
+// buku stores tags as a comma delimited string with leading and trailing
+// commas, eg. ",productivity,tools,"; bookmarks without tags have ",".
+impl From<BukuBookmark> for PotentialBookmark {
+    fn from(entry: BukuBookmark) -> Self {
+        Self {
+            uri: entry.url,
+            title: entry.metadata.filter(|m| !m.trim().is_empty()),
+            tags: entry
+                .tags
+                .unwrap_or_default()
+                .split(',')
+                .map(|t| t.trim())
+                .filter(|t| !t.is_empty())
+                .map(|t| t.to_string())
+                .collect(),
+            notes: entry.desc,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_debug_snapshot;
+
+    #[test]
+    fn converting_buku_bookmarks_works() {
+        // GIVEN
+        let entries = vec![
+            BukuBookmark {
+                id: 1,
+                url: "https://github.com/dhth/bmm".into(),
+                metadata: Some("GitHub - dhth/bmm: get to your bookmarks in a flash".into()),
+                tags: Some(",bookmarks,tools,".into()),
+                desc: Some("a bookmarks manager for the command line".into()),
+            },
+            BukuBookmark {
+                id: 2,
+                url: "https://github.com/dhth/omm".into(),
+                metadata: Some("".into()),
+                tags: Some(",".into()),
+                desc: None,
+            },
+        ];
+
+        // WHEN
+        let bookmarks = entries
+            .into_iter()
+            .map(PotentialBookmark::from)
+            .collect::<Vec<_>>();
+
+        // THEN
+        assert_debug_snapshot!(bookmarks, @r#"
+        [
+            PotentialBookmark {
+                uri: "https://github.com/dhth/bmm",
+                title: Some(
+                    "GitHub - dhth/bmm: get to your bookmarks in a flash",
+                ),
+                tags: [
+                    "bookmarks",
+                    "tools",
+                ],
+                notes: Some(
+                    "a bookmarks manager for the command line",
+                ),
+                created_at: None,
+                updated_at: None,
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/omm",
+                title: None,
+                tags: [],
+                notes: None,
+                created_at: None,
+                updated_at: None,
+            },
+        ]
+        "#);
+    }
+}
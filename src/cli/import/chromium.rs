@@ -0,0 +1,350 @@
+use super::{ImportError, ParseResult, merge_duplicate_bookmarks, validate_potential_bookmarks};
+use crate::domain::{PotentialBookmark, sanitize_tag_name};
+use serde::Deserialize;
+
+// Chromium stores timestamps as microseconds since 1601-01-01 (UTC)
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+const SECONDS_BETWEEN_1601_AND_UNIX_EPOCH: i64 = 11_644_473_600;
+
+#[derive(Debug, Deserialize)]
+struct ChromiumBookmarks {
+    roots: ChromiumRoots,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChromiumRoots {
+    bookmark_bar: Option<ChromiumNode>,
+    other: Option<ChromiumNode>,
+    synced: Option<ChromiumNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum ChromiumNode {
+    Url {
+        name: String,
+        url: String,
+        date_added: Option<String>,
+    },
+    Folder {
+        name: String,
+        #[serde(default)]
+        children: Vec<ChromiumNode>,
+    },
+}
+
+pub(super) fn parse_chromium_bookmarks(
+    bytes: &[u8],
+    ignore_attribute_errors: bool,
+    folders_as_tags: bool,
+) -> Result<ParseResult, ImportError> {
+    let bookmarks: ChromiumBookmarks =
+        serde_json::from_slice(bytes).map_err(ImportError::CouldntParseChromiumBookmarks)?;
+
+    Ok(validate_potential_bookmarks(
+        collect_bookmarks(bookmarks, folders_as_tags),
+        ignore_attribute_errors,
+    ))
+}
+
+// The root folders ("Bookmarks bar", "Other bookmarks", etc.) are not turned
+// into tags; only the folders nested within them are.
+fn collect_bookmarks(
+    bookmarks: ChromiumBookmarks,
+    folders_as_tags: bool,
+) -> Vec<PotentialBookmark> {
+    let roots = bookmarks.roots;
+    let mut potential_bookmarks = Vec::new();
+    for root in [roots.bookmark_bar, roots.other, roots.synced]
+        .into_iter()
+        .flatten()
+    {
+        match root {
+            ChromiumNode::Folder { children, .. } => {
+                for child in children {
+                    collect_from_node(
+                        child,
+                        &mut Vec::new(),
+                        folders_as_tags,
+                        &mut potential_bookmarks,
+                    );
+                }
+            }
+            url => collect_from_node(
+                url,
+                &mut Vec::new(),
+                folders_as_tags,
+                &mut potential_bookmarks,
+            ),
+        }
+    }
+
+    merge_duplicate_bookmarks(potential_bookmarks)
+}
+
+fn collect_from_node(
+    node: ChromiumNode,
+    folder_tags: &mut Vec<String>,
+    folders_as_tags: bool,
+    potential_bookmarks: &mut Vec<PotentialBookmark>,
+) {
+    match node {
+        ChromiumNode::Url {
+            name,
+            url,
+            date_added,
+        } => {
+            let timestamp = date_added.as_deref().and_then(to_unix_seconds);
+            potential_bookmarks.push(PotentialBookmark {
+                uri: url,
+                title: Some(name),
+                tags: if folders_as_tags {
+                    folder_tags.clone()
+                } else {
+                    Vec::new()
+                },
+                notes: None,
+                created_at: timestamp,
+                updated_at: timestamp,
+            });
+        }
+        ChromiumNode::Folder { name, children } => {
+            let tag = sanitize_tag_name(&name);
+            if let Some(t) = &tag {
+                folder_tags.push(t.clone());
+            }
+            for child in children {
+                collect_from_node(child, folder_tags, folders_as_tags, potential_bookmarks);
+            }
+            if tag.is_some() {
+                folder_tags.pop();
+            }
+        }
+    }
+}
+
+fn to_unix_seconds(value: &str) -> Option<i64> {
+    value
+        .trim()
+        .parse::<i64>()
+        .ok()
+        .map(|t| t / MICROSECONDS_PER_SECOND - SECONDS_BETWEEN_1601_AND_UNIX_EPOCH)
+        .filter(|t| *t > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_debug_snapshot;
+
+    const BOOKMARKS: &str = r#"
+{
+   "checksum": "3b0c2e9a0f4ea0a6ff4e8b5d2c0a8e61",
+   "roots": {
+      "bookmark_bar": {
+         "children": [ {
+            "children": [ {
+               "children": [ {
+                  "date_added": "13384370620000000",
+                  "guid": "4b1d8c4e-6f3e-4c1b-9d59-3f5a8d2e1c01",
+                  "id": "7",
+                  "name": "sqlx - crates.io: Rust Package Registry",
+                  "type": "url",
+                  "url": "https://crates.io/crates/sqlx"
+               } ],
+               "date_added": "13384370592000000",
+               "date_modified": "13384394367000000",
+               "guid": "4b1d8c4e-6f3e-4c1b-9d59-3f5a8d2e1c02",
+               "id": "6",
+               "name": "Rust Crates",
+               "type": "folder"
+            }, {
+               "date_added": "13384394215000000",
+               "guid": "4b1d8c4e-6f3e-4c1b-9d59-3f5a8d2e1c03",
+               "id": "8",
+               "name": "GitHub - dhth/omm",
+               "type": "url",
+               "url": "https://github.com/dhth/omm"
+            } ],
+            "date_added": "13384370538000000",
+            "date_modified": "13384394270000000",
+            "guid": "4b1d8c4e-6f3e-4c1b-9d59-3f5a8d2e1c04",
+            "id": "5",
+            "name": "Productivity",
+            "type": "folder"
+         }, {
+            "date_added": "13384394297000000",
+            "guid": "4b1d8c4e-6f3e-4c1b-9d59-3f5a8d2e1c05",
+            "id": "9",
+            "name": "GitHub - dhth/bmm",
+            "type": "url",
+            "url": "https://github.com/dhth/bmm"
+         } ],
+         "date_added": "13384370422000000",
+         "date_modified": "13384394297000000",
+         "guid": "0bc5d13f-2cba-5d74-951f-3f233fe6c908",
+         "id": "1",
+         "name": "Bookmarks bar",
+         "type": "folder"
+      },
+      "other": {
+         "children": [ {
+            "date_added": "13384394400000000",
+            "guid": "4b1d8c4e-6f3e-4c1b-9d59-3f5a8d2e1c06",
+            "id": "10",
+            "name": "GitHub - dhth/bmm (again)",
+            "type": "url",
+            "url": "https://github.com/dhth/bmm"
+         } ],
+         "date_added": "13384370422000000",
+         "date_modified": "0",
+         "guid": "82b081ec-3dd3-529c-8475-ab6c344590dd",
+         "id": "2",
+         "name": "Other bookmarks",
+         "type": "folder"
+      },
+      "synced": {
+         "children": [  ],
+         "date_added": "13384370422000000",
+         "date_modified": "0",
+         "guid": "4cf2e351-0e85-532b-bb37-df045d8f8d0f",
+         "id": "3",
+         "name": "Mobile bookmarks",
+         "type": "folder"
+      }
+   },
+   "version": 1
+}
+"#;
+
+    #[test]
+    fn collecting_bookmarks_from_a_chromium_bookmarks_file_works() {
+        // GIVEN
+        let bookmarks: ChromiumBookmarks =
+            serde_json::from_str(BOOKMARKS).expect("bookmarks should've been deserialized");
+
+        // WHEN
+        let potential_bookmarks = collect_bookmarks(bookmarks, false);
+
+        // THEN
+        assert_debug_snapshot!(potential_bookmarks, @r#"
+        [
+            PotentialBookmark {
+                uri: "https://crates.io/crates/sqlx",
+                title: Some(
+                    "sqlx - crates.io: Rust Package Registry",
+                ),
+                tags: [],
+                notes: None,
+                created_at: Some(
+                    1739897020,
+                ),
+                updated_at: Some(
+                    1739897020,
+                ),
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/omm",
+                title: Some(
+                    "GitHub - dhth/omm",
+                ),
+                tags: [],
+                notes: None,
+                created_at: Some(
+                    1739920615,
+                ),
+                updated_at: Some(
+                    1739920615,
+                ),
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/bmm",
+                title: Some(
+                    "GitHub - dhth/bmm",
+                ),
+                tags: [],
+                notes: None,
+                created_at: Some(
+                    1739920697,
+                ),
+                updated_at: Some(
+                    1739920800,
+                ),
+            },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn collecting_bookmarks_from_a_chromium_bookmarks_file_with_folders_as_tags_works() {
+        // GIVEN
+        let bookmarks: ChromiumBookmarks =
+            serde_json::from_str(BOOKMARKS).expect("bookmarks should've been deserialized");
+
+        // WHEN
+        let potential_bookmarks = collect_bookmarks(bookmarks, true);
+
+        // THEN
+        assert_debug_snapshot!(potential_bookmarks, @r#"
+        [
+            PotentialBookmark {
+                uri: "https://crates.io/crates/sqlx",
+                title: Some(
+                    "sqlx - crates.io: Rust Package Registry",
+                ),
+                tags: [
+                    "productivity",
+                    "rust-crates",
+                ],
+                notes: None,
+                created_at: Some(
+                    1739897020,
+                ),
+                updated_at: Some(
+                    1739897020,
+                ),
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/omm",
+                title: Some(
+                    "GitHub - dhth/omm",
+                ),
+                tags: [
+                    "productivity",
+                ],
+                notes: None,
+                created_at: Some(
+                    1739920615,
+                ),
+                updated_at: Some(
+                    1739920615,
+                ),
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/bmm",
+                title: Some(
+                    "GitHub - dhth/bmm",
+                ),
+                tags: [],
+                notes: None,
+                created_at: Some(
+                    1739920697,
+                ),
+                updated_at: Some(
+                    1739920800,
+                ),
+            },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn converting_chromium_timestamps_works() {
+        // GIVEN
+        // WHEN
+        // THEN
+        assert_eq!(to_unix_seconds("13384370620000000"), Some(1739897020));
+        assert_eq!(to_unix_seconds("0"), None);
+        assert_eq!(to_unix_seconds("not-a-number"), None);
+    }
+}
@@ -0,0 +1,260 @@
+use super::{ImportError, InputEntry};
+use crate::domain::{DraftBookmark, PotentialBookmark, parse_timestamp};
+use std::io::Read;
+
+// Column names are matched case-insensitively, with spaces and hyphens treated
+// as underscores. These cover bmm's own delimited output, as well as exports
+// from services like Raindrop.io and Pocket.
+pub(super) const URI_COLUMNS: [&str; 4] = ["uri", "url", "link", "href"];
+const TITLE_COLUMNS: [&str; 2] = ["title", "name"];
+const TAGS_COLUMNS: [&str; 4] = ["tags", "tag", "labels", "keywords"];
+const NOTES_COLUMNS: [&str; 4] = ["notes", "note", "description", "excerpt"];
+const CREATED_AT_COLUMNS: [&str; 6] = [
+    "created_at",
+    "created",
+    "time_added",
+    "date_added",
+    "add_date",
+    "added",
+];
+const UPDATED_AT_COLUMNS: [&str; 6] = [
+    "updated_at",
+    "updated",
+    "time_updated",
+    "last_modified",
+    "modified",
+    "date_modified",
+];
+const TAG_SEPARATORS: [char; 2] = [',', '|'];
+
+struct ColumnIndexes {
+    uri: usize,
+    title: Option<usize>,
+    tags: Option<usize>,
+    notes: Option<usize>,
+    created_at: Option<usize>,
+    updated_at: Option<usize>,
+}
+
+impl ColumnIndexes {
+    fn from_header(header: &csv::StringRecord) -> Result<Self, ImportError> {
+        let names = header
+            .iter()
+            .map(|h| {
+                h.trim_start_matches('\u{feff}')
+                    .trim()
+                    .to_lowercase()
+                    .replace([' ', '-'], "_")
+            })
+            .collect::<Vec<_>>();
+        let find = |candidates: &[&str]| {
+            candidates
+                .iter()
+                .find_map(|c| names.iter().position(|n| n == c))
+        };
+
+        Ok(Self {
+            uri: find(&URI_COLUMNS).ok_or(ImportError::DelimitedInputHasNoURIColumn)?,
+            title: find(&TITLE_COLUMNS),
+            tags: find(&TAGS_COLUMNS),
+            notes: find(&NOTES_COLUMNS),
+            created_at: find(&CREATED_AT_COLUMNS),
+            updated_at: find(&UPDATED_AT_COLUMNS),
+        })
+    }
+}
+
+// Records are read lazily, so large files needn't be held in memory
+pub(super) fn delimited_entries<R: Read>(
+    reader: R,
+    delimiter: u8,
+    ignore_attribute_errors: bool,
+) -> Result<impl Iterator<Item = Result<InputEntry, ImportError>>, ImportError> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+
+    let columns = ColumnIndexes::from_header(
+        csv_reader
+            .headers()
+            .map_err(ImportError::CouldntParseDelimitedInput)?,
+    )?;
+
+    Ok(csv_reader
+        .into_records()
+        .enumerate()
+        .map(move |(index, record)| {
+            let record = record.map_err(ImportError::CouldntParseDelimitedInput)?;
+            let potential_bookmark =
+                to_potential_bookmark(&record, &columns, ignore_attribute_errors)?;
+
+            Ok((
+                index,
+                DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors)),
+            ))
+        }))
+}
+
+fn to_potential_bookmark(
+    record: &csv::StringRecord,
+    columns: &ColumnIndexes,
+    ignore_attribute_errors: bool,
+) -> Result<PotentialBookmark, ImportError> {
+    let line = record.position().map(|p| p.line()).unwrap_or_default();
+    let field = |index: Option<usize>| index.and_then(|i| record.get(i)).filter(|v| !v.is_empty());
+    let timestamp = |index: Option<usize>| match field(index) {
+        Some(value) => match parse_timestamp(value) {
+            Some(t) => Ok(Some(t)),
+            None if ignore_attribute_errors => Ok(None),
+            None => Err(ImportError::CouldntParseTimestamp(line, value.to_string())),
+        },
+        None => Ok(None),
+    };
+
+    Ok(PotentialBookmark {
+        uri: field(Some(columns.uri)).unwrap_or_default().to_string(),
+        title: field(columns.title).map(|t| t.to_string()),
+        tags: field(columns.tags)
+            .map(|t| {
+                t.split(TAG_SEPARATORS)
+                    .map(|t| t.trim().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default(),
+        notes: field(columns.notes).map(|n| n.to_string()),
+        created_at: timestamp(columns.created_at)?,
+        updated_at: timestamp(columns.updated_at)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::import::{ParseResult, collect_entries};
+    use insta::assert_yaml_snapshot;
+
+    fn parse_delimited_content(
+        content: &str,
+        delimiter: u8,
+        ignore_attribute_errors: bool,
+    ) -> Result<ParseResult, ImportError> {
+        collect_entries(delimited_entries(
+            content.as_bytes(),
+            delimiter,
+            ignore_attribute_errors,
+        )?)
+    }
+
+    //-------------//
+    //  SUCCESSES  //
+    //-------------//
+
+    #[test]
+    fn parsing_bmms_delimited_output_works() {
+        // GIVEN
+        let content = r#"uri,title,tags
+https://github.com/dhth/omm,"GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line","productivity,tools"
+https://github.com/dhth/hours,,
+"#;
+
+        // WHEN
+        let result =
+            parse_delimited_content(content, b',', false).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/dhth/omm"
+          title: "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line"
+          tags:
+            - productivity
+            - tools
+        - uri: "https://github.com/dhth/hours"
+          title: ~
+          tags: []
+        "#);
+    }
+
+    #[test]
+    fn parsing_delimited_content_with_aliased_columns_works() {
+        // GIVEN
+        let content = "\u{feff}Title\tURL\tTime Added\tTags\tStatus
+GitHub - dhth/omm\thttps://github.com/dhth/omm\t1739920615\tproductivity|tools\tunread
+GitHub - dhth/bmm\thttps://github.com/dhth/bmm\t2025-02-18T23:18:17.000Z\t\tarchive
+";
+
+        // WHEN
+        let result =
+            parse_delimited_content(content, b'\t', false).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/dhth/omm"
+          title: GitHub - dhth/omm
+          tags:
+            - productivity
+            - tools
+          created_at: 1739920615
+          updated_at: 1739920615
+        - uri: "https://github.com/dhth/bmm"
+          title: GitHub - dhth/bmm
+          tags: []
+          created_at: 1739920697
+          updated_at: 1739920697
+        "#);
+    }
+
+    #[test]
+    fn force_parsing_delimited_content_with_invalid_timestamps_ignores_them() {
+        // GIVEN
+        let content = "url,created\nhttps://github.com/dhth/bmm,last tuesday\n";
+
+        // WHEN
+        let result =
+            parse_delimited_content(content, b',', true).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/dhth/bmm"
+          title: ~
+          tags: []
+        "#);
+    }
+
+    //------------//
+    //  FAILURES  //
+    //------------//
+
+    #[test]
+    fn parsing_delimited_content_without_a_uri_column_fails() {
+        // GIVEN
+        let content = "title,tags\nbmm,tools\n";
+
+        // WHEN
+        let error =
+            parse_delimited_content(content, b',', false).expect_err("parsing should've failed");
+
+        // THEN
+        assert!(matches!(error, ImportError::DelimitedInputHasNoURIColumn));
+    }
+
+    #[test]
+    fn parsing_delimited_content_with_invalid_timestamps_fails() {
+        // GIVEN
+        let content = "url,created\nhttps://github.com/dhth/omm,1739920615\nhttps://github.com/dhth/bmm,last tuesday\n";
+
+        // WHEN
+        let error =
+            parse_delimited_content(content, b',', false).expect_err("parsing should've failed");
+
+        // THEN
+        assert_eq!(
+            error.to_string(),
+            r#"couldn't parse timestamp on line 3: "last tuesday""#
+        );
+    }
+}
@@ -0,0 +1,493 @@
+use super::{ImportError, InputEntry, merge_duplicate_bookmarks};
+use crate::common::SAVE_CHUNK_SIZE;
+use crate::domain::{DraftBookmark, PotentialBookmark, sanitize_tag_name};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteConnection};
+use sqlx::{ConnectOptions, FromRow, QueryBuilder, Sqlite};
+use std::collections::HashMap;
+use std::path::Path;
+
+const BOOKMARK_TYPE_URL: i64 = 1;
+const BOOKMARK_TYPE_FOLDER: i64 = 2;
+const TAGS_ROOT_GUID: &str = "tags________";
+const ROOT_GUIDS: [&str; 6] = [
+    "root________",
+    "menu________",
+    "toolbar_____",
+    "unfiled_____",
+    "mobile______",
+    TAGS_ROOT_GUID,
+];
+const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
+
+// Completed with the type of entries to read
+const MOZ_BOOKMARKS_QUERY: &str = "
+SELECT
+    b.id,
+    b.type AS kind,
+    b.parent,
+    b.title,
+    b.guid,
+    b.dateAdded AS date_added,
+    b.lastModified AS last_modified,
+    p.url,
+    p.title AS place_title
+FROM
+    moz_bookmarks b
+    LEFT JOIN moz_places p ON b.fk = p.id
+WHERE
+    b.type = ";
+
+// Bookmarked places (leaving out tags and smart bookmarks), in the order they
+// were first bookmarked; a page continues after the last place read
+const MOZ_PLACES_PAGE_QUERY: &str = "
+SELECT
+    b.fk AS place_id,
+    MIN(b.id) AS first_id
+FROM
+    moz_bookmarks b
+    JOIN moz_places p ON b.fk = p.id
+WHERE
+    b.type = ?
+    AND substr(p.url, 1, 6) != 'place:'
+    AND b.parent NOT IN (
+        SELECT
+            id
+        FROM
+            moz_bookmarks
+        WHERE
+            parent IN (
+                SELECT
+                    id
+                FROM
+                    moz_bookmarks
+                WHERE
+                    guid = ?
+            )
+    )
+GROUP BY
+    b.fk
+HAVING
+    MIN(b.id) > ?
+ORDER BY
+    first_id
+LIMIT
+    ?
+";
+
+#[derive(Debug, FromRow)]
+struct MozBookmark {
+    id: i64,
+    kind: i64,
+    parent: Option<i64>,
+    title: Option<String>,
+    guid: String,
+    date_added: Option<i64>,
+    last_modified: Option<i64>,
+    url: Option<String>,
+    place_title: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+struct MozPlace {
+    place_id: i64,
+    first_id: i64,
+}
+
+// Folders are needed to tell tags apart from bookmarks, and to turn folders
+// into tags; there are usually few of them, so they're read up front.
+struct Folders {
+    by_id: HashMap<i64, MozBookmark>,
+    tags_root_id: Option<i64>,
+}
+
+impl From<Vec<MozBookmark>> for Folders {
+    fn from(entries: Vec<MozBookmark>) -> Self {
+        let by_id = entries
+            .into_iter()
+            .filter(|e| e.kind == BOOKMARK_TYPE_FOLDER)
+            .map(|e| (e.id, e))
+            .collect::<HashMap<_, _>>();
+        let tags_root_id = by_id
+            .values()
+            .find(|f| f.guid == TAGS_ROOT_GUID)
+            .map(|f| f.id);
+
+        Self {
+            by_id,
+            tags_root_id,
+        }
+    }
+}
+
+// Bookmarks are read a page of places at a time; all entries for a place are
+// part of the same page, so that duplicates can be merged.
+pub(super) struct FirefoxReader {
+    conn: SqliteConnection,
+    folders: Folders,
+    last_first_id: i64,
+    num_read: usize,
+    ignore_attribute_errors: bool,
+    folders_as_tags: bool,
+}
+
+impl FirefoxReader {
+    // The database is opened as immutable so that reading it works even if
+    // it's in use by Firefox, and so that bmm never writes to it.
+    pub(super) async fn open(
+        path: &Path,
+        ignore_attribute_errors: bool,
+        folders_as_tags: bool,
+    ) -> Result<Self, ImportError> {
+        let mut conn = SqliteConnectOptions::new()
+            .filename(path)
+            .read_only(true)
+            .immutable(true)
+            .connect()
+            .await
+            .map_err(ImportError::CouldntReadFirefoxDatabase)?;
+
+        let mut query_builder = QueryBuilder::<Sqlite>::new(MOZ_BOOKMARKS_QUERY);
+        query_builder.push_bind(BOOKMARK_TYPE_FOLDER);
+        let folders = query_builder
+            .build_query_as::<MozBookmark>()
+            .fetch_all(&mut conn)
+            .await
+            .map_err(ImportError::CouldntReadFirefoxDatabase)?;
+
+        Ok(Self {
+            conn,
+            folders: Folders::from(folders),
+            last_first_id: 0,
+            num_read: 0,
+            ignore_attribute_errors,
+            folders_as_tags,
+        })
+    }
+
+    /// Returns an empty page once all bookmarks have been read.
+    pub(super) async fn next_page(&mut self) -> Result<Vec<InputEntry>, ImportError> {
+        let entries = self
+            .read_next_page()
+            .await
+            .map_err(ImportError::CouldntReadFirefoxDatabase)?;
+
+        let page = collect_bookmarks(&entries, &self.folders, self.folders_as_tags)
+            .into_iter()
+            .enumerate()
+            .map(|(index, potential_bookmark)| {
+                (
+                    self.num_read + index,
+                    DraftBookmark::try_from((potential_bookmark, self.ignore_attribute_errors)),
+                )
+            })
+            .collect::<Vec<_>>();
+        self.num_read += page.len();
+
+        Ok(page)
+    }
+
+    async fn read_next_page(&mut self) -> Result<Vec<MozBookmark>, sqlx::Error> {
+        let places = sqlx::query_as::<_, MozPlace>(MOZ_PLACES_PAGE_QUERY)
+            .bind(BOOKMARK_TYPE_URL)
+            .bind(TAGS_ROOT_GUID)
+            .bind(self.last_first_id)
+            .bind(SAVE_CHUNK_SIZE as i64)
+            .fetch_all(&mut self.conn)
+            .await?;
+
+        let Some(last) = places.last() else {
+            return Ok(Vec::new());
+        };
+        self.last_first_id = last.first_id;
+
+        // tag entries for the places are read as well
+        let mut query_builder = QueryBuilder::<Sqlite>::new(MOZ_BOOKMARKS_QUERY);
+        query_builder.push_bind(BOOKMARK_TYPE_URL);
+        query_builder.push(" AND b.fk IN (");
+        let mut separated = query_builder.separated(", ");
+        for place in &places {
+            separated.push_bind(place.place_id);
+        }
+        separated.push_unseparated(") ORDER BY b.id");
+
+        query_builder
+            .build_query_as::<MozBookmark>()
+            .fetch_all(&mut self.conn)
+            .await
+    }
+}
+
+// Firefox stores tags as folders under the "tags" root, each holding an entry
+// for every bookmarked place carrying that tag. Everything else of type URL is
+// an actual bookmark.
+fn collect_bookmarks(
+    entries: &[MozBookmark],
+    folders: &Folders,
+    folders_as_tags: bool,
+) -> Vec<PotentialBookmark> {
+    let mut bookmark_entries = Vec::new();
+    let mut firefox_tags: HashMap<&str, Vec<String>> = HashMap::new();
+    for entry in entries.iter().filter(|e| e.kind == BOOKMARK_TYPE_URL) {
+        let Some(uri) = entry.url.as_deref() else {
+            continue;
+        };
+        // smart bookmarks (eg. "Most Visited") point to queries, not web pages
+        if uri.starts_with("place:") {
+            continue;
+        }
+
+        let parent_folder = entry.parent.and_then(|p| folders.by_id.get(&p));
+        match parent_folder {
+            Some(tag_folder)
+                if folders.tags_root_id.is_some() && tag_folder.parent == folders.tags_root_id =>
+            {
+                if let Some(tag) = tag_folder.title.as_deref().and_then(sanitize_tag_name) {
+                    firefox_tags.entry(uri).or_default().push(tag);
+                }
+            }
+            _ => bookmark_entries.push((uri, entry)),
+        }
+    }
+
+    let potential_bookmarks = bookmark_entries
+        .into_iter()
+        .map(|(uri, entry)| {
+            let mut tags = if folders_as_tags {
+                get_folder_tags(entry, &folders.by_id)
+            } else {
+                Vec::new()
+            };
+            tags.extend(firefox_tags.get(uri).cloned().unwrap_or_default());
+
+            PotentialBookmark {
+                uri: uri.to_string(),
+                title: entry
+                    .title
+                    .as_deref()
+                    .or(entry.place_title.as_deref())
+                    .filter(|t| !t.trim().is_empty())
+                    .map(|t| t.to_string()),
+                tags,
+                notes: None,
+                created_at: to_unix_seconds(entry.date_added),
+                updated_at: to_unix_seconds(entry.last_modified),
+            }
+        })
+        .collect();
+
+    merge_duplicate_bookmarks(potential_bookmarks)
+}
+
+fn get_folder_tags(entry: &MozBookmark, folders: &HashMap<i64, MozBookmark>) -> Vec<String> {
+    let mut folder_tags = Vec::new();
+    let mut parent = entry.parent;
+    let mut depth = 0;
+    // the depth check guards against cycles in a corrupted database
+    while let Some(folder) = parent.and_then(|p| folders.get(&p))
+        && depth <= folders.len()
+    {
+        depth += 1;
+        if !ROOT_GUIDS.contains(&folder.guid.as_str())
+            && let Some(tag) = folder.title.as_deref().and_then(sanitize_tag_name)
+        {
+            folder_tags.push(tag);
+        }
+        parent = folder.parent;
+    }
+
+    folder_tags.reverse();
+    folder_tags
+}
+
+fn to_unix_seconds(microseconds: Option<i64>) -> Option<i64> {
+    microseconds
+        .map(|t| t / MICROSECONDS_PER_SECOND)
+        .filter(|t| *t > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_debug_snapshot;
+
+    fn folder(id: i64, parent: i64, title: &str, guid: &str) -> MozBookmark {
+        MozBookmark {
+            id,
+            kind: BOOKMARK_TYPE_FOLDER,
+            parent: Some(parent),
+            title: Some(title.to_string()),
+            guid: guid.to_string(),
+            date_added: None,
+            last_modified: None,
+            url: None,
+            place_title: None,
+        }
+    }
+
+    fn bookmark(id: i64, parent: i64, url: &str, title: Option<&str>) -> MozBookmark {
+        MozBookmark {
+            id,
+            kind: BOOKMARK_TYPE_URL,
+            parent: Some(parent),
+            title: title.map(|t| t.to_string()),
+            guid: format!("bookmark{id:04}"),
+            date_added: Some((1739897020 + id) * MICROSECONDS_PER_SECOND),
+            last_modified: Some((1739897041 + id) * MICROSECONDS_PER_SECOND),
+            url: Some(url.to_string()),
+            place_title: Some(format!("place title for {url}")),
+        }
+    }
+
+    fn places() -> Vec<MozBookmark> {
+        vec![
+            folder(1, 0, "", "root________"),
+            folder(2, 1, "menu", "menu________"),
+            folder(3, 1, "toolbar", "toolbar_____"),
+            folder(4, 1, "tags", "tags________"),
+            folder(5, 1, "unfiled", "unfiled_____"),
+            folder(6, 3, "Productivity", "folder000006"),
+            folder(7, 6, "Rust Crates", "folder000007"),
+            bookmark(8, 7, "https://crates.io/crates/sqlx", Some("sqlx")),
+            bookmark(9, 6, "https://github.com/dhth/omm", None),
+            bookmark(10, 2, "https://github.com/dhth/bmm", Some("bmm")),
+            bookmark(11, 5, "https://crates.io/crates/sqlx", Some("sqlx again")),
+            bookmark(12, 2, "place:sort=8&maxResults=10", Some("Most Visited")),
+            folder(13, 4, "Rust", "folder000013"),
+            bookmark(14, 13, "https://crates.io/crates/sqlx", None),
+            bookmark(15, 13, "https://github.com/dhth/bmm", None),
+            folder(16, 4, "command line", "folder000016"),
+            bookmark(17, 16, "https://github.com/dhth/omm", None),
+        ]
+    }
+
+    #[test]
+    fn collecting_bookmarks_from_firefox_places_works() {
+        // GIVEN
+        let entries = places();
+        let folders = Folders::from(places());
+
+        // WHEN
+        let bookmarks = collect_bookmarks(&entries, &folders, false);
+
+        // THEN
+        assert_debug_snapshot!(bookmarks, @r#"
+        [
+            PotentialBookmark {
+                uri: "https://crates.io/crates/sqlx",
+                title: Some(
+                    "sqlx",
+                ),
+                tags: [
+                    "rust",
+                ],
+                notes: None,
+                created_at: Some(
+                    1739897028,
+                ),
+                updated_at: Some(
+                    1739897052,
+                ),
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/omm",
+                title: Some(
+                    "place title for https://github.com/dhth/omm",
+                ),
+                tags: [
+                    "command-line",
+                ],
+                notes: None,
+                created_at: Some(
+                    1739897029,
+                ),
+                updated_at: Some(
+                    1739897050,
+                ),
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/bmm",
+                title: Some(
+                    "bmm",
+                ),
+                tags: [
+                    "rust",
+                ],
+                notes: None,
+                created_at: Some(
+                    1739897030,
+                ),
+                updated_at: Some(
+                    1739897051,
+                ),
+            },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn collecting_bookmarks_from_firefox_places_with_folders_as_tags_works() {
+        // GIVEN
+        let entries = places();
+        let folders = Folders::from(places());
+
+        // WHEN
+        let bookmarks = collect_bookmarks(&entries, &folders, true);
+
+        // THEN
+        assert_debug_snapshot!(bookmarks, @r#"
+        [
+            PotentialBookmark {
+                uri: "https://crates.io/crates/sqlx",
+                title: Some(
+                    "sqlx",
+                ),
+                tags: [
+                    "productivity",
+                    "rust-crates",
+                    "rust",
+                ],
+                notes: None,
+                created_at: Some(
+                    1739897028,
+                ),
+                updated_at: Some(
+                    1739897052,
+                ),
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/omm",
+                title: Some(
+                    "place title for https://github.com/dhth/omm",
+                ),
+                tags: [
+                    "productivity",
+                    "command-line",
+                ],
+                notes: None,
+                created_at: Some(
+                    1739897029,
+                ),
+                updated_at: Some(
+                    1739897050,
+                ),
+            },
+            PotentialBookmark {
+                uri: "https://github.com/dhth/bmm",
+                title: Some(
+                    "bmm",
+                ),
+                tags: [
+                    "rust",
+                ],
+                notes: None,
+                created_at: Some(
+                    1739897030,
+                ),
+                updated_at: Some(
+                    1739897051,
+                ),
+            },
+        ]
+        "#);
+    }
+}
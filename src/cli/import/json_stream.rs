@@ -0,0 +1,277 @@
+use super::ImportError;
+use crate::common::SAVE_CHUNK_SIZE;
+use crate::domain::{BOOKMARKS_EXPORT_VERSION, ExportedBookmark};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor,
+};
+use serde::{Deserialize, Deserializer};
+use std::cell::Cell;
+use std::fmt;
+use std::io::Read;
+use std::marker::PhantomData;
+use std::sync::mpsc::{SyncSender, sync_channel};
+use std::thread;
+
+type Sender<T> = SyncSender<Result<T, ImportError>>;
+
+// serde_json can only read a JSON array by driving a visitor over all of its
+// elements; to hand them out one at a time instead, the array is read on a
+// separate thread, which sends elements over a bounded channel. This way, at
+// most one chunk's worth of elements is held in memory at any point.
+fn read_on_thread<T, R, F>(reader: R, read: F) -> impl Iterator<Item = Result<T, ImportError>>
+where
+    T: Send + 'static,
+    R: Read + Send + 'static,
+    F: FnOnce(
+            &mut serde_json::Deserializer<serde_json::de::IoRead<R>>,
+            &Sender<T>,
+        ) -> Result<(), ImportError>
+        + Send
+        + 'static,
+{
+    let (sender, receiver) = sync_channel(SAVE_CHUNK_SIZE);
+    thread::spawn(move || {
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        if let Err(e) = read(&mut deserializer, &sender) {
+            // the receiver is gone if the import stopped early, in which case
+            // there's no one left to report the error to
+            let _ = sender.send(Err(e));
+        }
+    });
+
+    receiver.into_iter()
+}
+
+/// Reads the elements of a top level JSON array one by one.
+pub(super) fn json_array_elements<T, R>(
+    reader: R,
+    to_error: fn(serde_json::Error) -> ImportError,
+) -> impl Iterator<Item = Result<T, ImportError>>
+where
+    T: DeserializeOwned + Send + 'static,
+    R: Read + Send + 'static,
+{
+    read_on_thread(reader, move |deserializer, sender| {
+        ElementsSeed::new(sender)
+            .deserialize(&mut *deserializer)
+            .and_then(|_| deserializer.end())
+            .map_err(to_error)
+    })
+}
+
+/// Reads the bookmarks of a file written by `bmm export --format json` one by
+/// one, after checking that its version is supported.
+pub(super) fn bookmarks_export_elements<R>(
+    reader: R,
+) -> impl Iterator<Item = Result<ExportedBookmark, ImportError>>
+where
+    R: Read + Send + 'static,
+{
+    read_on_thread(reader, |deserializer, sender| {
+        let unsupported_version = Cell::new(None);
+        let result = deserializer
+            .deserialize_struct(
+                "BookmarksExport",
+                &["version", "bookmarks"],
+                ExportVisitor {
+                    sender,
+                    unsupported_version: &unsupported_version,
+                },
+            )
+            .and_then(|_| deserializer.end());
+
+        match unsupported_version.get() {
+            Some(version) => Err(ImportError::UnsupportedExportVersion(version)),
+            None => Ok(result?),
+        }
+    })
+}
+
+// Stops deserialization once the receiving end is gone
+fn send<T, E: de::Error>(sender: &Sender<T>, value: T) -> Result<(), E> {
+    sender
+        .send(Ok(value))
+        .map_err(|_| E::custom("import was stopped"))
+}
+
+struct ElementsSeed<'a, T> {
+    sender: &'a Sender<T>,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> ElementsSeed<'a, T> {
+    fn new(sender: &'a Sender<T>) -> Self {
+        Self {
+            sender,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for ElementsSeed<'_, T> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ElementsSeed<'_, T> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(element) = seq.next_element()? {
+            send(self.sender, element)?;
+        }
+
+        Ok(())
+    }
+}
+
+// The version is usually the first key; bookmarks that show up before it are
+// held back until it's known to be supported.
+struct ExportVisitor<'a> {
+    sender: &'a Sender<ExportedBookmark>,
+    unsupported_version: &'a Cell<Option<u32>>,
+}
+
+impl<'de> Visitor<'de> for ExportVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("struct BookmarksExport")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut version_seen = false;
+        let mut bookmarks_seen = false;
+        let mut held_back: Vec<ExportedBookmark> = Vec::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "version" => {
+                    let version = map.next_value::<u32>()?;
+                    if version != BOOKMARKS_EXPORT_VERSION {
+                        self.unsupported_version.set(Some(version));
+                        return Err(de::Error::custom("unsupported export version"));
+                    }
+                    version_seen = true;
+                    for bookmark in held_back.drain(..) {
+                        send(self.sender, bookmark)?;
+                    }
+                }
+                "bookmarks" if version_seen => {
+                    map.next_value_seed(ElementsSeed::new(self.sender))?;
+                    bookmarks_seen = true;
+                }
+                "bookmarks" => {
+                    held_back = map.next_value()?;
+                    bookmarks_seen = true;
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        if !version_seen {
+            return Err(de::Error::missing_field("version"));
+        }
+        if !bookmarks_seen {
+            return Err(de::Error::missing_field("bookmarks"));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reading_json_array_elements_works() {
+        // GIVEN
+        let content = r#"[{"a": 1}, {"a": 2}, {"a": 3}]"#;
+
+        // WHEN
+        let elements = json_array_elements::<serde_json::Value, _>(
+            content.as_bytes(),
+            ImportError::CouldntDeserializeJSONInput,
+        )
+        .collect::<Result<Vec<_>, _>>()
+        .expect("reading should've succeeded");
+
+        // THEN
+        assert_eq!(elements.len(), 3);
+    }
+
+    #[test]
+    fn reading_json_array_elements_returns_elements_before_an_error() {
+        // GIVEN
+        let content = r#"[{"a": 1}, {"a": 2}, {"a": "#;
+
+        // WHEN
+        let results = json_array_elements::<serde_json::Value, _>(
+            content.as_bytes(),
+            ImportError::CouldntDeserializeJSONInput,
+        )
+        .collect::<Vec<_>>();
+
+        // THEN
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(results[2].is_err());
+    }
+
+    #[test]
+    fn reading_bookmarks_export_elements_works_if_the_version_comes_last() {
+        // GIVEN
+        let content = r#"{"bookmarks": [{"uri": "https://github.com/dhth/bmm"}], "version": 1}"#;
+
+        // WHEN
+        let bookmarks = bookmarks_export_elements(content.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .expect("reading should've succeeded");
+
+        // THEN
+        assert_eq!(bookmarks.len(), 1);
+    }
+
+    #[test]
+    fn reading_bookmarks_export_elements_fails_for_unsupported_versions() {
+        // GIVEN
+        let content = r#"{"version": 99, "bookmarks": [{"uri": "https://github.com/dhth/bmm"}]}"#;
+
+        // WHEN
+        let results = bookmarks_export_elements(content.as_bytes()).collect::<Vec<_>>();
+
+        // THEN
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(ImportError::UnsupportedExportVersion(99))
+        ));
+    }
+
+    #[test]
+    fn reading_bookmarks_export_elements_fails_without_a_version() {
+        // GIVEN
+        let content = r#"{"bookmarks": []}"#;
+
+        // WHEN
+        let results = bookmarks_export_elements(content.as_bytes()).collect::<Vec<_>>();
+
+        // THEN
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(ImportError::CouldntDeserializeJSONInput(_))
+        ));
+    }
+}
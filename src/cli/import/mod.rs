@@ -0,0 +1,1473 @@
+mod buku;
+mod chromium;
+mod delimited;
+mod firefox;
+mod json_stream;
+mod pinboard;
+mod pocket;
+
+use delimited::URI_COLUMNS;
+
+use super::chunks::{InputEntry, ValidatedChunk, next_chunk, report_partial_save, report_progress};
+use super::display::DisplayError;
+use crate::common::{
+    BUKU, BUKU_DATABASE_FILE_NAME, CHROMIUM, CHROMIUM_BOOKMARKS_FILE_NAME, CSV, FIREFOX,
+    FIREFOX_DATABASE_EXTENSION, HTML, IMPORT_FILE_FORMATS, JSON, JSONL, PINBOARD, POCKET, TSV, TXT,
+};
+use crate::domain::{
+    BOOKMARKS_EXPORT_VERSION, DraftBookmark, DraftBookmarkError, DraftBookmarkErrors,
+    ExportedBookmark, PotentialBookmark, PotentialImportedBookmark, SaveReport, TagAliases,
+    parse_timestamp, sanitize_tag_name,
+};
+use crate::persistence::{
+    DBError, SaveBookmarkOptions, create_or_update_bookmarks, get_tag_aliases,
+};
+use select::document::Document;
+use select::node::Node;
+use select::predicate::{Element, Name};
+use sqlx::{Pool, Sqlite};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Error as IOError;
+use std::io::{BufRead, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Passing this as the path makes bmm read the input from stdin
+const STDIN_PATH: &str = "-";
+
+type ParseResult = Result<Vec<DraftBookmark>, Vec<(usize, DraftBookmarkError)>>;
+type Entries<'a> = Box<dyn Iterator<Item = Result<InputEntry, ImportError>> + 'a>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ImportError {
+    #[error("file has no extension")]
+    FileHasNoExtension,
+    #[error("file doesn't exist")]
+    FileDoesntExist,
+    #[error("couldn't open file: {0}")]
+    CouldntOpenFile(#[source] IOError),
+    #[error("couldn't read file: {0}")]
+    CouldntReadFile(#[source] IOError),
+    #[error("couldn't read stdin: {0}")]
+    CouldntReadStdin(#[source] IOError),
+    #[error("the format of the input needs to be provided via --format when reading from stdin")]
+    FormatNeededForStdin,
+    #[error("{0} input can't be read from stdin; provide the path to the file instead")]
+    FormatCantBeReadFromStdin(String),
+    #[error("couldn't parse HTML input: {0}")]
+    CouldntParseHTMLInput(#[source] IOError),
+    #[error("couldn't parse JSON input: {0}")]
+    CouldntDeserializeJSONInput(#[from] serde_json::Error),
+    #[error("couldn't parse delimited input: {0}")]
+    CouldntParseDelimitedInput(#[source] csv::Error),
+    #[error("couldn't find a column for URIs in the header row (expected one of: {URI_COLUMNS:?})")]
+    DelimitedInputHasNoURIColumn,
+    #[error("couldn't parse timestamp on line {0}: \"{1}\"")]
+    CouldntParseTimestamp(u64, String),
+    #[error("couldn't parse timestamp of entry {0}: \"{1}\"")]
+    CouldntParseEntryTimestamp(usize, String),
+    #[error("couldn't parse Chromium bookmarks file: {0}")]
+    CouldntParseChromiumBookmarks(#[source] serde_json::Error),
+    #[error("couldn't parse Pinboard export: {0}")]
+    CouldntParsePinboardExport(#[source] serde_json::Error),
+    #[error("couldn't read Firefox bookmarks database: {0}")]
+    CouldntReadFirefoxDatabase(#[source] sqlx::Error),
+    #[error("couldn't read buku database: {0}")]
+    CouldntReadBukuDatabase(#[source] sqlx::Error),
+    #[error("couldn't parse JSON input on line {0}: {1}")]
+    CouldntDeserializeJSONLine(usize, #[source] serde_json::Error),
+    #[error("export version {0} is not supported (supported version: {BOOKMARKS_EXPORT_VERSION})")]
+    UnsupportedExportVersion(u32),
+    #[error("file format \"{0}\" not supported (supported formats: {IMPORT_FILE_FORMATS:?})")]
+    FileFormatNotSupported(String),
+    #[error("{}\n\n{}", errors.msg(), errors)]
+    ValidationError { errors: DraftBookmarkErrors },
+    #[error("couldn't get tag aliases: {0}")]
+    CouldntGetTagAliases(#[source] DBError),
+    #[error("couldn't save bookmarks to bmm's database: {0}")]
+    SaveError(#[from] DBError),
+    #[error("couldn't display report: {0}")]
+    CouldntDisplayReport(#[source] DisplayError),
+    #[error("something unexpected happened: {0}")]
+    UnexpectedError(String),
+}
+
+#[derive(Debug)]
+pub struct ImportStats {
+    pub report: SaveReport,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ImportOptions {
+    pub reset_missing: bool,
+    pub dry_run: bool,
+    pub ignore_attribute_errors: bool,
+    pub folders_as_tags: bool,
+    // whether the report should list the outcome for every bookmark
+    pub list_bookmarks: bool,
+}
+
+pub async fn import_bookmarks(
+    pool: &Pool<Sqlite>,
+    path: &str,
+    format: Option<&str>,
+    options: ImportOptions,
+) -> Result<Option<ImportStats>, ImportError> {
+    let ImportOptions {
+        reset_missing,
+        dry_run,
+        ignore_attribute_errors,
+        folders_as_tags,
+        list_bookmarks,
+    } = options;
+    let from_stdin = path == STDIN_PATH;
+    let pathbuf = PathBuf::from(path);
+    if !from_stdin && !pathbuf.exists() {
+        return Err(ImportError::FileDoesntExist);
+    }
+
+    let format = match format {
+        Some(f) => f,
+        None if from_stdin => return Err(ImportError::FormatNeededForStdin),
+        None => infer_import_format(&pathbuf)?,
+    };
+
+    // Most formats are read lazily, chunk by chunk, and databases page by
+    // page. HTML files (including Pocket's HTML export) and Chromium's
+    // bookmarks file are trees that can only be parsed as a whole.
+    let mut input = match format {
+        FIREFOX | BUKU if from_stdin => {
+            return Err(ImportError::FormatCantBeReadFromStdin(format.into()));
+        }
+        FIREFOX => Input::Firefox(
+            firefox::FirefoxReader::open(&pathbuf, ignore_attribute_errors, folders_as_tags)
+                .await?,
+        ),
+        BUKU => Input::Buku(buku::BukuReader::open(&pathbuf, ignore_attribute_errors).await?),
+        TXT => Input::Entries(Box::new(text_entries(read_lines(&pathbuf, from_stdin)?))),
+        JSONL => Input::Entries(Box::new(jsonl_entries(
+            read_lines(&pathbuf, from_stdin)?,
+            ignore_attribute_errors,
+        ))),
+        CSV | TSV => {
+            let delimiter = if format == TSV { b'\t' } else { b',' };
+
+            Input::Entries(Box::new(delimited::delimited_entries(
+                open_input(&pathbuf, from_stdin)?,
+                delimiter,
+                ignore_attribute_errors,
+            )?))
+        }
+        JSON => Input::Entries(json_entries(
+            open_input(&pathbuf, from_stdin)?,
+            ignore_attribute_errors,
+            read_error(from_stdin),
+        )?),
+        PINBOARD => Input::Entries(Box::new(pinboard::pinboard_entries(
+            open_input(&pathbuf, from_stdin)?,
+            ignore_attribute_errors,
+        ))),
+        POCKET => Input::Entries(pocket::pocket_entries(
+            open_input(&pathbuf, from_stdin)?,
+            ignore_attribute_errors,
+            read_error(from_stdin),
+        )?),
+        _ => {
+            let mut bytes = Vec::new();
+            open_input(&pathbuf, from_stdin)?
+                .read_to_end(&mut bytes)
+                .map_err(read_error(from_stdin))?;
+
+            Input::Entries(parsed_entries(parse_content(
+                bytes.as_slice(),
+                format,
+                ignore_attribute_errors,
+                folders_as_tags,
+            )?)?)
+        }
+    };
+
+    // bookmarks are validated while being parsed; tag aliases are resolved
+    // afterwards, chunk by chunk
+    let tag_aliases = TagAliases::from(
+        get_tag_aliases(pool)
+            .await
+            .map_err(ImportError::CouldntGetTagAliases)?,
+    );
+
+    let start = SystemTime::now();
+    let since_the_epoch = start
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| ImportError::UnexpectedError(format!("system time error: {e}")))?;
+    let now = since_the_epoch.as_secs() as i64;
+    let save_options = SaveBookmarkOptions {
+        reset_missing_attributes: reset_missing,
+        reset_tags: reset_missing,
+    };
+
+    let mut report = SaveReport::new(list_bookmarks);
+    let mut num_bookmarks_shown = 0;
+    loop {
+        let chunk = match input.next_chunk().await {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break,
+            Err(e) => {
+                if !dry_run {
+                    report_partial_save(report.num_bookmarks());
+                }
+                return Err(e);
+            }
+        };
+
+        let mut draft_bookmarks = match chunk {
+            ParseResult::Ok(b) => b,
+            ParseResult::Err(errs) => {
+                if !dry_run {
+                    report_partial_save(report.num_bookmarks());
+                }
+                return Err(ImportError::ValidationError {
+                    errors: DraftBookmarkErrors { errors: errs },
+                });
+            }
+        };
+
+        for draft_bookmark in &mut draft_bookmarks {
+            draft_bookmark.resolve_tag_aliases(&tag_aliases);
+        }
+
+        if dry_run {
+            print_dry_run_chunk(&draft_bookmarks, num_bookmarks_shown == 0)?;
+            num_bookmarks_shown += draft_bookmarks.len();
+            continue;
+        }
+
+        let outcomes =
+            match create_or_update_bookmarks(pool, &draft_bookmarks, now, save_options).await {
+                Ok(o) => o,
+                Err(e) => {
+                    report_partial_save(report.num_bookmarks());
+                    return Err(e.into());
+                }
+            };
+        report.add(&draft_bookmarks, &outcomes);
+        report_progress(draft_bookmarks.len(), report.num_bookmarks());
+    }
+
+    if dry_run {
+        if num_bookmarks_shown == 0 {
+            println!("[]");
+        } else {
+            println!("\n]");
+        }
+
+        return Ok(None);
+    }
+
+    Ok(Some(ImportStats { report }))
+}
+
+// Where bookmarks are read from: files yield entries one by one, databases a
+// page of entries at a time
+enum Input<'a> {
+    Entries(Entries<'a>),
+    Firefox(firefox::FirefoxReader),
+    Buku(buku::BukuReader),
+}
+
+impl Input<'_> {
+    async fn next_chunk(&mut self) -> Result<Option<ValidatedChunk>, ImportError> {
+        let page = match self {
+            Input::Entries(entries) => return next_chunk(entries),
+            Input::Firefox(reader) => reader.next_page().await?,
+            Input::Buku(reader) => reader.next_page().await?,
+        };
+
+        next_chunk(&mut page.into_iter().map(Ok))
+    }
+}
+
+fn read_error(from_stdin: bool) -> fn(IOError) -> ImportError {
+    if from_stdin {
+        ImportError::CouldntReadStdin
+    } else {
+        ImportError::CouldntReadFile
+    }
+}
+
+// The input has to be Send, as JSON input is read on a separate thread
+fn open_input(path: &Path, from_stdin: bool) -> Result<Box<dyn BufRead + Send>, ImportError> {
+    if from_stdin {
+        return Ok(Box::new(BufReader::new(std::io::stdin())));
+    }
+
+    let file = File::open(path).map_err(ImportError::CouldntOpenFile)?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn read_lines(
+    path: &Path,
+    from_stdin: bool,
+) -> Result<impl Iterator<Item = Result<String, ImportError>>, ImportError> {
+    let to_import_error = read_error(from_stdin);
+    let lines = open_input(path, from_stdin)?.lines();
+
+    Ok(lines.map(move |line| line.map_err(to_import_error)))
+}
+
+// Formats that can only be parsed as a whole are validated up front; nothing
+// is saved if any of their entries are invalid. Other formats are validated
+// chunk by chunk, so chunks before an invalid entry might already be saved.
+fn parsed_entries(parse_result: ParseResult) -> Result<Entries<'static>, ImportError> {
+    match parse_result {
+        ParseResult::Ok(draft_bookmarks) => Ok(Box::new(
+            draft_bookmarks
+                .into_iter()
+                .enumerate()
+                .map(|(index, db)| Ok((index, Ok(db)))),
+        )),
+        ParseResult::Err(errs) => Err(ImportError::ValidationError {
+            errors: DraftBookmarkErrors { errors: errs },
+        }),
+    }
+}
+
+// Reads all entries of a lazily read input; used to test parsers in isolation
+#[cfg(test)]
+fn collect_entries<I>(entries: I) -> Result<ParseResult, ImportError>
+where
+    I: IntoIterator<Item = Result<InputEntry, ImportError>>,
+{
+    let mut validation_errors = Vec::new();
+    let mut draft_bookmarks = Vec::new();
+    for entry in entries {
+        let (index, result) = entry?;
+        match result {
+            Ok(db) => draft_bookmarks.push(db),
+            Err(e) => validation_errors.push((index, e)),
+        }
+    }
+
+    if validation_errors.is_empty() {
+        Ok(ParseResult::Ok(draft_bookmarks))
+    } else {
+        Ok(ParseResult::Err(validation_errors))
+    }
+}
+
+// The output of a dry run is written chunk by chunk, but forms a single JSON
+// array (formatted the same way serde_json would format it)
+fn print_dry_run_chunk(
+    draft_bookmarks: &[DraftBookmark],
+    is_first_chunk: bool,
+) -> Result<(), ImportError> {
+    for (index, db) in draft_bookmarks.iter().enumerate() {
+        let output = serde_json::to_string_pretty(db).map_err(|e| {
+            ImportError::UnexpectedError(format!(
+                "couldn't serialize list of bookmarks to JSON: {e}"
+            ))
+        })?;
+        let separator = if is_first_chunk && index == 0 {
+            "[\n"
+        } else {
+            ",\n"
+        };
+        let indented = output
+            .lines()
+            .map(|line| format!("  {line}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        print!("{separator}{indented}");
+    }
+
+    Ok(())
+}
+
+fn parse_content(
+    bytes: &[u8],
+    format: &str,
+    ignore_attribute_errors: bool,
+    folders_as_tags: bool,
+) -> Result<ParseResult, ImportError> {
+    let result = match format {
+        HTML => parse_html_content(bytes, ignore_attribute_errors, folders_as_tags)
+            .map_err(ImportError::CouldntParseHTMLInput)?,
+        CHROMIUM => {
+            chromium::parse_chromium_bookmarks(bytes, ignore_attribute_errors, folders_as_tags)?
+        }
+        ext => {
+            return Err(ImportError::FileFormatNotSupported(ext.into()));
+        }
+    };
+
+    Ok(result)
+}
+
+// Chromium based browsers store bookmarks in a file called "Bookmarks", without
+// an extension, and buku in one called "bookmarks.db"; for everything else, the
+// file's extension decides the format.
+fn infer_import_format(path: &Path) -> Result<&str, ImportError> {
+    match path.file_name().and_then(|n| n.to_str()) {
+        Some(CHROMIUM_BOOKMARKS_FILE_NAME) => return Ok(CHROMIUM),
+        Some(BUKU_DATABASE_FILE_NAME) => return Ok(BUKU),
+        _ => {}
+    }
+
+    let extension = path.extension().ok_or(ImportError::FileHasNoExtension)?;
+    let extension = extension.to_str().ok_or(ImportError::UnexpectedError(
+        "couldn't convert file extension to a string slice".into(),
+    ))?;
+
+    match extension {
+        FIREFOX_DATABASE_EXTENSION => Ok(FIREFOX),
+        _ => Ok(extension),
+    }
+}
+
+fn validate_potential_bookmarks<I>(
+    potential_bookmarks: I,
+    ignore_attribute_errors: bool,
+) -> ParseResult
+where
+    I: IntoIterator<Item = PotentialBookmark>,
+{
+    let mut validation_errors = Vec::new();
+    let mut draft_bookmarks = Vec::new();
+    for (index, pb) in potential_bookmarks.into_iter().enumerate() {
+        match DraftBookmark::try_from((pb, ignore_attribute_errors)) {
+            Ok(db) => draft_bookmarks.push(db),
+            Err(e) => validation_errors.push((index, e)),
+        }
+    }
+
+    if validation_errors.is_empty() {
+        ParseResult::Ok(draft_bookmarks)
+    } else {
+        ParseResult::Err(validation_errors)
+    }
+}
+
+// Browsers allow saving the same URI in several folders; such entries are
+// merged into one, with the tags of all of them, the earliest creation time,
+// and the latest update time.
+fn merge_duplicate_bookmarks(
+    potential_bookmarks: Vec<PotentialBookmark>,
+) -> Vec<PotentialBookmark> {
+    let mut merged: Vec<PotentialBookmark> = Vec::with_capacity(potential_bookmarks.len());
+    let mut index_by_uri: HashMap<String, usize> = HashMap::new();
+
+    for pb in potential_bookmarks {
+        match index_by_uri.get(&pb.uri) {
+            Some(&index) => {
+                let existing = &mut merged[index];
+                if existing.title.is_none() {
+                    existing.title = pb.title;
+                }
+                for tag in pb.tags {
+                    if !existing.tags.contains(&tag) {
+                        existing.tags.push(tag);
+                    }
+                }
+                existing.created_at = match (existing.created_at, pb.created_at) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+                existing.updated_at = match (existing.updated_at, pb.updated_at) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    (a, b) => a.or(b),
+                };
+            }
+            None => {
+                index_by_uri.insert(pb.uri.clone(), merged.len());
+                merged.push(pb);
+            }
+        }
+    }
+
+    merged
+}
+
+fn parse_html_content(
+    bytes: &[u8],
+    ignore_attribute_errors: bool,
+    folders_as_tags: bool,
+) -> Result<ParseResult, std::io::Error> {
+    let document = Document::from_read(bytes)?;
+    let mut validation_errors = Vec::new();
+    let mut draft_bookmarks = Vec::new();
+
+    for (index, node) in document.find(Name("a")).enumerate() {
+        let uri = node.attr("href").unwrap_or("");
+        let title = node.text();
+        let mut tags = node
+            .attr("tags")
+            .unwrap_or("")
+            .split(",")
+            .map(|t| t.to_string())
+            .collect::<Vec<_>>();
+        if folders_as_tags {
+            for folder_tag in get_folder_tags(&node) {
+                if !tags.contains(&folder_tag) {
+                    tags.push(folder_tag);
+                }
+            }
+        }
+        let tags = tags.join(",");
+        let potential_bookmark = PotentialBookmark::from(PotentialImportedBookmark {
+            uri: uri.to_string(),
+            title: Some(title),
+            tags: Some(tags),
+            notes: get_notes(&node),
+        })
+        .with_timestamps(
+            node.attr("add_date").and_then(parse_timestamp),
+            node.attr("last_modified").and_then(parse_timestamp),
+        );
+        match DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors)) {
+            Ok(db) => {
+                draft_bookmarks.push(db);
+            }
+            Err(e) => {
+                validation_errors.push((index, e));
+            }
+        }
+    }
+
+    let result = if validation_errors.is_empty() {
+        ParseResult::Ok(draft_bookmarks)
+    } else {
+        ParseResult::Err(validation_errors)
+    };
+
+    Ok(result)
+}
+
+// A bookmark's description (written by browsers, and by "bmm export") is the
+// text of the <DD> following the <DT> that holds the bookmark.
+fn get_notes(node: &Node) -> Option<String> {
+    let entry = node.parent().filter(|p| p.name() == Some("dt"))?;
+    let next_element = std::iter::successors(entry.next(), |n| n.next()).find(|n| n.is(Element))?;
+
+    (next_element.name() == Some("dd"))
+        .then(|| next_element.text().trim().to_string())
+        .filter(|n| !n.is_empty())
+}
+
+// In NETSCAPE-Bookmark-file-1 files, a folder is a <DT> holding an <H3> (the
+// folder's name) followed by a <DL> with the folder's contents. Special folders
+// like the bookmarks toolbar are not meant to be tags, and are skipped.
+fn get_folder_tags(node: &Node) -> Vec<String> {
+    let mut folder_tags = Vec::new();
+    let mut ancestor = node.parent();
+    while let Some(current) = ancestor {
+        if current.name() == Some("dt")
+            && let Some(heading) = current.children().find(|c| c.name() == Some("h3"))
+            && heading.attr("personal_toolbar_folder").is_none()
+            && heading.attr("unfiled_bookmarks_folder").is_none()
+            && let Some(tag) = sanitize_tag_name(&heading.text())
+        {
+            folder_tags.push(tag);
+        }
+        ancestor = current.parent();
+    }
+
+    folder_tags.reverse();
+    folder_tags
+}
+
+fn text_entries<I>(lines: I) -> impl Iterator<Item = Result<InputEntry, ImportError>>
+where
+    I: Iterator<Item = Result<String, ImportError>>,
+{
+    lines.enumerate().map(|(index, line)| {
+        let potential_bookmark = PotentialImportedBookmark {
+            uri: line?,
+            title: None,
+            tags: None,
+            notes: None,
+        };
+
+        Ok((index, DraftBookmark::try_from(potential_bookmark)))
+    })
+}
+
+// Plain JSON arrays and the versioned format written by "bmm export" are told
+// apart by their first character
+fn json_entries<R>(
+    reader: R,
+    ignore_attribute_errors: bool,
+    to_read_error: fn(IOError) -> ImportError,
+) -> Result<Entries<'static>, ImportError>
+where
+    R: BufRead + Send + 'static,
+{
+    let (first_byte, reader) = peek_first_non_whitespace_byte(reader).map_err(to_read_error)?;
+    if first_byte == Some(b'{') {
+        return Ok(Box::new(
+            json_stream::bookmarks_export_elements(reader)
+                .enumerate()
+                .map(move |(index, exported_bookmark)| {
+                    let potential_bookmark = PotentialBookmark::from(exported_bookmark?);
+
+                    Ok((
+                        index,
+                        DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors)),
+                    ))
+                }),
+        ));
+    }
+
+    Ok(Box::new(
+        json_stream::json_array_elements::<PotentialImportedBookmark, _>(
+            reader,
+            ImportError::CouldntDeserializeJSONInput,
+        )
+        .enumerate()
+        .map(move |(index, potential_bookmark)| {
+            Ok((
+                index,
+                DraftBookmark::try_from((potential_bookmark?, ignore_attribute_errors)),
+            ))
+        }),
+    ))
+}
+
+// Returns the first byte that isn't whitespace, along with a reader that still
+// yields everything (so that positions in parsing errors stay correct)
+fn peek_first_non_whitespace_byte<R: BufRead>(
+    mut reader: R,
+) -> Result<(Option<u8>, impl BufRead), IOError> {
+    let mut skipped = Vec::new();
+    let first_byte = loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break None;
+        }
+
+        match buffer.iter().position(|b| !b.is_ascii_whitespace()) {
+            Some(position) => {
+                let byte = buffer[position];
+                skipped.extend_from_slice(&buffer[..position]);
+                reader.consume(position);
+                break Some(byte);
+            }
+            None => {
+                let len = buffer.len();
+                skipped.extend_from_slice(buffer);
+                reader.consume(len);
+            }
+        }
+    };
+
+    Ok((first_byte, Cursor::new(skipped).chain(reader)))
+}
+
+fn jsonl_entries<I>(
+    lines: I,
+    ignore_attribute_errors: bool,
+) -> impl Iterator<Item = Result<InputEntry, ImportError>>
+where
+    I: Iterator<Item = Result<String, ImportError>>,
+{
+    lines
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(l) if l.trim().is_empty()))
+        .map(move |(index, line)| {
+            let exported_bookmark: ExportedBookmark = serde_json::from_str(&line?)
+                .map_err(|e| ImportError::CouldntDeserializeJSONLine(index + 1, e))?;
+            let potential_bookmark = PotentialBookmark::from(exported_bookmark);
+
+            Ok((
+                index,
+                DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors)),
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::{assert_debug_snapshot, assert_yaml_snapshot};
+
+    fn parse_json_content(
+        content: &'static str,
+        ignore_attribute_errors: bool,
+    ) -> Result<ParseResult, ImportError> {
+        collect_entries(json_entries(
+            content.as_bytes(),
+            ignore_attribute_errors,
+            ImportError::CouldntReadFile,
+        )?)
+    }
+
+    //-------------//
+    //  SUCCESSES  //
+    //-------------//
+
+    #[test]
+    fn parsing_valid_html_content_works() {
+        // GIVEN
+        let content = r#"
+<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<!-- This is an automatically generated file.
+It will be read and overwritten.
+Do Not Edit! -->
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+<DT><A HREF="https://github.com/junegunn/fzf" ADD_DATE="1739262074" PRIVATE="0" TAGS="search,cli">junegunn/fzf: :cherry_blossom: A command-line fuzzy finder</A>
+<DT><A HREF="https://github.com/serde-rs/serde" ADD_DATE="1739264074" LAST_MODIFIED="1739264074" TAGS="rust,github">serde-rs/serde: Serialization framework for Rust</A>
+<DD>This article describes JavaScript for Automation, a new feature in OS X Yosemite.
+</DL><p>
+"#;
+
+        // WHEN
+        let result = parse_html_content(content.as_bytes(), false, false)
+            .expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+          tags:
+            - cli
+            - search
+          created_at: 1739262074
+          updated_at: 1739262074
+        - uri: "https://github.com/serde-rs/serde"
+          title: "serde-rs/serde: Serialization framework for Rust"
+          tags:
+            - github
+            - rust
+          notes: "This article describes JavaScript for Automation, a new feature in OS X Yosemite."
+          created_at: 1739264074
+          updated_at: 1739264074
+        "#);
+    }
+
+    #[test]
+    fn parsing_html_content_with_nested_folders_works() {
+        // GIVEN
+        let content = r#"
+<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+    <DT><H3>Folder 1</H3>
+    <DL><p>
+        <DT><H3>Subfolder 1</H3>
+        <DL><p>
+            <DT><A HREF="https://github.com/junegunn/fzf" TAGS="search,cli">junegunn/fzf: :cherry_blossom: A command-line fuzzy finder</A>
+        </DL><p>
+    </DL><p>
+    <DT><H3>Folder 2</H3>
+    <DL><p>
+        <DT><A HREF="https://github.com/serde-rs/serde" TAGS="rust,github">serde-rs/serde: Serialization framework for Rust</A>
+    </DL><p>
+</DL><p>
+"#;
+
+        // WHEN
+        let result = parse_html_content(content.as_bytes(), false, false)
+            .expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+          tags:
+            - cli
+            - search
+        - uri: "https://github.com/serde-rs/serde"
+          title: "serde-rs/serde: Serialization framework for Rust"
+          tags:
+            - github
+            - rust
+        "#);
+    }
+
+    #[test]
+    fn parsing_html_content_with_missing_attributes_works() {
+        // GIVEN
+        let content = r#"
+<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<!-- This is an automatically generated file.
+It will be read and overwritten.
+Do Not Edit! -->
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+<DT><A HREF="https://github.com/junegunn/fzf" ADD_DATE="1739262074" PRIVATE="0">junegunn/fzf: :cherry_blossom: A command-line fuzzy finder</A>
+<DT><A HREF="https://github.com/serde-rs/serde" ADD_DATE="1739264074" LAST_MODIFIED="1739264074" TAGS="rust,github"></A>
+<DD>This article describes JavaScript for Automation, a new feature in OS X Yosemite.
+</DL><p>
+"#;
+
+        // WHEN
+        let result = parse_html_content(content.as_bytes(), false, false)
+            .expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        insta::assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+          tags: []
+          created_at: 1739262074
+          updated_at: 1739262074
+        - uri: "https://github.com/serde-rs/serde"
+          title: ~
+          tags:
+            - github
+            - rust
+          notes: "This article describes JavaScript for Automation, a new feature in OS X Yosemite."
+          created_at: 1739264074
+          updated_at: 1739264074
+        "#);
+    }
+
+    #[test]
+    fn parsing_html_content_with_folders_as_tags_works() {
+        // GIVEN
+        let content = r#"
+<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks Menu</H1>
+<DL><p>
+    <DT><H3 ADD_DATE="1736450822" LAST_MODIFIED="1739920697" PERSONAL_TOOLBAR_FOLDER="true">Bookmarks Toolbar</H3>
+    <DL><p>
+        <DT><H3 ADD_DATE="1739896938" LAST_MODIFIED="1739920670">Productivity</H3>
+        <DL><p>
+            <DT><H3 ADD_DATE="1739896992" LAST_MODIFIED="1739920767">Rust Crates</H3>
+            <DL><p>
+                <DT><A HREF="https://crates.io/crates/sqlx" ADD_DATE="1739897020" TAGS="crates,rust">sqlx - crates.io: Rust Package Registry</A>
+            </DL><p>
+            <DT><A HREF="https://github.com/dhth/omm" ADD_DATE="1739920615" TAGS="productivity,tools">GitHub - dhth/omm</A>
+        </DL><p>
+        <DT><A HREF="https://github.com/dhth/bmm" ADD_DATE="1739920697">GitHub - dhth/bmm</A>
+    </DL><p>
+    <DT><H3 ADD_DATE="1739896938" LAST_MODIFIED="1739920670">Reading list</H3>
+    <DL><p>
+    </DL><p>
+    <DT><A HREF="https://github.com/serde-rs/serde" ADD_DATE="1739264074">serde</A>
+</DL><p>
+"#;
+
+        // WHEN
+        let result = parse_html_content(content.as_bytes(), false, true)
+            .expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        insta::assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://crates.io/crates/sqlx"
+          title: "sqlx - crates.io: Rust Package Registry"
+          tags:
+            - crates
+            - productivity
+            - rust
+            - rust-crates
+          created_at: 1739897020
+          updated_at: 1739897020
+        - uri: "https://github.com/dhth/omm"
+          title: GitHub - dhth/omm
+          tags:
+            - productivity
+            - tools
+          created_at: 1739920615
+          updated_at: 1739920615
+        - uri: "https://github.com/dhth/bmm"
+          title: GitHub - dhth/bmm
+          tags: []
+          created_at: 1739920697
+          updated_at: 1739920697
+        - uri: "https://github.com/serde-rs/serde"
+          title: serde
+          tags: []
+          created_at: 1739264074
+          updated_at: 1739264074
+        "#);
+    }
+
+    #[test]
+    fn parsing_html_content_scales_timestamps_down_to_seconds() {
+        // GIVEN
+        let content = r#"
+<DL><p>
+    <DT><A HREF="https://github.com/dhth/omm" ADD_DATE="1739897020123" LAST_MODIFIED="1739920615123456">omm</A>
+    <DT><A HREF="https://github.com/dhth/bmm" ADD_DATE=" 1739897020 " LAST_MODIFIED="yesterday">bmm</A>
+</DL><p>
+"#;
+
+        // WHEN
+        let result = parse_html_content(content.as_bytes(), false, false)
+            .expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        insta::assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/dhth/omm"
+          title: omm
+          tags: []
+          created_at: 1739897020
+          updated_at: 1739920615
+        - uri: "https://github.com/dhth/bmm"
+          title: bmm
+          tags: []
+          created_at: 1739897020
+          updated_at: 1739897020
+        "#);
+    }
+
+    #[test]
+    fn parsing_valid_text_content_works() {
+        // GIVEN
+        let content = vec![
+            "https://github.com/junegunn/fzf".to_string(),
+            "https://github.com/serde-rs/serde".to_string(),
+        ];
+
+        // WHEN
+        let result = collect_entries(text_entries(content.into_iter().map(Ok)))
+            .expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        insta::assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: ~
+          tags: []
+        - uri: "https://github.com/serde-rs/serde"
+          title: ~
+          tags: []
+        "#);
+    }
+
+    #[test]
+    fn parsing_valid_json_content_works() {
+        // GIVEN
+        let content = r#"
+[
+  {
+    "tags": "cli,search",
+    "title": "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder",
+    "uri": "https://github.com/junegunn/fzf"
+  },
+  {
+    "tags": "rust,github",
+    "title": "serde-rs/serde: Serialization framework for Rust",
+    "uri": "https://github.com/serde-rs/serde"
+  }
+]
+"#;
+
+        // WHEN
+        let result = parse_json_content(content, false).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+
+        insta::assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+          tags:
+            - cli
+            - search
+        - uri: "https://github.com/serde-rs/serde"
+          title: "serde-rs/serde: Serialization framework for Rust"
+          tags:
+            - github
+            - rust
+        "#);
+    }
+
+    #[test]
+    fn parsing_versioned_json_content_works() {
+        // GIVEN
+        let content = r#"
+{
+  "version": 1,
+  "bookmarks": [
+    {
+      "uri": "https://github.com/junegunn/fzf",
+      "title": "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder",
+      "tags": ["cli", "search"],
+      "created_at": 1739262074,
+      "updated_at": 1739264074
+    },
+    {
+      "uri": "https://github.com/serde-rs/serde"
+    }
+  ]
+}
+"#;
+
+        // WHEN
+        let result = parse_json_content(content, false).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        insta::assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+          tags:
+            - cli
+            - search
+          created_at: 1739262074
+          updated_at: 1739264074
+        - uri: "https://github.com/serde-rs/serde"
+          title: ~
+          tags: []
+        "#);
+    }
+
+    #[test]
+    fn parsing_jsonl_content_works() {
+        // GIVEN
+        let content = vec![
+            r#"{"uri":"https://github.com/junegunn/fzf","title":"junegunn/fzf: :cherry_blossom: A command-line fuzzy finder","tags":["cli","search"],"created_at":1739262074,"updated_at":1739264074}"#.to_string(),
+            "".to_string(),
+            r#"{"uri":"https://github.com/serde-rs/serde","tags":["rust"]}"#.to_string(),
+        ];
+
+        // WHEN
+        let result = collect_entries(jsonl_entries(content.into_iter().map(Ok), false))
+            .expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        insta::assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+          tags:
+            - cli
+            - search
+          created_at: 1739262074
+          updated_at: 1739264074
+        - uri: "https://github.com/serde-rs/serde"
+          title: ~
+          tags:
+            - rust
+        "#);
+    }
+
+    #[test]
+    fn parsing_json_content_with_padded_tags_works() {
+        // GIVEN
+        let content = r#"
+[
+  {
+    "tags": "cli, search",
+    "title": "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder",
+    "uri": "https://github.com/junegunn/fzf"
+  },
+  {
+    "tags": "rust,   github   ",
+    "title": "serde-rs/serde: Serialization framework for Rust",
+    "uri": "https://github.com/serde-rs/serde"
+  }
+]
+"#;
+
+        // WHEN
+        let result = parse_json_content(content, false).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+          tags:
+            - cli
+            - search
+        - uri: "https://github.com/serde-rs/serde"
+          title: "serde-rs/serde: Serialization framework for Rust"
+          tags:
+            - github
+            - rust
+        "#);
+    }
+
+    #[test]
+    fn parsing_json_content_with_missing_title_works() {
+        // GIVEN
+        let content = r#"
+[
+  {
+    "tags": "cli,search",
+    "title": "",
+    "uri": "https://github.com/junegunn/fzf"
+  },
+  {
+    "tags": "rust,github",
+    "uri": "https://github.com/serde-rs/serde"
+  }
+]
+"#;
+
+        // WHEN
+        let result = parse_json_content(content, false).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: ~
+          tags:
+            - cli
+            - search
+        - uri: "https://github.com/serde-rs/serde"
+          title: ~
+          tags:
+            - github
+            - rust
+        "#);
+    }
+
+    #[test]
+    fn parsing_json_content_with_missing_tags_works() {
+        // GIVEN
+        let content = r#"
+[
+  {
+    "tags": "",
+    "title": "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder",
+    "uri": "https://github.com/junegunn/fzf"
+  },
+  {
+    "title": "serde-rs/serde: Serialization framework for Rust",
+    "uri": "https://github.com/serde-rs/serde"
+  }
+]
+"#;
+
+        // WHEN
+        let result = parse_json_content(content, false).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/junegunn/fzf"
+          title: "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+          tags: []
+        - uri: "https://github.com/serde-rs/serde"
+          title: "serde-rs/serde: Serialization framework for Rust"
+          tags: []
+        "#);
+    }
+
+    //------------//
+    //  FAILURES  //
+    //------------//
+
+    #[test]
+    fn parsing_html_content_with_incorrect_uris_returns_validation_errors() {
+        // GIVEN
+        let content = r#"
+<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<!-- This is an automatically generated file.
+It will be read and overwritten.
+Do Not Edit! -->
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+<DT><A HREF="" ADD_DATE="1739262074" PRIVATE="0" TAGS="search,cli">junegunn/fzf: :cherry_blossom: A command-line fuzzy finder</A>
+<DT><A HREF="https://github.com/serde-rs/serde" ADD_DATE="1739264074" LAST_MODIFIED="1739264074" TAGS="rust,github">serde-rs/serde: Serialization framework for Rust</A>
+<DD>This article describes JavaScript for Automation, a new feature in OS X Yosemite.
+</DL><p>
+"#;
+
+        // WHEN
+        let result = parse_html_content(content.as_bytes(), false, false)
+            .expect("parsing should've succeeded");
+        let validation_errors = result.expect_err("should've returned validation errors");
+
+        // THEN
+        assert_debug_snapshot!(validation_errors, @"
+        [
+            (
+                0,
+                CouldntParseUri(
+                    RelativeUrlWithoutBase,
+                ),
+            ),
+        ]
+        ");
+    }
+
+    #[test]
+    fn parsing_html_content_with_incorrect_tags_returns_validation_errors() {
+        // GIVEN
+        let content = r#"
+<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+<!-- This is an automatically generated file.
+It will be read and overwritten.
+Do Not Edit! -->
+<TITLE>Bookmarks</TITLE>
+<H1>Bookmarks</H1>
+<DL><p>
+<DT><A HREF="https://github.com/junegunn/fzf" ADD_DATE="1739262074" PRIVATE="0" TAGS="invalid!!!tag,cli">junegunn/fzf: :cherry_blossom: A command-line fuzzy finder</A>
+<DT><A HREF="https://github.com/serde-rs/serde" ADD_DATE="1739264074" LAST_MODIFIED="1739264074" TAGS="rust,github">serde-rs/serde: Serialization framework for Rust</A>
+<DD>This article describes JavaScript for Automation, a new feature in OS X Yosemite.
+</DL><p>
+"#;
+
+        // WHEN
+        let result = parse_html_content(content.as_bytes(), false, false)
+            .expect("parsing should've succeeded");
+        let validation_errors = result.expect_err("should've returned validation errors");
+
+        // THEN
+        assert_debug_snapshot!(validation_errors, @r#"
+        [
+            (
+                0,
+                TagIsInvalid(
+                    [
+                        "invalid!!!tag",
+                    ],
+                ),
+            ),
+        ]
+        "#);
+    }
+
+    #[test]
+    fn parsing_html_content_with_out_of_order_timestamps_returns_validation_errors() {
+        // GIVEN
+        let content = r#"
+<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<DL><p>
+<DT><A HREF="https://github.com/junegunn/fzf" ADD_DATE="1739264074" LAST_MODIFIED="1739262074">fzf</A>
+</DL><p>
+"#;
+
+        // WHEN
+        let result = parse_html_content(content.as_bytes(), false, false)
+            .expect("parsing should've succeeded");
+
+        // THEN
+        let errors = result.expect_err("result should've been validation errors");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].1.to_string(),
+            "creation time (1739264074) is later than the last update time (1739262074)"
+        );
+    }
+
+    #[test]
+    fn parsing_text_content_returns_validation_errors_for_incorrect_uris() {
+        // GIVEN
+        let content = vec![
+            "https/github.com/junegunn/fzf".to_string(),
+            "https://github.com/serde-rs/serde".to_string(),
+        ];
+
+        // WHEN
+        let validation_errors = collect_entries(text_entries(content.into_iter().map(Ok)))
+            .expect("parsing should've succeeded")
+            .expect_err("should've returned validation errors");
+
+        // THEN
+        assert_debug_snapshot!(validation_errors, @"
+        [
+            (
+                0,
+                CouldntParseUri(
+                    RelativeUrlWithoutBase,
+                ),
+            ),
+        ]
+        ");
+    }
+
+    #[test]
+    fn parsing_jsonl_content_returns_validation_errors_with_line_indexes() {
+        // GIVEN
+        let content = vec![
+            r#"{"uri":"https://github.com/junegunn/fzf"}"#.to_string(),
+            r#"{"uri":"https://github.com/serde-rs/serde","created_at":1739264074,"updated_at":1739262074}"#.to_string(),
+        ];
+
+        // WHEN
+        let result = collect_entries(jsonl_entries(content.into_iter().map(Ok), false))
+            .expect("parsing should've succeeded");
+
+        // THEN
+        let errors = result.expect_err("result should've been validation errors");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 1);
+        assert_eq!(
+            errors[0].1.to_string(),
+            "creation time (1739264074) is later than the last update time (1739262074)"
+        );
+    }
+
+    #[test]
+    fn parsing_versioned_json_content_with_an_unsupported_version_fails() {
+        // GIVEN
+        let content = r#"{"version": 2, "bookmarks": []}"#;
+
+        // WHEN
+        let error = parse_json_content(content, false).expect_err("parsing should've failed");
+
+        // THEN
+        assert_eq!(
+            error.to_string(),
+            "export version 2 is not supported (supported version: 1)"
+        );
+    }
+
+    #[test]
+    fn parsing_invalid_jsonl_reports_the_line_number() {
+        // GIVEN
+        let content = vec![
+            r#"{"uri":"https://github.com/junegunn/fzf"}"#.to_string(),
+            r#"{"title":"no uri here"}"#.to_string(),
+        ];
+
+        // WHEN
+        let error = collect_entries(jsonl_entries(content.into_iter().map(Ok), false))
+            .expect_err("parsing should've failed");
+
+        // THEN
+        assert_eq!(
+            error.to_string(),
+            "couldn't parse JSON input on line 2: missing field `uri` at line 1 column 23"
+        );
+    }
+
+    #[test]
+    fn parsing_invalid_json_fails() {
+        // GIVEN
+        let content = r#"
+[
+  {
+    "tags": "",
+    "title": "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder",
+    "uri": "https://github.com/junegunn/fzf"
+  }
+  {
+    "title": "serde-rs/serde: Serialization framework for Rust",
+    "uri": "https://github.com/serde-rs/serde"
+  }
+]
+"#;
+
+        // WHEN
+        let error = parse_json_content(content, false).expect_err("result should've been an error");
+
+        // THEN
+        assert_debug_snapshot!(error, @r#"
+        CouldntDeserializeJSONInput(
+            Error("expected `,` or `]`", line: 8, column: 3),
+        )
+        "#);
+    }
+
+    #[test]
+    fn parsing_json_content_without_mandatory_fields_fails() {
+        // GIVEN
+        let content = r#"
+[
+  {
+    "tags": "",
+    "title": "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+  }
+  {
+    "title": "serde-rs/serde: Serialization framework for Rust",
+    "uri": "https://github.com/serde-rs/serde"
+  }
+]
+"#;
+
+        // WHEN
+        let error = parse_json_content(content, false).expect_err("result should've been an error");
+
+        // THEN
+        assert_debug_snapshot!(error, @r#"
+        CouldntDeserializeJSONInput(
+            Error("missing field `uri`", line: 6, column: 3),
+        )
+        "#);
+    }
+
+    #[test]
+    fn parsing_json_content_with_empty_uri_fails() {
+        // GIVEN
+        let content = r#"
+[
+  {
+    "uri": "",
+    "tags": "",
+    "title": "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+  },
+  {
+    "title": "serde-rs/serde: Serialization framework for Rust",
+    "uri": "https://github.com/serde-rs/serde"
+  }
+]
+"#;
+
+        // WHEN
+        let result = parse_json_content(content, false).expect("parsing should've succeeded");
+        let validation_errors = result.expect_err("should've returned validation errors");
+
+        // THEN
+        assert_debug_snapshot!(validation_errors, @"
+        [
+            (
+                0,
+                CouldntParseUri(
+                    RelativeUrlWithoutBase,
+                ),
+            ),
+        ]
+        ");
+    }
+
+    #[test]
+    fn parsing_json_content_with_invalid_tags_fails() {
+        // GIVEN
+        let content = r#"
+[
+  {
+    "uri": "https://github.com/junegunn/fzf",
+    "tags": "invalid tag",
+    "title": "junegunn/fzf: :cherry_blossom: A command-line fuzzy finder"
+  },
+  {
+    "title": "serde-rs/serde: Serialization framework for Rust",
+    "uri": "https://github.com/serde-rs/serde"
+  }
+]
+"#;
+
+        // WHEN
+        let result = parse_json_content(content, false).expect("parsing should've succeeded");
+        let validation_errors = result.expect_err("should've returned validation errors");
+
+        // THEN
+        assert_debug_snapshot!(validation_errors, @r#"
+        [
+            (
+                0,
+                TagIsInvalid(
+                    [
+                        "invalid tag",
+                    ],
+                ),
+            ),
+        ]
+        "#);
+    }
+}
@@ -0,0 +1,154 @@
+use super::json_stream::json_array_elements;
+use super::{ImportError, InputEntry};
+use crate::domain::{DraftBookmark, PotentialBookmark, PotentialImportedBookmark, parse_timestamp};
+use serde::Deserialize;
+use std::io::Read;
+
+// Pinboard's JSON export (https://pinboard.in/export/) holds an array of posts.
+// A post's "extended" description is saved as the bookmark's notes; fields like
+// "shared" and "toread" have no counterpart in bmm, and are ignored.
+#[derive(Debug, Deserialize)]
+struct PinboardPost {
+    href: String,
+    description: Option<String>,
+    extended: Option<String>,
+    tags: Option<String>,
+    time: Option<String>,
+}
+
+// Posts are read one by one, so large exports needn't be held in memory
+pub(super) fn pinboard_entries<R>(
+    reader: R,
+    ignore_attribute_errors: bool,
+) -> impl Iterator<Item = Result<InputEntry, ImportError>>
+where
+    R: Read + Send + 'static,
+{
+    json_array_elements::<PinboardPost, _>(reader, ImportError::CouldntParsePinboardExport)
+        .enumerate()
+        .map(move |(index, post)| {
+            let potential_bookmark = to_potential_bookmark(post?, index, ignore_attribute_errors)?;
+
+            Ok((
+                index,
+                DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors)),
+            ))
+        })
+}
+
+fn to_potential_bookmark(
+    post: PinboardPost,
+    index: usize,
+    ignore_attribute_errors: bool,
+) -> Result<PotentialBookmark, ImportError> {
+    let time = match post.time.as_deref().filter(|t| !t.trim().is_empty()) {
+        Some(value) => match parse_timestamp(value) {
+            Some(t) => Some(t),
+            None if ignore_attribute_errors => None,
+            None => {
+                return Err(ImportError::CouldntParseEntryTimestamp(
+                    index + 1,
+                    value.to_string(),
+                ));
+            }
+        },
+        None => None,
+    };
+
+    // Pinboard separates tags with spaces, bmm with commas
+    let tags = post
+        .tags
+        .map(|t| t.split_whitespace().collect::<Vec<_>>().join(","));
+    let title = post.description.filter(|d| !d.trim().is_empty());
+
+    Ok(PotentialBookmark::from(PotentialImportedBookmark {
+        uri: post.href,
+        title,
+        tags,
+        notes: post.extended,
+    })
+    .with_timestamps(time, time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::import::collect_entries;
+    use insta::assert_yaml_snapshot;
+
+    //-------------//
+    //  SUCCESSES  //
+    //-------------//
+
+    #[test]
+    fn parsing_pinboard_content_works() {
+        // GIVEN
+        let content = r#"[
+  {
+    "href": "https://github.com/dhth/bmm",
+    "description": "GitHub - dhth/bmm: get to your bookmarks in a flash",
+    "extended": "a bookmarks manager for the command line",
+    "meta": "5a2a4e2b3f0f4b6f8d0c9b7e1a3c5d7f",
+    "hash": "0c3a5d8e2b4f6a8c1e3d5f7b9a2c4e6d",
+    "time": "2025-02-18T23:18:17Z",
+    "shared": "no",
+    "toread": "no",
+    "tags": "tools  command-line"
+  },
+  {
+    "href": "https://github.com/dhth/omm",
+    "description": "",
+    "extended": "",
+    "time": "2025-02-18T23:16:55Z",
+    "shared": "yes",
+    "toread": "yes",
+    "tags": ""
+  }
+]"#;
+
+        // WHEN
+        let result = collect_entries(pinboard_entries(content.as_bytes(), false))
+            .expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/dhth/bmm"
+          title: "GitHub - dhth/bmm: get to your bookmarks in a flash"
+          tags:
+            - command-line
+            - tools
+          notes: a bookmarks manager for the command line
+          created_at: 1739920697
+          updated_at: 1739920697
+        - uri: "https://github.com/dhth/omm"
+          title: ~
+          tags: []
+          created_at: 1739920615
+          updated_at: 1739920615
+        "#);
+    }
+
+    //------------//
+    //  FAILURES  //
+    //------------//
+
+    #[test]
+    fn parsing_pinboard_content_with_invalid_times_fails() {
+        // GIVEN
+        let content = r#"[
+  {"href": "https://github.com/dhth/omm", "description": "omm", "time": "2025-02-18T23:16:55Z", "tags": "tools"},
+  {"href": "https://github.com/dhth/bmm", "description": "bmm", "time": "last tuesday", "tags": "tools"}
+]"#;
+
+        // WHEN
+        let error = collect_entries(pinboard_entries(content.as_bytes(), false))
+            .expect_err("parsing should've failed");
+
+        // THEN
+        assert_eq!(
+            error.to_string(),
+            r#"couldn't parse timestamp of entry 2: "last tuesday""#
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use super::delimited::delimited_entries;
+use super::{
+    Entries, ImportError, ParseResult, parsed_entries, peek_first_non_whitespace_byte,
+    validate_potential_bookmarks,
+};
+use crate::domain::{PotentialBookmark, PotentialImportedBookmark, parse_timestamp};
+use select::document::Document;
+use select::predicate::Name;
+use std::io::{BufRead, Error as IOError, Read};
+
+// Pocket has exported bookmarks both as an HTML list (of the form
+// <li><a href="..." time_added="..." tags="...">title</a></li>), and as a CSV
+// file (with the columns title, url, time_added, tags, and status). The format
+// is decided by the file's contents; the CSV export is read lazily, while the
+// HTML one can only be parsed as a whole.
+pub(super) fn pocket_entries<R>(
+    reader: R,
+    ignore_attribute_errors: bool,
+    to_read_error: fn(IOError) -> ImportError,
+) -> Result<Entries<'static>, ImportError>
+where
+    R: BufRead + 'static,
+{
+    let (first_byte, mut reader) = peek_first_non_whitespace_byte(reader).map_err(to_read_error)?;
+    if first_byte != Some(b'<') {
+        return Ok(Box::new(delimited_entries(
+            reader,
+            b',',
+            ignore_attribute_errors,
+        )?));
+    }
+
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes).map_err(to_read_error)?;
+
+    parsed_entries(parse_pocket_html(&bytes, ignore_attribute_errors)?)
+}
+
+fn parse_pocket_html(
+    bytes: &[u8],
+    ignore_attribute_errors: bool,
+) -> Result<ParseResult, ImportError> {
+    let document = Document::from_read(bytes).map_err(ImportError::CouldntParseHTMLInput)?;
+
+    let potential_bookmarks = document.find(Name("a")).map(|node| {
+        let title = node.text();
+        let time_added = node.attr("time_added").and_then(parse_timestamp);
+
+        PotentialBookmark::from(PotentialImportedBookmark {
+            uri: node.attr("href").unwrap_or_default().to_string(),
+            title: Some(title).filter(|t| !t.trim().is_empty()),
+            tags: node.attr("tags").map(|t| t.to_string()),
+            notes: None,
+        })
+        .with_timestamps(time_added, time_added)
+    });
+
+    Ok(validate_potential_bookmarks(
+        potential_bookmarks,
+        ignore_attribute_errors,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::import::collect_entries;
+    use insta::assert_yaml_snapshot;
+
+    //-------------//
+    //  SUCCESSES  //
+    //-------------//
+
+    #[test]
+    fn parsing_pocket_html_content_works() {
+        // GIVEN
+        let content = r#"<!DOCTYPE html>
+<html>
+<head><title>Pocket Export</title></head>
+<body>
+<h1>Unread</h1>
+<ul>
+<li><a href="https://github.com/dhth/bmm" time_added="1739920697" tags="tools,command-line">GitHub - dhth/bmm: get to your bookmarks in a flash</a></li>
+<li><a href="https://github.com/dhth/omm" time_added="1739920615" tags="">https://github.com/dhth/omm</a></li>
+</ul>
+<h1>Read Archive</h1>
+<ul>
+<li><a href="https://github.com/dhth/hours" time_added="1739920661" tags="productivity">GitHub - dhth/hours</a></li>
+</ul>
+</body>
+</html>
+"#;
+
+        // WHEN
+        let entries = pocket_entries(content.as_bytes(), false, ImportError::CouldntReadFile)
+            .expect("reading should've succeeded");
+        let result = collect_entries(entries).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/dhth/bmm"
+          title: "GitHub - dhth/bmm: get to your bookmarks in a flash"
+          tags:
+            - command-line
+            - tools
+          created_at: 1739920697
+          updated_at: 1739920697
+        - uri: "https://github.com/dhth/omm"
+          title: "https://github.com/dhth/omm"
+          tags: []
+          created_at: 1739920615
+          updated_at: 1739920615
+        - uri: "https://github.com/dhth/hours"
+          title: GitHub - dhth/hours
+          tags:
+            - productivity
+          created_at: 1739920661
+          updated_at: 1739920661
+        "#);
+    }
+
+    #[test]
+    fn parsing_pocket_csv_content_works() {
+        // GIVEN
+        let content = "title,url,time_added,tags,status
+GitHub - dhth/bmm,https://github.com/dhth/bmm,1739920697,tools|command-line,unread
+https://github.com/dhth/omm,https://github.com/dhth/omm,1739920615,,archive
+";
+
+        // WHEN
+        let entries = pocket_entries(content.as_bytes(), false, ImportError::CouldntReadFile)
+            .expect("reading should've succeeded");
+        let result = collect_entries(entries).expect("parsing should've succeeded");
+        let draft_bookmarks = result.expect("should've returned draft bookmarks");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmarks, @r#"
+        - uri: "https://github.com/dhth/bmm"
+          title: GitHub - dhth/bmm
+          tags:
+            - command-line
+            - tools
+          created_at: 1739920697
+          updated_at: 1739920697
+        - uri: "https://github.com/dhth/omm"
+          title: "https://github.com/dhth/omm"
+          tags: []
+          created_at: 1739920615
+          updated_at: 1739920615
+        "#);
+    }
+}
@@ -2,11 +2,13 @@ use super::DisplayError;
 use super::display::display_bookmarks;
 use crate::args::OutputFormat;
 use crate::persistence::DBError;
-use crate::persistence::get_bookmarks;
+use crate::persistence::{BookmarkFilter, Page, get_bookmarks};
 use sqlx::{Pool, Sqlite};
 
 #[derive(thiserror::Error, Debug)]
 pub enum ListBookmarksError {
+    #[error("invalid regex: {0}")]
+    InvalidRegex(#[from] regex::Error),
     #[error("couldn't get bookmarks from db: {0}")]
     CouldntGetBookmarksFromDB(DBError),
     #[error("couldn't display results: {0}")]
@@ -15,13 +17,13 @@ pub enum ListBookmarksError {
 
 pub async fn list_bookmarks(
     pool: &Pool<Sqlite>,
-    uri: Option<String>,
-    title: Option<String>,
-    tags: Vec<String>,
+    filter: &BookmarkFilter,
+    page: &Page,
     format: OutputFormat,
-    limit: u16,
 ) -> Result<(), ListBookmarksError> {
-    let bookmarks = get_bookmarks(pool, uri, title, tags, limit)
+    filter.check_patterns()?;
+
+    let bookmarks = get_bookmarks(pool, filter, page)
         .await
         .map_err(ListBookmarksError::CouldntGetBookmarksFromDB)?;
 
@@ -1,5 +1,7 @@
+mod chunks;
 mod delete;
 mod display;
+mod export;
 mod import;
 mod list;
 mod save;
@@ -10,6 +12,7 @@ mod tags;
 
 pub use delete::*;
 pub use display::*;
+pub use export::*;
 pub use import::*;
 pub use list::*;
 pub use save::*;
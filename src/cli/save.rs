@@ -1,7 +1,11 @@
+use super::tags::get_tag_suggestions;
 use crate::common::{ENV_VAR_BMM_EDITOR, ENV_VAR_EDITOR};
-use crate::domain::{DraftBookmark, DraftBookmarkError, PotentialBookmark, SavedBookmark};
+use crate::domain::{
+    DraftBookmark, DraftBookmarkError, PotentialBookmark, SavedBookmark, TagAliases,
+};
 use crate::persistence::{
     DBError, SaveBookmarkOptions, create_or_update_bookmark, get_bookmark_with_exact_uri,
+    get_tag_aliases,
 };
 use regex::{Error as RegexError, Regex};
 use sqlx::{Pool, Sqlite};
@@ -13,12 +17,18 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use tempfile::tempdir;
 use which::{Error as WhichError, which};
 
+const NUM_SUGGESTED_TAGS: usize = 5;
+
 #[derive(thiserror::Error, Debug)]
 pub enum SaveBookmarkError {
     #[error("couldn't check if uri already saved: {0}")]
     CouldntCheckIfBookmarkExists(DBError),
     #[error("uri already saved")]
     UriAlreadySaved,
+    #[error("couldn't get tag aliases: {0}")]
+    CouldntGetTagAliases(DBError),
+    #[error("couldn't get tag suggestions: {0}")]
+    CouldntGetTagSuggestions(DBError),
     #[error(transparent)]
     CouldntUseTextEditor(#[from] CouldntGetDetailsViaEditorError),
     #[error(transparent)]
@@ -66,6 +76,7 @@ pub async fn save_bookmark(
     fail_if_uri_saved: bool,
     reset_missing: bool,
     ignore_attribute_errors: bool,
+    suggest: bool,
 ) -> Result<(), SaveBookmarkError> {
     let maybe_existing_bookmark = get_bookmark_with_exact_uri(pool, &potential_bookmark.uri)
         .await
@@ -79,32 +90,81 @@ pub async fn save_bookmark(
         && !use_editor
         && potential_bookmark.title.is_none()
         && potential_bookmark.tags.is_empty()
+        && potential_bookmark.notes.is_none()
     {
         println!("nothing to update!");
+        if suggest {
+            list_tag_suggestions(pool, &potential_bookmark.uri).await?;
+        }
         return Ok(());
     }
 
+    let tag_aliases = TagAliases::from(
+        get_tag_aliases(pool)
+            .await
+            .map_err(SaveBookmarkError::CouldntGetTagAliases)?,
+    );
+
     let draft_bookmark = match use_editor {
         true => match maybe_existing_bookmark {
             Some(existing_bookmark) => {
-                let (title, tags) = get_bookmark_update_details_from_temp_file(&existing_bookmark)?;
+                let suggested_tags = match suggest {
+                    true => {
+                        let existing_tags = split_tags(existing_bookmark.tags.as_deref());
+                        get_suggested_tags(pool, &existing_bookmark.uri, &existing_tags).await?
+                    }
+                    false => vec![],
+                };
+
+                let (title, tags, notes) = get_bookmark_update_details_from_temp_file(
+                    &existing_bookmark,
+                    &suggested_tags,
+                )?;
 
                 let potential_bookmark = PotentialBookmark::from((
                     potential_bookmark.uri.as_str(),
                     title.as_deref(),
                     tags.as_deref(),
-                ));
-
-                DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors))?
+                ))
+                .with_notes(notes);
+
+                DraftBookmark::try_from((
+                    potential_bookmark,
+                    ignore_attribute_errors,
+                    &tag_aliases,
+                ))?
             }
             None => {
-                let potential_bookmark =
-                    get_new_bookmark_details_from_temp_file(&potential_bookmark.uri)?;
-
-                DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors))?
+                // tags passed on the command line are pre-filled, along with the ones
+                // suggested based on them
+                let prefilled_tags = match suggest {
+                    true => {
+                        let suggested_tags = get_suggested_tags(
+                            pool,
+                            &potential_bookmark.uri,
+                            &potential_bookmark.tags,
+                        )
+                        .await?;
+                        [potential_bookmark.tags.clone(), suggested_tags].concat()
+                    }
+                    false => vec![],
+                };
+
+                let potential_bookmark = get_new_bookmark_details_from_temp_file(
+                    &potential_bookmark.uri,
+                    &prefilled_tags,
+                )?;
+
+                DraftBookmark::try_from((
+                    potential_bookmark,
+                    ignore_attribute_errors,
+                    &tag_aliases,
+                ))?
             }
         },
-        false => DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors))?,
+        false => {
+            DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors, &tag_aliases))?
+        }
     };
 
     let reset_missing = if use_editor { true } else { reset_missing };
@@ -122,12 +182,65 @@ pub async fn save_bookmark(
         .await
         .map_err(SaveBookmarkError::CouldntSaveBookmark)?;
 
+    // suggestions are pre-filled in the text editor instead
+    if suggest && !use_editor {
+        list_tag_suggestions(pool, draft_bookmark.uri()).await?;
+    }
+
     Ok(())
 }
 
+// the tags the bookmark is saved with are used to suggest others
+async fn list_tag_suggestions(pool: &Pool<Sqlite>, uri: &str) -> Result<(), SaveBookmarkError> {
+    let saved_tags = get_bookmark_with_exact_uri(pool, uri)
+        .await
+        .map_err(SaveBookmarkError::CouldntGetTagSuggestions)?
+        .map(|bookmark| split_tags(bookmark.tags.as_deref()))
+        .unwrap_or_default();
+
+    let suggestions = get_tag_suggestions(pool, uri, &saved_tags, NUM_SUGGESTED_TAGS)
+        .await
+        .map_err(SaveBookmarkError::CouldntGetTagSuggestions)?;
+
+    if suggestions.is_empty() {
+        println!("no tags to suggest");
+        return Ok(());
+    }
+
+    println!("suggested tags:");
+    for suggestion in suggestions {
+        println!("  - {suggestion}");
+    }
+
+    Ok(())
+}
+
+async fn get_suggested_tags(
+    pool: &Pool<Sqlite>,
+    uri: &str,
+    chosen_tags: &[String],
+) -> Result<Vec<String>, SaveBookmarkError> {
+    let suggestions = get_tag_suggestions(pool, uri, chosen_tags, NUM_SUGGESTED_TAGS)
+        .await
+        .map_err(SaveBookmarkError::CouldntGetTagSuggestions)?;
+
+    Ok(suggestions.into_iter().map(|s| s.tag).collect())
+}
+
+fn split_tags(tags: Option<&str>) -> Vec<String> {
+    tags.unwrap_or_default()
+        .split(',')
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string())
+        .collect()
+}
+
+type BookmarkUpdateDetails = (Option<String>, Option<String>, Option<String>);
+
 fn get_bookmark_update_details_from_temp_file(
     bookmark: &SavedBookmark,
-) -> Result<(Option<String>, Option<String>), CouldntGetDetailsViaEditorError> {
+    suggested_tags: &[String],
+) -> Result<BookmarkUpdateDetails, CouldntGetDetailsViaEditorError> {
     let tmp_dir = tempdir().map_err(CouldntGetDetailsViaEditorError::CreateTempFile)?;
 
     let tmp_file_path = tmp_dir.path().join("bmm-edit.txt");
@@ -139,7 +252,7 @@ fn get_bookmark_update_details_from_temp_file(
         .open(&tmp_file_path)
         .map_err(CouldntGetDetailsViaEditorError::OpenTempFile)?;
 
-    let file_contents = get_update_bookmark_tmp_file_contents(bookmark);
+    let file_contents = get_update_bookmark_tmp_file_contents(bookmark, suggested_tags);
     file.write_all(file_contents.as_bytes())
         .map_err(CouldntGetDetailsViaEditorError::WriteToTempFile)?;
 
@@ -166,6 +279,7 @@ fn get_bookmark_update_details_from_temp_file(
 
 fn get_new_bookmark_details_from_temp_file(
     uri: &str,
+    prefilled_tags: &[String],
 ) -> Result<PotentialBookmark, CouldntGetDetailsViaEditorError> {
     let tmp_dir = tempdir().map_err(CouldntGetDetailsViaEditorError::CreateTempFile)?;
 
@@ -178,7 +292,7 @@ fn get_new_bookmark_details_from_temp_file(
         .open(&tmp_file_path)
         .map_err(CouldntGetDetailsViaEditorError::OpenTempFile)?;
 
-    let file_contents = get_create_bookmark_tmp_file_contents(uri);
+    let file_contents = get_create_bookmark_tmp_file_contents(uri, prefilled_tags);
     file.write_all(file_contents.as_bytes())
         .map_err(CouldntGetDetailsViaEditorError::WriteToTempFile)?;
 
@@ -200,12 +314,8 @@ fn get_new_bookmark_details_from_temp_file(
         .read_to_string(&mut modified_contents)
         .map_err(CouldntGetDetailsViaEditorError::ReadTempFileContents)?;
 
-    let (uri, title, tags) = parse_new_bookmark_temp_file_content(&modified_contents)?;
-    Ok(PotentialBookmark::from((
-        &uri,
-        title.as_ref(),
-        tags.as_ref(),
-    )))
+    let (uri, title, tags, notes) = parse_new_bookmark_temp_file_content(&modified_contents)?;
+    Ok(PotentialBookmark::from((&uri, title.as_ref(), tags.as_ref())).with_notes(notes))
 }
 
 fn get_text_editor_exe() -> Result<(String, String), CouldntGetDetailsViaEditorError> {
@@ -232,7 +342,16 @@ fn get_text_editor_exe() -> Result<(String, String), CouldntGetDetailsViaEditorE
     Err(CouldntGetDetailsViaEditorError::NoEditorConfigured)
 }
 
-fn get_update_bookmark_tmp_file_contents(bookmark: &SavedBookmark) -> String {
+fn get_update_bookmark_tmp_file_contents(
+    bookmark: &SavedBookmark,
+    suggested_tags: &[String],
+) -> String {
+    let tags = split_tags(bookmark.tags.as_deref())
+        .into_iter()
+        .chain(suggested_tags.iter().cloned())
+        .collect::<Vec<_>>()
+        .join(",");
+
     format!(
         r#"
        __             
@@ -256,14 +375,22 @@ Comma separate tags:
 >>>
 {}
 <<<
+
+Notes (can span multiple lines):
+>>>
+{}
+<<<
 "#,
         bookmark.uri,
         bookmark.title.as_deref().unwrap_or_default(),
-        bookmark.tags.as_deref().unwrap_or_default(),
+        tags,
+        bookmark.notes.as_deref().unwrap_or_default(),
     )
 }
 
-fn get_create_bookmark_tmp_file_contents(uri: &str) -> String {
+fn get_create_bookmark_tmp_file_contents(uri: &str, prefilled_tags: &[String]) -> String {
+    let tags = prefilled_tags.join(",");
+
     format!(
         r#"
        __             
@@ -287,6 +414,11 @@ Title:
 
 Comma separated tags:
 >>>
+{tags}
+<<<
+
+Notes (can span multiple lines):
+>>>
 
 <<<
 "#
@@ -295,7 +427,7 @@ Comma separated tags:
 
 fn parse_bookmark_update_temp_file_content(
     input: &str,
-) -> Result<(Option<String>, Option<String>), ParsingTempFileContentError> {
+) -> Result<BookmarkUpdateDetails, ParsingTempFileContentError> {
     let re = Regex::new(r">>>\s*\n(.*?)\n\s*<<<")?;
     let captures: Vec<_> = re.captures_iter(input).collect();
 
@@ -315,12 +447,16 @@ fn parse_bookmark_update_temp_file_content(
         false => Some(tags_line.to_string()),
     };
 
-    Ok((title, tags))
+    let notes = parse_notes_from_temp_file_content(input)?;
+
+    Ok((title, tags, notes))
 }
 
+type NewBookmarkDetails = (String, Option<String>, Option<String>, Option<String>);
+
 fn parse_new_bookmark_temp_file_content(
     input: &str,
-) -> Result<(String, Option<String>, Option<String>), ParsingTempFileContentError> {
+) -> Result<NewBookmarkDetails, ParsingTempFileContentError> {
     let re = Regex::new(r">>>\s*\n(.*?)\n\s*<<<")?;
     let captures: Vec<_> = re.captures_iter(input).collect();
 
@@ -342,7 +478,25 @@ fn parse_new_bookmark_temp_file_content(
         false => Some(tags_line.to_string()),
     };
 
-    Ok((uri, title, tags))
+    let notes = parse_notes_from_temp_file_content(input)?;
+
+    Ok((uri, title, tags, notes))
+}
+
+// Unlike the other details, notes can span multiple lines. The notes section
+// is optional, so that files written by older versions of bmm can still be
+// parsed.
+fn parse_notes_from_temp_file_content(
+    input: &str,
+) -> Result<Option<String>, ParsingTempFileContentError> {
+    let re = Regex::new(r"(?s)\nNotes[^\n]*:[ \t]*\n>>>[ \t]*\n(.*?)\n?[ \t]*<<<")?;
+
+    let notes = re
+        .captures(input)
+        .map(|c| c[1].trim().to_string())
+        .filter(|n| !n.is_empty());
+
+    Ok(notes)
 }
 
 #[cfg(test)]
@@ -389,6 +543,7 @@ tag1,tag2,tag3
         assert_yaml_snapshot!(result, @r#"
         - Uri title goes here
         - "tag1,tag2,tag3"
+        - ~
         "#);
     }
 
@@ -418,6 +573,7 @@ tag1,tag2,tag3
         assert_yaml_snapshot!(result, @r#"
         - ~
         - "tag1,tag2,tag3"
+        - ~
         "#);
     }
 
@@ -447,6 +603,7 @@ Comma separate tags:
         assert_yaml_snapshot!(result, @"
         - Uri title goes here
         - ~
+        - ~
         ");
     }
 
@@ -488,6 +645,7 @@ tag1,tag2,tag3
         - "https://someuri.com"
         - Title goes here
         - "tag1,tag2,tag3"
+        - ~
         "#);
     }
 
@@ -529,6 +687,7 @@ tag1,tag2,tag3
         - "https://someuri.com"
         - ~
         - "tag1,tag2,tag3"
+        - ~
         "#);
     }
 
@@ -570,6 +729,103 @@ Comma separated tags:
         - "https://someuri.com"
         - Title goes here
         - ~
+        - ~
+        "#);
+    }
+
+    #[test]
+    fn parsing_temp_file_content_for_bookmark_update_with_multi_line_notes_works() {
+        // GIVEN
+        let bookmark = SavedBookmark {
+            uri: "https://someuri.com".into(),
+            title: Some("Uri title goes here".into()),
+            tags: Some("tag1,tag2".into()),
+            notes: Some("first line\n\n  second line".into()),
+            created_at: 1739920697,
+            updated_at: 1739920697,
+            snippet: None,
+        };
+        let temp_file_content = get_update_bookmark_tmp_file_contents(&bookmark, &[]);
+
+        // WHEN
+        let result = parse_bookmark_update_temp_file_content(&temp_file_content)
+            .expect("parsing should've succeeded");
+
+        // THEN
+        assert_yaml_snapshot!(result, @r#"
+        - Uri title goes here
+        - "tag1,tag2"
+        - "first line\n\n  second line"
+        "#);
+    }
+
+    #[test]
+    fn parsing_temp_file_content_for_new_bookmark_with_notes_works() {
+        // GIVEN
+        let temp_file_content = get_create_bookmark_tmp_file_contents("https://someuri.com", &[])
+            .replace(
+                "Notes (can span multiple lines):\n>>>\n\n<<<",
+                "Notes (can span multiple lines):\n>>>\nworth a read\nlater\n<<<",
+            );
+
+        // WHEN
+        let result = parse_new_bookmark_temp_file_content(&temp_file_content)
+            .expect("parsing should've succeeded");
+
+        // THEN
+        assert_yaml_snapshot!(result, @r#"
+        - "https://someuri.com"
+        - ~
+        - ~
+        - "worth a read\nlater"
+        "#);
+    }
+
+    #[test]
+    fn parsing_temp_file_content_for_bookmark_update_with_suggested_tags_works() {
+        // GIVEN
+        let bookmark = SavedBookmark {
+            uri: "https://someuri.com".into(),
+            title: Some("Uri title goes here".into()),
+            tags: Some("tag1,tag2".into()),
+            notes: None,
+            created_at: 1739920697,
+            updated_at: 1739920697,
+            snippet: None,
+        };
+        let temp_file_content =
+            get_update_bookmark_tmp_file_contents(&bookmark, &["tag3".into(), "tag4".into()]);
+
+        // WHEN
+        let result = parse_bookmark_update_temp_file_content(&temp_file_content)
+            .expect("parsing should've succeeded");
+
+        // THEN
+        assert_yaml_snapshot!(result, @r#"
+        - Uri title goes here
+        - "tag1,tag2,tag3,tag4"
+        - ~
+        "#);
+    }
+
+    #[test]
+    fn parsing_temp_file_content_for_new_bookmark_with_prefilled_tags_works() {
+        // GIVEN
+        let temp_file_content = get_create_bookmark_tmp_file_contents(
+            "https://someuri.com",
+            &["tag1".into(), "tag2".into()],
+        );
+
+        // WHEN
+        let result = parse_new_bookmark_temp_file_content(&temp_file_content)
+            .expect("parsing should've succeeded");
+
+        // THEN
+        assert_yaml_snapshot!(result, @r#"
+        - "https://someuri.com"
+        - ~
+        - "tag1,tag2"
+        - ~
         "#);
     }
 
@@ -1,6 +1,11 @@
-use crate::common::IMPORT_UPPER_LIMIT;
-use crate::domain::{DraftBookmark, DraftBookmarkErrors, PotentialBookmark};
-use crate::persistence::{DBError, SaveBookmarkOptions, create_or_update_bookmarks};
+use super::chunks::{next_chunk, report_partial_save, report_progress};
+use super::display::DisplayError;
+use crate::domain::{
+    DraftBookmark, DraftBookmarkErrors, PotentialBookmark, SaveReport, TagAliases,
+};
+use crate::persistence::{
+    DBError, SaveBookmarkOptions, create_or_update_bookmarks, get_tag_aliases,
+};
 use sqlx::{Pool, Sqlite};
 use std::io::BufRead;
 use std::io::Error as IOError;
@@ -10,19 +15,21 @@ use std::time::{SystemTime, UNIX_EPOCH};
 pub enum SaveBookmarksError {
     #[error("couldn't read stdin: {0}")]
     CouldntReadStdin(#[from] IOError),
-    #[error("file has too many bookmarks: {0} (maximum allowed at a time: {IMPORT_UPPER_LIMIT})")]
-    TooManyBookmarks(usize),
     #[error("{}\n\n{}", errors.msg(), errors)]
     ValidationError { errors: DraftBookmarkErrors },
+    #[error("couldn't get tag aliases: {0}")]
+    CouldntGetTagAliases(#[source] DBError),
     #[error("couldn't save bookmarks to bmm's database: {0}")]
     SaveError(#[from] DBError),
+    #[error("couldn't display report: {0}")]
+    CouldntDisplayReport(#[source] DisplayError),
     #[error("something unexpected happened: {0}")]
     UnexpectedError(String),
 }
 
 #[derive(Debug)]
 pub struct SaveAllStats {
-    pub num_bookmarks: usize,
+    pub report: SaveReport,
 }
 
 pub async fn save_all_bookmarks(
@@ -32,39 +39,34 @@ pub async fn save_all_bookmarks(
     use_stdin: bool,
     reset_missing: bool,
     ignore_attribute_errors: bool,
+    list_bookmarks: bool,
 ) -> Result<Option<SaveAllStats>, SaveBookmarksError> {
-    let mut uris_to_save = uris.unwrap_or_default();
-
-    if use_stdin {
-        let stdin = std::io::stdin();
-        for line in stdin.lock().lines() {
-            uris_to_save.push(line?);
-        }
-    }
-
-    if uris_to_save.len() > IMPORT_UPPER_LIMIT {
-        return Err(SaveBookmarksError::TooManyBookmarks(uris_to_save.len()));
-    }
-
-    let mut validation_errors = Vec::new();
-    let mut draft_bookmarks = Vec::new();
-
-    for (index, uri) in uris_to_save.into_iter().enumerate() {
-        let potential_bookmark = PotentialBookmark::from((uri, None, &tags));
-        let db_result = DraftBookmark::try_from((potential_bookmark, ignore_attribute_errors));
-        match db_result {
-            Ok(db) => draft_bookmarks.push(db),
-            Err(e) => validation_errors.push((index, e)),
-        }
-    }
-
-    if !validation_errors.is_empty() {
-        return Err(SaveBookmarksError::ValidationError {
-            errors: DraftBookmarkErrors {
-                errors: validation_errors,
-            },
+    let tag_aliases = TagAliases::from(
+        get_tag_aliases(pool)
+            .await
+            .map_err(SaveBookmarksError::CouldntGetTagAliases)?,
+    );
+    let stdin_lines = use_stdin
+        .then(|| std::io::stdin().lock().lines())
+        .into_iter()
+        .flatten();
+    let mut entries = uris
+        .unwrap_or_default()
+        .into_iter()
+        .map(Ok)
+        .chain(stdin_lines)
+        .enumerate()
+        .map(|(index, uri)| {
+            let potential_bookmark = PotentialBookmark::from((uri?, None, &tags));
+            Ok::<_, SaveBookmarksError>((
+                index,
+                DraftBookmark::try_from((
+                    potential_bookmark,
+                    ignore_attribute_errors,
+                    &tag_aliases,
+                )),
+            ))
         });
-    }
 
     let start = SystemTime::now();
     let since_the_epoch = start
@@ -75,9 +77,37 @@ pub async fn save_all_bookmarks(
         reset_missing_attributes: false,
         reset_tags: reset_missing,
     };
-    create_or_update_bookmarks(pool, &draft_bookmarks, now, save_options).await?;
 
-    Ok(Some(SaveAllStats {
-        num_bookmarks: draft_bookmarks.len(),
-    }))
+    let mut report = SaveReport::new(list_bookmarks);
+    loop {
+        let draft_bookmarks = match next_chunk(&mut entries) {
+            Ok(Some(Ok(draft_bookmarks))) => draft_bookmarks,
+            Ok(None) => break,
+            Ok(Some(Err(validation_errors))) => {
+                report_partial_save(report.num_bookmarks());
+                return Err(SaveBookmarksError::ValidationError {
+                    errors: DraftBookmarkErrors {
+                        errors: validation_errors,
+                    },
+                });
+            }
+            Err(e) => {
+                report_partial_save(report.num_bookmarks());
+                return Err(e);
+            }
+        };
+
+        let outcomes =
+            match create_or_update_bookmarks(pool, &draft_bookmarks, now, save_options).await {
+                Ok(o) => o,
+                Err(e) => {
+                    report_partial_save(report.num_bookmarks());
+                    return Err(e.into());
+                }
+            };
+        report.add(&draft_bookmarks, &outcomes);
+        report_progress(draft_bookmarks.len(), report.num_bookmarks());
+    }
+
+    Ok(Some(SaveAllStats { report }))
 }
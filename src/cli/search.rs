@@ -1,42 +1,111 @@
 use super::DisplayError;
-use super::display::display_bookmarks;
+use super::display::{display_bookmarks, display_search_results};
 use crate::args::OutputFormat;
+use crate::domain::{SearchQuery, SearchQueryError, fuzzy_rank};
 use crate::persistence::DBError;
-use crate::persistence::{SearchTerms, SearchTermsError, get_bookmarks_by_query};
+use crate::persistence::{
+    BookmarkFilter, Page, get_all_bookmarks_matching, get_bookmarks_by_query,
+};
 use crate::tui::run_tui;
 use crate::tui::{AppTuiError, TuiContext};
 use sqlx::{Pool, Sqlite};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(thiserror::Error, Debug)]
 pub enum SearchBookmarksError {
     #[error("search query is invalid: {0}")]
-    SearchQueryInvalid(#[from] SearchTermsError),
+    SearchQueryInvalid(#[from] SearchQueryError),
+    #[error("fuzzy search query is empty")]
+    FuzzyQueryEmpty,
     #[error("couldn't get bookmarks from db: {0}")]
     CouldntGetBookmarksFromDB(DBError),
     #[error("couldn't display results: {0}")]
     CouldntDisplayResults(DisplayError),
     #[error(transparent)]
     CouldntRunTui(#[from] AppTuiError),
+    #[error("something unexpected happened: {0}")]
+    UnexpectedError(String),
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SearchOptions {
+    // rank bookmarks by how closely they fuzzy match the query, instead of
+    // parsing it as a structured query
+    pub fuzzy: bool,
+    pub snippets: bool,
+    pub tui: bool,
 }
 
 pub async fn search_bookmarks(
     pool: &Pool<Sqlite>,
-    query_terms: &Vec<String>,
+    query_terms: &[String],
+    filter: &BookmarkFilter,
+    page: &Page,
+    format: OutputFormat,
+    options: SearchOptions,
+) -> Result<(), SearchBookmarksError> {
+    if options.fuzzy {
+        return fuzzy_search_bookmarks(pool, query_terms, filter, page, format, options.tui).await;
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| SearchBookmarksError::UnexpectedError(format!("system time error: {e}")))?
+        .as_secs() as i64;
+    let search_query = SearchQuery::parse(&query_terms.join(" "), now)?;
+
+    if options.tui {
+        run_tui(pool, TuiContext::Search(search_query)).await?;
+        return Ok(());
+    }
+
+    let bookmarks = get_bookmarks_by_query(pool, &search_query, filter, page)
+        .await
+        .map_err(SearchBookmarksError::CouldntGetBookmarksFromDB)?;
+
+    if bookmarks.is_empty() {
+        return Ok(());
+    }
+
+    if options.snippets && matches!(format, OutputFormat::Plain) {
+        display_search_results(&bookmarks);
+        return Ok(());
+    }
+
+    display_bookmarks(&bookmarks, &format).map_err(SearchBookmarksError::CouldntDisplayResults)?;
+
+    Ok(())
+}
+
+async fn fuzzy_search_bookmarks(
+    pool: &Pool<Sqlite>,
+    query_terms: &[String],
+    filter: &BookmarkFilter,
+    page: &Page,
     format: OutputFormat,
-    limit: u16,
     tui: bool,
 ) -> Result<(), SearchBookmarksError> {
-    let search_terms = SearchTerms::try_from(query_terms)?;
+    let query = query_terms.join(" ");
+    if query.trim().is_empty() {
+        return Err(SearchBookmarksError::FuzzyQueryEmpty);
+    }
 
     if tui {
-        run_tui(pool, TuiContext::Search(search_terms)).await?;
+        run_tui(pool, TuiContext::FuzzySearch(query)).await?;
         return Ok(());
     }
 
-    let bookmarks = get_bookmarks_by_query(pool, &search_terms, limit)
+    let candidates = get_all_bookmarks_matching(pool, filter)
         .await
         .map_err(SearchBookmarksError::CouldntGetBookmarksFromDB)?;
 
+    let bookmarks = fuzzy_rank(candidates, &query)
+        .into_iter()
+        .skip(page.offset as usize)
+        .take(page.limit as usize)
+        .map(|m| m.bookmark)
+        .collect::<Vec<_>>();
+
     if bookmarks.is_empty() {
         return Ok(());
     }
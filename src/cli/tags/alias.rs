@@ -0,0 +1,111 @@
+use super::super::DisplayError;
+use super::super::display::display_tag_aliases;
+use crate::args::OutputFormat;
+use crate::domain::{TAG_REGEX_STR, Tag};
+use crate::persistence::DBError;
+use crate::persistence::{
+    create_or_update_tag_alias, delete_tag_aliases, get_tag_aliases, rename_tag_name,
+};
+use sqlx::{Pool, Sqlite};
+
+#[derive(thiserror::Error, Debug)]
+pub enum TagAliasError {
+    #[error("alias is invalid (valid regex: {TAG_REGEX_STR})")]
+    AliasIsInvalid,
+    #[error("tag is invalid (valid regex: {TAG_REGEX_STR})")]
+    TagIsInvalid,
+    #[error("alias and tag are the same")]
+    AliasAndTagSame,
+    #[error("\"{0}\" is an alias itself; aliases can only point to canonical tags")]
+    TagIsAnAlias(String),
+    #[error("\"{0}\" has aliases pointing to it, so it can't be an alias itself")]
+    AliasHasAliases(String),
+    #[error("aliases do not exist: {0:?}")]
+    AliasesDoNotExist(Vec<String>),
+    #[error(transparent)]
+    CouldntInteractWithDB(#[from] DBError),
+    #[error("couldn't display results: {0}")]
+    CouldntDisplayResults(#[from] DisplayError),
+}
+
+pub async fn add_tag_alias(
+    pool: &Pool<Sqlite>,
+    alias: String,
+    tag: String,
+) -> Result<(), TagAliasError> {
+    let alias = Tag::try_from(alias.as_str()).map_err(|_| TagAliasError::AliasIsInvalid)?;
+    let tag = Tag::try_from(tag.as_str()).map_err(|_| TagAliasError::TagIsInvalid)?;
+    if alias == tag {
+        return Err(TagAliasError::AliasAndTagSame);
+    }
+
+    // aliases are resolved once, so they can't be chained
+    let existing_aliases = get_tag_aliases(pool).await?;
+    if existing_aliases.iter().any(|a| a.alias == tag.name()) {
+        return Err(TagAliasError::TagIsAnAlias(tag.name().to_string()));
+    }
+    if existing_aliases.iter().any(|a| a.tag == alias.name()) {
+        return Err(TagAliasError::AliasHasAliases(alias.name().to_string()));
+    }
+
+    create_or_update_tag_alias(pool, &alias, &tag).await?;
+
+    Ok(())
+}
+
+pub async fn remove_tag_aliases(
+    pool: &Pool<Sqlite>,
+    aliases: Vec<String>,
+) -> Result<(), TagAliasError> {
+    if aliases.is_empty() {
+        return Ok(());
+    }
+
+    let existing_aliases = get_tag_aliases(pool).await?;
+    let non_existent_aliases = aliases
+        .iter()
+        .filter(|alias| !existing_aliases.iter().any(|a| &a.alias == *alias))
+        .cloned()
+        .collect::<Vec<_>>();
+    if !non_existent_aliases.is_empty() {
+        return Err(TagAliasError::AliasesDoNotExist(non_existent_aliases));
+    }
+
+    let num_aliases_removed = delete_tag_aliases(pool, &aliases).await?;
+
+    match num_aliases_removed {
+        1 => println!("removed 1 alias"),
+        n => println!("removed {n} aliases"),
+    }
+
+    Ok(())
+}
+
+pub async fn list_tag_aliases(
+    pool: &Pool<Sqlite>,
+    format: OutputFormat,
+) -> Result<(), TagAliasError> {
+    let aliases = get_tag_aliases(pool).await?;
+
+    display_tag_aliases(&aliases, &format)?;
+
+    Ok(())
+}
+
+// tags saved before an alias was added (eg. bookmarks tagged "js" before "js"
+// was made an alias of "javascript") are renamed to the canonical tag, merging
+// them with it if it already exists
+pub async fn apply_tag_aliases(pool: &Pool<Sqlite>) -> Result<(), TagAliasError> {
+    let mut num_tags_folded = 0;
+    for alias in get_tag_aliases(pool).await? {
+        let tag = Tag::try_from(alias.tag.as_str()).map_err(|_| TagAliasError::TagIsInvalid)?;
+        num_tags_folded += rename_tag_name(pool, alias.alias, tag).await?;
+    }
+
+    match num_tags_folded {
+        1 => println!("folded 1 tag into its canonical tag"),
+        n => println!("folded {n} tags into their canonical tags"),
+    }
+
+    Ok(())
+}
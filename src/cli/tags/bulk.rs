@@ -0,0 +1,196 @@
+use super::{ConfirmationError, confirm};
+use crate::domain::{SearchQuery, SearchQueryError, TAG_REGEX_STR, Tag, TagAliases};
+use crate::persistence::DBError;
+use crate::persistence::{
+    add_tags_to_bookmarks, get_all_bookmarks_matching_query, get_tag_aliases,
+    remove_tags_from_bookmarks,
+};
+use sqlx::{Pool, Sqlite};
+use std::io::Error as IOError;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(thiserror::Error, Debug)]
+pub enum BulkTagsError {
+    #[error("tags {0:?} are invalid (valid regex: {TAG_REGEX_STR})")]
+    TagsAreInvalid(Vec<String>),
+    #[error("query is invalid: {0}")]
+    QueryInvalid(#[from] SearchQueryError),
+    #[error(transparent)]
+    CouldntInteractWithDB(#[from] DBError),
+    #[error("couldn't flush stdout: {0}")]
+    CouldntFlushStdout(IOError),
+    #[error("couldn't read your input: {0}")]
+    CouldntReadUserInput(IOError),
+    #[error("something unexpected happened: {0}")]
+    UnexpectedError(String),
+}
+
+impl From<ConfirmationError> for BulkTagsError {
+    fn from(e: ConfirmationError) -> Self {
+        match e {
+            ConfirmationError::CouldntFlushStdout(e) => Self::CouldntFlushStdout(e),
+            ConfirmationError::CouldntReadUserInput(e) => Self::CouldntReadUserInput(e),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TagsChange {
+    Add,
+    Remove,
+}
+
+pub async fn add_tags(
+    pool: &Pool<Sqlite>,
+    tags: Vec<String>,
+    query: String,
+    dry_run: bool,
+    skip_confirmation: bool,
+) -> Result<(), BulkTagsError> {
+    change_tags(
+        pool,
+        TagsChange::Add,
+        tags,
+        query,
+        dry_run,
+        skip_confirmation,
+    )
+    .await
+}
+
+pub async fn remove_tags(
+    pool: &Pool<Sqlite>,
+    tags: Vec<String>,
+    query: String,
+    dry_run: bool,
+    skip_confirmation: bool,
+) -> Result<(), BulkTagsError> {
+    change_tags(
+        pool,
+        TagsChange::Remove,
+        tags,
+        query,
+        dry_run,
+        skip_confirmation,
+    )
+    .await
+}
+
+// only bookmarks that would actually change are shown and updated, eg. ones
+// that already have every tag being added are left as is
+async fn change_tags(
+    pool: &Pool<Sqlite>,
+    change: TagsChange,
+    tags: Vec<String>,
+    query: String,
+    dry_run: bool,
+    skip_confirmation: bool,
+) -> Result<(), BulkTagsError> {
+    let tags = parse_tags(pool, tags).await?;
+    if tags.is_empty() {
+        return Ok(());
+    }
+
+    let start = SystemTime::now();
+    let since_the_epoch = start
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| BulkTagsError::UnexpectedError(format!("system time error: {e}")))?;
+    let now = since_the_epoch.as_secs() as i64;
+
+    let search_query = SearchQuery::parse(&query, now)?;
+
+    let uris = get_all_bookmarks_matching_query(pool, &search_query)
+        .await?
+        .into_iter()
+        .filter(|bookmark| {
+            let bookmark_tags = bookmark
+                .tags
+                .as_deref()
+                .unwrap_or_default()
+                .split(',')
+                .collect::<Vec<_>>();
+            let has_tag = |tag: &Tag| bookmark_tags.contains(&tag.name());
+            match change {
+                TagsChange::Add => !tags.iter().all(has_tag),
+                TagsChange::Remove => tags.iter().any(has_tag),
+            }
+        })
+        .map(|bookmark| bookmark.uri)
+        .collect::<Vec<_>>();
+
+    if uris.is_empty() {
+        println!("no bookmarks would change");
+        return Ok(());
+    }
+
+    let tag_names = tags
+        .iter()
+        .map(|t| format!("\"{}\"", t.name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let (verb, preposition) = match change {
+        TagsChange::Add => ("add", "to"),
+        TagsChange::Remove => ("remove", "from"),
+    };
+    let tags_noun = if tags.len() == 1 { "tag" } else { "tags" };
+    let bookmarks_noun = if uris.len() == 1 {
+        "1 bookmark".to_string()
+    } else {
+        format!("{} bookmarks", uris.len())
+    };
+    let modal = if dry_run { "Would" } else { "Will" };
+
+    if dry_run || !skip_confirmation {
+        println!("{modal} {verb} {tags_noun} {tag_names} {preposition} {bookmarks_noun}:");
+        for uri in &uris {
+            println!("  - {uri}");
+        }
+    }
+
+    if dry_run {
+        return Ok(());
+    }
+
+    if !skip_confirmation && !confirm("\nType \"y\" to confirm.")? {
+        println!("cancelled");
+        return Ok(());
+    }
+
+    let num_bookmarks = match change {
+        TagsChange::Add => add_tags_to_bookmarks(pool, &uris, &tags, now).await?,
+        TagsChange::Remove => remove_tags_from_bookmarks(pool, &uris, &tags, now).await?,
+    };
+
+    match num_bookmarks {
+        1 => println!("updated 1 bookmark"),
+        n => println!("updated {n} bookmarks"),
+    }
+
+    Ok(())
+}
+
+// tag aliases are replaced with the tags they stand for, the same way they are
+// when saving bookmarks
+async fn parse_tags(pool: &Pool<Sqlite>, tags: Vec<String>) -> Result<Vec<Tag>, BulkTagsError> {
+    let tag_aliases = TagAliases::from(get_tag_aliases(pool).await?);
+
+    let mut parsed_tags = Vec::with_capacity(tags.len());
+    let mut invalid_tags = Vec::new();
+    for tag in tags {
+        if tag.trim().is_empty() {
+            continue;
+        }
+        match Tag::try_from(tag.as_str()) {
+            Ok(t) => parsed_tags.push(tag_aliases.resolve(&t).unwrap_or(t)),
+            Err(_) => invalid_tags.push(tag),
+        }
+    }
+    if !invalid_tags.is_empty() {
+        return Err(BulkTagsError::TagsAreInvalid(invalid_tags));
+    }
+
+    parsed_tags.sort();
+    parsed_tags.dedup();
+
+    Ok(parsed_tags)
+}
@@ -1,6 +1,7 @@
 use super::super::DisplayError;
-use super::super::display::{display_tags, display_tags_with_stats};
+use super::super::display::{display_tag_tree, display_tags, display_tags_with_stats};
 use crate::args::OutputFormat;
+use crate::domain::build_tag_tree;
 use crate::persistence::DBError;
 use crate::persistence::{get_tags, get_tags_with_stats};
 use crate::tui::{AppTuiError, TuiContext, run_tui};
@@ -20,6 +21,7 @@ pub async fn list_tags(
     pool: &Pool<Sqlite>,
     format: OutputFormat,
     show_stats: bool,
+    tree: bool,
     tui: bool,
 ) -> Result<(), ListTagsError> {
     if tui {
@@ -27,6 +29,12 @@ pub async fn list_tags(
         return Ok(());
     }
 
+    if tree {
+        let tags_stats = get_tags_with_stats(pool).await?;
+        display_tag_tree(&build_tag_tree(&tags_stats), &format)?;
+        return Ok(());
+    }
+
     match show_stats {
         true => {
             let tags_stats = get_tags_with_stats(pool).await?;
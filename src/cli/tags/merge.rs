@@ -0,0 +1,120 @@
+use super::rename::{find_alias, print_renames_preview};
+use super::{ConfirmationError, confirm};
+use crate::domain::{TAG_REGEX_STR, Tag};
+use crate::persistence::DBError;
+use crate::persistence::{get_tags, rename_tag_names};
+use sqlx::{Pool, Sqlite};
+use std::io::Error as IOError;
+
+#[derive(thiserror::Error, Debug)]
+pub enum MergeTagsError {
+    #[error("target tag is invalid (valid regex: {TAG_REGEX_STR})")]
+    TagIsInvalid,
+    #[error("tags {0:?} are invalid (valid regex: {TAG_REGEX_STR})")]
+    TagsAreInvalid(Vec<String>),
+    #[error("\"{0}\" is an alias; tags can't be merged into an alias")]
+    TagIsAnAlias(String),
+    #[error("there are no tags to merge other than the target tag")]
+    NothingToMerge,
+    #[error("tags do not exist: {0:?}")]
+    TagsDoNotExist(Vec<String>),
+    #[error(transparent)]
+    CouldntMergeTags(#[from] DBError),
+    #[error("couldn't flush stdout: {0}")]
+    CouldntFlushStdout(IOError),
+    #[error("couldn't read your input: {0}")]
+    CouldntReadUserInput(IOError),
+}
+
+impl From<ConfirmationError> for MergeTagsError {
+    fn from(e: ConfirmationError) -> Self {
+        match e {
+            ConfirmationError::CouldntFlushStdout(e) => Self::CouldntFlushStdout(e),
+            ConfirmationError::CouldntReadUserInput(e) => Self::CouldntReadUserInput(e),
+        }
+    }
+}
+
+// the target tag can be a new tag, an existing one, or even one of the source
+// tags (which is then left as is); bookmarks end up with the target tag if
+// they had any of the source tags
+pub async fn merge_tags(
+    pool: &Pool<Sqlite>,
+    source_tags: Vec<String>,
+    target_tag: String,
+    dry_run: bool,
+    skip_confirmation: bool,
+) -> Result<(), MergeTagsError> {
+    let target_tag =
+        Tag::try_from(target_tag.as_str()).map_err(|_| MergeTagsError::TagIsInvalid)?;
+    if let Some(alias) = find_alias(pool, [&target_tag]).await? {
+        return Err(MergeTagsError::TagIsAnAlias(alias));
+    }
+
+    let mut parsed_source_tags = Vec::with_capacity(source_tags.len());
+    let mut invalid_tags = Vec::new();
+    for tag in source_tags {
+        match Tag::try_from(tag.as_str()) {
+            Ok(t) => parsed_source_tags.push(t.name().to_string()),
+            Err(_) => invalid_tags.push(tag),
+        }
+    }
+    if !invalid_tags.is_empty() {
+        return Err(MergeTagsError::TagsAreInvalid(invalid_tags));
+    }
+
+    let mut source_tags = parsed_source_tags
+        .into_iter()
+        .filter(|t| t != target_tag.name())
+        .collect::<Vec<_>>();
+    source_tags.sort();
+    source_tags.dedup();
+    if source_tags.is_empty() {
+        return Err(MergeTagsError::NothingToMerge);
+    }
+
+    let all_tags = get_tags(pool).await?;
+    let non_existent_tags = source_tags
+        .iter()
+        .filter(|t| !all_tags.contains(t))
+        .cloned()
+        .collect::<Vec<_>>();
+    if !non_existent_tags.is_empty() {
+        return Err(MergeTagsError::TagsDoNotExist(non_existent_tags));
+    }
+
+    let renames = source_tags
+        .into_iter()
+        .map(|source_tag| (source_tag, target_tag.clone()))
+        .collect::<Vec<_>>();
+
+    let tags_noun = if renames.len() == 1 { "tag" } else { "tags" };
+    let modal = if dry_run { "Would" } else { "Will" };
+    let header = format!(
+        "{modal} merge {} {tags_noun} into \"{}\":",
+        renames.len(),
+        target_tag.name()
+    );
+
+    if dry_run || !skip_confirmation {
+        print_renames_preview(pool, &header, &renames).await?;
+    }
+
+    if dry_run {
+        return Ok(());
+    }
+
+    if !skip_confirmation && !confirm("\nType \"y\" to confirm.")? {
+        println!("cancelled");
+        return Ok(());
+    }
+
+    let num_tags = rename_tag_names(pool, &renames).await?;
+
+    match num_tags {
+        1 => println!("merged 1 tag into \"{}\"", target_tag.name()),
+        n => println!("merged {n} tags into \"{}\"", target_tag.name()),
+    }
+
+    Ok(())
+}
@@ -1,7 +1,38 @@
+mod alias;
+mod bulk;
 mod delete;
 mod list;
+mod merge;
 mod rename;
+mod suggest;
 
+use std::io::{Error as IOError, Write};
+
+pub use alias::*;
+pub use bulk::*;
 pub use delete::*;
 pub use list::*;
+pub use merge::*;
 pub use rename::*;
+pub use suggest::*;
+
+pub(super) enum ConfirmationError {
+    CouldntFlushStdout(IOError),
+    CouldntReadUserInput(IOError),
+}
+
+// asks for a "y" on stdin before going ahead with a change
+pub(super) fn confirm(prompt: &str) -> Result<bool, ConfirmationError> {
+    println!("{prompt}");
+
+    std::io::stdout()
+        .flush()
+        .map_err(ConfirmationError::CouldntFlushStdout)?;
+
+    let mut input = String::new();
+    std::io::stdin()
+        .read_line(&mut input)
+        .map_err(ConfirmationError::CouldntReadUserInput)?;
+
+    Ok(input.trim() == "y")
+}
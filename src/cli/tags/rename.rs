@@ -1,7 +1,14 @@
-use crate::domain::{TAG_REGEX_STR, Tag};
+use super::{ConfirmationError, confirm};
+use crate::domain::{TAG_REGEX_STR, TAG_SEPARATOR, Tag};
 use crate::persistence::DBError;
-use crate::persistence::rename_tag_name;
+use crate::persistence::{
+    count_bookmarks_with_any_tag, get_tag_aliases, get_tags, get_tags_with_stats, rename_tag_name,
+    rename_tag_names,
+};
+use regex::Regex;
 use sqlx::{Pool, Sqlite};
+use std::collections::{BTreeMap, HashSet};
+use std::io::Error as IOError;
 
 #[derive(thiserror::Error, Debug)]
 pub enum RenameTagError {
@@ -13,18 +20,50 @@ pub enum RenameTagError {
     CouldntRenameTag(#[from] DBError),
     #[error("new tag is invalid (valid regex: {TAG_REGEX_STR})")]
     TagIsInvalid,
+    #[error("tag \"{0}\" would be invalid after renaming (valid regex: {TAG_REGEX_STR})")]
+    NestedTagIsInvalid(String),
+    #[error("\"{0}\" is an alias; tags can't be renamed to an alias")]
+    TagIsAnAlias(String),
+    #[error("tags can't be moved under themselves")]
+    TargetInsideSource,
+    #[error("invalid regex: {0}")]
+    InvalidRegex(#[from] regex::Error),
+    #[error("tag \"{0}\" would be renamed, and have other tags renamed to it at the same time")]
+    RenamesOverlap(String),
+    #[error("couldn't flush stdout: {0}")]
+    CouldntFlushStdout(IOError),
+    #[error("couldn't read your input: {0}")]
+    CouldntReadUserInput(IOError),
+}
+
+impl From<ConfirmationError> for RenameTagError {
+    fn from(e: ConfirmationError) -> Self {
+        match e {
+            ConfirmationError::CouldntFlushStdout(e) => Self::CouldntFlushStdout(e),
+            ConfirmationError::CouldntReadUserInput(e) => Self::CouldntReadUserInput(e),
+        }
+    }
 }
 
 pub async fn rename_tag(
     pool: &Pool<Sqlite>,
     source_tag: String,
     target_tag: String,
+    subtree: bool,
 ) -> Result<(), RenameTagError> {
     if source_tag.trim() == target_tag.trim() {
         return Err(RenameTagError::SourceAndTargetSame);
     }
 
+    if subtree {
+        return rename_tag_subtree(pool, source_tag.trim(), target_tag.trim()).await;
+    }
+
     let new_tag = Tag::try_from(target_tag.as_str()).map_err(|_| RenameTagError::TagIsInvalid)?;
+    if let Some(alias) = find_alias(pool, [&new_tag]).await? {
+        return Err(RenameTagError::TagIsAnAlias(alias));
+    }
+
     let result = rename_tag_name(pool, source_tag, new_tag).await?;
     if result == 0 {
         return Err(RenameTagError::NoSuchTag);
@@ -32,3 +71,213 @@ pub async fn rename_tag(
 
     Ok(())
 }
+
+// renames the tag, along with every tag nested under it, eg. renaming "lang" to
+// "languages" renames "lang/rust" to "languages/rust"; the source tag itself
+// doesn't need to exist
+async fn rename_tag_subtree(
+    pool: &Pool<Sqlite>,
+    source_tag: &str,
+    target_tag: &str,
+) -> Result<(), RenameTagError> {
+    Tag::try_from(target_tag).map_err(|_| RenameTagError::TagIsInvalid)?;
+
+    let source_prefix = format!("{source_tag}{TAG_SEPARATOR}");
+    if target_tag.starts_with(&source_prefix) {
+        return Err(RenameTagError::TargetInsideSource);
+    }
+
+    let renames = get_tags(pool)
+        .await?
+        .into_iter()
+        .filter_map(|tag| {
+            let rest = if tag == source_tag {
+                ""
+            } else {
+                tag.strip_prefix(&source_prefix)?
+            };
+            let new_name = if rest.is_empty() {
+                target_tag.to_string()
+            } else {
+                format!("{target_tag}{TAG_SEPARATOR}{rest}")
+            };
+            Some((tag, new_name))
+        })
+        .map(|(tag, new_name)| match Tag::try_from(new_name.as_str()) {
+            Ok(new_tag) => Ok((tag, new_tag)),
+            Err(_) => Err(RenameTagError::NestedTagIsInvalid(new_name)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if renames.is_empty() {
+        return Err(RenameTagError::NoSuchTag);
+    }
+
+    if let Some(alias) = find_alias(pool, renames.iter().map(|(_, target)| target)).await? {
+        return Err(RenameTagError::TagIsAnAlias(alias));
+    }
+
+    rename_tag_names(pool, &renames).await?;
+
+    Ok(())
+}
+
+// the replacement can refer to the pattern's capture groups, eg. renaming tags
+// matching "^py-(.*)" to "python-$1" turns "py-django" into "python-django";
+// tags that end up with the same name are merged
+pub async fn rename_tags_matching_pattern(
+    pool: &Pool<Sqlite>,
+    pattern: String,
+    replacement: String,
+    dry_run: bool,
+    skip_confirmation: bool,
+) -> Result<(), RenameTagError> {
+    let regex = Regex::new(&pattern)?;
+
+    let renames = get_tags(pool)
+        .await?
+        .into_iter()
+        .filter(|tag| regex.is_match(tag))
+        .map(|tag| {
+            let new_name = regex.replace(&tag, replacement.as_str()).into_owned();
+            (tag, new_name)
+        })
+        .filter(|(tag, new_name)| tag != new_name)
+        .map(|(tag, new_name)| match Tag::try_from(new_name.as_str()) {
+            Ok(new_tag) => Ok((tag, new_tag)),
+            Err(_) => Err(RenameTagError::NestedTagIsInvalid(new_name)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if renames.is_empty() {
+        println!("no tags would change");
+        return Ok(());
+    }
+
+    // renames happen one after the other, so a tag that's renamed can't be
+    // the target of another rename
+    let sources = renames
+        .iter()
+        .map(|(source, _)| source.as_str())
+        .collect::<HashSet<_>>();
+    if let Some((_, target)) = renames
+        .iter()
+        .find(|(_, target)| sources.contains(target.name()))
+    {
+        return Err(RenameTagError::RenamesOverlap(target.name().to_string()));
+    }
+
+    if let Some(alias) = find_alias(pool, renames.iter().map(|(_, target)| target)).await? {
+        return Err(RenameTagError::TagIsAnAlias(alias));
+    }
+
+    let tags_noun = if renames.len() == 1 { "tag" } else { "tags" };
+    let header = if dry_run {
+        format!("Would rename {} {tags_noun}:", renames.len())
+    } else {
+        format!("Will rename {} {tags_noun}:", renames.len())
+    };
+
+    if dry_run || !skip_confirmation {
+        print_renames_preview(pool, &header, &renames).await?;
+    }
+
+    if dry_run {
+        return Ok(());
+    }
+
+    if !skip_confirmation && !confirm("\nType \"y\" to confirm.")? {
+        println!("cancelled");
+        return Ok(());
+    }
+
+    let num_tags = rename_tag_names(pool, &renames).await?;
+
+    match num_tags {
+        1 => println!("renamed 1 tag"),
+        n => println!("renamed {n} tags"),
+    }
+
+    Ok(())
+}
+
+// tag filters resolve aliases before matching, so a tag renamed to an alias
+// couldn't be found by its own name anymore
+pub(super) async fn find_alias<'a, I>(
+    pool: &Pool<Sqlite>,
+    targets: I,
+) -> Result<Option<String>, DBError>
+where
+    I: IntoIterator<Item = &'a Tag>,
+{
+    let aliases = get_tag_aliases(pool).await?;
+    let alias = targets
+        .into_iter()
+        .find(|target| aliases.iter().any(|a| a.alias == target.name()))
+        .map(|target| target.name().to_string());
+
+    Ok(alias)
+}
+
+// shows each tag that'd be renamed along with its number of bookmarks, the
+// number of bookmarks the tags they'd be renamed to would end up with, and how
+// the number of tags would change
+pub(super) async fn print_renames_preview(
+    pool: &Pool<Sqlite>,
+    header: &str,
+    renames: &[(String, Tag)],
+) -> Result<(), DBError> {
+    let tags_stats = get_tags_with_stats(pool).await?;
+    let num_bookmarks = |tag: &str| {
+        tags_stats
+            .iter()
+            .find(|t| t.name == tag)
+            .map(|t| t.num_bookmarks)
+            .unwrap_or_default()
+    };
+
+    println!("{header}");
+    for (source, target) in renames {
+        println!(
+            "  - {source} ({}) -> {}",
+            bookmarks_count(num_bookmarks(source)),
+            target.name()
+        );
+    }
+
+    let mut merged_tags: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (source, target) in renames {
+        merged_tags
+            .entry(target.name())
+            .or_insert_with(|| vec![target.name().to_string()])
+            .push(source.clone());
+    }
+
+    println!("\nResulting tags:");
+    for (target, tags) in &merged_tags {
+        let num_bookmarks = count_bookmarks_with_any_tag(pool, tags).await?;
+        println!("  - {target} ({})", bookmarks_count(num_bookmarks));
+    }
+
+    let mut resulting_tags = tags_stats
+        .iter()
+        .map(|t| t.name.as_str())
+        .filter(|name| !renames.iter().any(|(source, _)| source == name))
+        .collect::<HashSet<_>>();
+    resulting_tags.extend(merged_tags.keys());
+    println!(
+        "\nNumber of tags: {} -> {}",
+        tags_stats.len(),
+        resulting_tags.len()
+    );
+
+    Ok(())
+}
+
+fn bookmarks_count(num_bookmarks: i64) -> String {
+    if num_bookmarks == 1 {
+        "1 bookmark".to_string()
+    } else {
+        format!("{num_bookmarks} bookmarks")
+    }
+}
@@ -0,0 +1,78 @@
+use super::super::DisplayError;
+use super::super::display::display_tag_suggestions;
+use crate::args::OutputFormat;
+use crate::domain::{
+    RelatedTagCounts, TAG_REGEX_STR, Tag, TagAliases, TagSuggestion, rank_tag_suggestions,
+    uri_domain,
+};
+use crate::persistence::DBError;
+use crate::persistence::{get_co_occurring_tag_counts, get_domain_tag_counts, get_tag_aliases};
+use sqlx::{Pool, Sqlite};
+use url::{ParseError, Url};
+
+#[derive(thiserror::Error, Debug)]
+pub enum SuggestTagsError {
+    #[error("couldn't parse provided uri value: {0}")]
+    CouldntParseUri(#[from] ParseError),
+    #[error("tags {0:?} are invalid (valid regex: {TAG_REGEX_STR})")]
+    TagsAreInvalid(Vec<String>),
+    #[error(transparent)]
+    CouldntInteractWithDB(#[from] DBError),
+    #[error("couldn't display results: {0}")]
+    CouldntDisplayResults(#[from] DisplayError),
+}
+
+pub async fn suggest_tags(
+    pool: &Pool<Sqlite>,
+    uri: String,
+    tags: Vec<String>,
+    limit: usize,
+    format: OutputFormat,
+) -> Result<(), SuggestTagsError> {
+    Url::parse(&uri)?;
+
+    let tag_aliases = TagAliases::from(get_tag_aliases(pool).await?);
+
+    let mut chosen_tags = Vec::with_capacity(tags.len());
+    let mut invalid_tags = Vec::new();
+    for tag in tags {
+        if tag.trim().is_empty() {
+            continue;
+        }
+        match Tag::try_from(tag.as_str()) {
+            Ok(t) => chosen_tags.push(tag_aliases.resolve(&t).unwrap_or(t).name().to_string()),
+            Err(_) => invalid_tags.push(tag),
+        }
+    }
+    if !invalid_tags.is_empty() {
+        return Err(SuggestTagsError::TagsAreInvalid(invalid_tags));
+    }
+
+    let suggestions = get_tag_suggestions(pool, &uri, &chosen_tags, limit).await?;
+
+    display_tag_suggestions(&suggestions, &format)?;
+
+    Ok(())
+}
+
+// suggestions are based on the tags of other bookmarks from the same domain,
+// and on tags that often appear along with the ones already chosen
+pub(crate) async fn get_tag_suggestions(
+    pool: &Pool<Sqlite>,
+    uri: &str,
+    chosen_tags: &[String],
+    limit: usize,
+) -> Result<Vec<TagSuggestion>, DBError> {
+    let by_domain = match uri_domain(uri) {
+        Some(domain) => get_domain_tag_counts(pool, &domain, uri).await?,
+        None => RelatedTagCounts::default(),
+    };
+    let by_co_occurrence = get_co_occurring_tag_counts(pool, chosen_tags, uri).await?;
+
+    Ok(rank_tag_suggestions(
+        &by_domain,
+        &by_co_occurrence,
+        chosen_tags,
+        limit,
+    ))
+}
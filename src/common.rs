@@ -1,8 +1,21 @@
+pub const BUKU: &str = "buku";
+pub const CHROMIUM: &str = "chromium";
+pub const CSV: &str = "csv";
+pub const FIREFOX: &str = "firefox";
 pub const HTML: &str = "html";
 pub const JSON: &str = "json";
+pub const JSONL: &str = "jsonl";
+pub const PINBOARD: &str = "pinboard";
+pub const POCKET: &str = "pocket";
+pub const TSV: &str = "tsv";
 pub const TXT: &str = "txt";
-pub const IMPORT_FILE_FORMATS: [&str; 3] = [HTML, JSON, TXT];
+pub const IMPORT_FILE_FORMATS: [&str; 11] = [
+    BUKU, CHROMIUM, CSV, FIREFOX, HTML, JSON, JSONL, PINBOARD, POCKET, TSV, TXT,
+];
+pub const BUKU_DATABASE_FILE_NAME: &str = "bookmarks.db";
+pub const CHROMIUM_BOOKMARKS_FILE_NAME: &str = "Bookmarks";
+pub const FIREFOX_DATABASE_EXTENSION: &str = "sqlite";
 pub const DEFAULT_LIMIT: u16 = 10000;
-pub const IMPORT_UPPER_LIMIT: usize = 9999;
+pub const SAVE_CHUNK_SIZE: usize = 1000;
 pub const ENV_VAR_BMM_EDITOR: &str = "BMM_EDITOR";
 pub const ENV_VAR_EDITOR: &str = "EDITOR";
@@ -1,16 +1,30 @@
-use super::tags::{TAG_REGEX_STR, Tag};
+use super::tags::{TAG_REGEX_STR, Tag, TagAliases};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use url::{ParseError, Url};
 
 const TITLE_MAX_LENGTH: usize = 500;
+pub const BOOKMARKS_EXPORT_VERSION: u32 = 1;
 
 #[derive(Debug, Serialize)]
 pub struct DraftBookmark {
     uri: String,
     title: Option<String>,
     tags: Vec<Tag>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated_at: Option<i64>,
+    // changes made to the bookmark's attributes because of
+    // --ignore-attribute-errors
+    #[serde(skip)]
+    corrections: Vec<String>,
+    // whether any of the corrections above dropped an invalid detail
+    #[serde(skip)]
+    skipped_details: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -18,6 +32,78 @@ pub struct PotentialBookmark {
     pub uri: String,
     pub title: Option<String>,
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    #[serde(default)]
+    pub updated_at: Option<i64>,
+}
+
+impl PotentialBookmark {
+    pub fn with_notes(mut self, notes: Option<String>) -> Self {
+        self.notes = notes;
+        self
+    }
+
+    pub fn with_timestamps(mut self, created_at: Option<i64>, updated_at: Option<i64>) -> Self {
+        self.created_at = created_at;
+        self.updated_at = updated_at;
+        self
+    }
+}
+
+/// A full-fidelity bookmark entry, as written by `bmm export` in the JSON and
+/// JSON Lines formats.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportedBookmark {
+    pub uri: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    #[serde(default)]
+    pub updated_at: Option<i64>,
+}
+
+/// The top-level document written by `bmm export --format json`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BookmarksExport {
+    pub version: u32,
+    pub bookmarks: Vec<ExportedBookmark>,
+}
+
+impl From<BookmarkRecord> for ExportedBookmark {
+    fn from(record: BookmarkRecord) -> Self {
+        Self {
+            uri: record.uri,
+            title: record.title,
+            tags: record
+                .tags
+                .map(|t| t.split(",").map(|t| t.to_string()).collect::<Vec<_>>())
+                .unwrap_or_default(),
+            notes: record.notes,
+            created_at: Some(record.created_at),
+            updated_at: Some(record.updated_at),
+        }
+    }
+}
+
+impl From<ExportedBookmark> for PotentialBookmark {
+    fn from(bookmark: ExportedBookmark) -> Self {
+        Self {
+            uri: bookmark.uri,
+            title: bookmark.title,
+            tags: bookmark.tags,
+            notes: bookmark.notes,
+            created_at: bookmark.created_at,
+            updated_at: bookmark.updated_at,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -25,6 +111,8 @@ pub struct PotentialImportedBookmark {
     pub uri: String,
     pub title: Option<String>,
     pub tags: Option<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
 }
 
 impl From<PotentialImportedBookmark> for PotentialBookmark {
@@ -38,6 +126,9 @@ impl From<PotentialImportedBookmark> for PotentialBookmark {
                 .split(",")
                 .map(|t| t.to_string())
                 .collect::<Vec<_>>(),
+            notes: bookmark.notes,
+            created_at: None,
+            updated_at: None,
         }
     }
 }
@@ -52,6 +143,7 @@ where
             uri: uri.as_ref().to_string(),
             title: title.map(|t| t.as_ref().to_string()),
             tags: tags.map(|t| t.as_ref().to_string()),
+            notes: None,
         }
     }
 }
@@ -71,6 +163,9 @@ where
                 .split(",")
                 .map(|t| t.to_string())
                 .collect::<Vec<_>>(),
+            notes: None,
+            created_at: None,
+            updated_at: None,
         }
     }
 }
@@ -88,6 +183,9 @@ where
                 .iter()
                 .map(|t| t.as_ref().to_string())
                 .collect::<Vec<_>>(),
+            notes: None,
+            created_at: None,
+            updated_at: None,
         }
     }
 }
@@ -100,6 +198,8 @@ pub enum DraftBookmarkError {
     TitleTooLong(usize),
     #[error("tags {0:?} are invalid (valid regex: {TAG_REGEX_STR})")]
     TagIsInvalid(Vec<String>),
+    #[error("creation time ({0}) is later than the last update time ({1})")]
+    TimestampsOutOfOrder(i64, i64),
 }
 
 #[derive(Debug)]
@@ -156,12 +256,28 @@ impl TryFrom<(PotentialBookmark, bool)> for DraftBookmark {
     type Error = DraftBookmarkError;
 
     fn try_from(value: (PotentialBookmark, bool)) -> Result<Self, Self::Error> {
+        let (potential_bookmark, ignore_attribute_errors) = value;
+        Self::try_from((
+            potential_bookmark,
+            ignore_attribute_errors,
+            &TagAliases::default(),
+        ))
+    }
+}
+
+// tag aliases get replaced with the canonical tags they stand for
+impl TryFrom<(PotentialBookmark, bool, &TagAliases)> for DraftBookmark {
+    type Error = DraftBookmarkError;
+
+    fn try_from(value: (PotentialBookmark, bool, &TagAliases)) -> Result<Self, Self::Error> {
         #[allow(clippy::expect_used)]
         static WHITESPACE_RE: Lazy<Regex> =
             Lazy::new(|| Regex::new(r"\s+").expect("regex is invalid"));
 
-        let (potential_bookmark, ignore_attribute_errors) = value;
+        let (potential_bookmark, ignore_attribute_errors, tag_aliases) = value;
         let tags = &potential_bookmark.tags;
+        let mut corrections = Vec::new();
+        let mut skipped_details = false;
 
         Url::parse(&potential_bookmark.uri).map_err(DraftBookmarkError::CouldntParseUri)?;
 
@@ -174,6 +290,7 @@ impl TryFrom<(PotentialBookmark, bool)> for DraftBookmark {
                     if t.is_empty() {
                         None
                     } else if t.len() > TITLE_MAX_LENGTH {
+                        corrections.push(format!("title trimmed to {TITLE_MAX_LENGTH} characters"));
                         t.get(0..TITLE_MAX_LENGTH)
                     } else {
                         Some(t)
@@ -205,8 +322,22 @@ impl TryFrom<(PotentialBookmark, bool)> for DraftBookmark {
                 .iter()
                 .map(|t| t.trim())
                 .filter(|t| !t.is_empty())
-                .map(|t| WHITESPACE_RE.replace_all(t, "-").to_string())
-                .filter_map(|t| Tag::try_from(t.as_str()).ok())
+                .filter_map(|t| {
+                    let replaced = WHITESPACE_RE.replace_all(t, "-");
+                    match Tag::try_from(replaced.as_ref()) {
+                        Ok(tag) => {
+                            if replaced != t {
+                                corrections.push(format!(r#"tag "{t}" changed to "{replaced}""#));
+                            }
+                            Some(tag)
+                        }
+                        Err(_) => {
+                            corrections.push(format!(r#"invalid tag "{t}" skipped"#));
+                            skipped_details = true;
+                            None
+                        }
+                    }
+                })
                 .collect::<Vec<_>>(),
             false => {
                 let mut tags = Vec::with_capacity(tags.len());
@@ -231,11 +362,42 @@ impl TryFrom<(PotentialBookmark, bool)> for DraftBookmark {
             }
         };
 
-        Ok(Self {
+        let notes = potential_bookmark
+            .notes
+            .as_deref()
+            .map(|n| n.trim())
+            .filter(|n| !n.is_empty())
+            .map(|n| n.to_string());
+
+        let (created_at, updated_at) =
+            match (potential_bookmark.created_at, potential_bookmark.updated_at) {
+                (Some(c), Some(u)) if c > u => match ignore_attribute_errors {
+                    true => {
+                        corrections
+                            .push("last update time set to the (later) creation time".to_string());
+                        (Some(c), Some(c))
+                    }
+                    false => return Err(DraftBookmarkError::TimestampsOutOfOrder(c, u)),
+                },
+                (Some(c), Some(u)) => (Some(c), Some(u)),
+                (Some(c), None) => (Some(c), Some(c)),
+                (None, Some(u)) => (Some(u), Some(u)),
+                (None, None) => (None, None),
+            };
+
+        let mut draft_bookmark = Self {
             uri: potential_bookmark.uri,
             title,
             tags,
-        })
+            notes,
+            created_at,
+            updated_at,
+            corrections,
+            skipped_details,
+        };
+        draft_bookmark.resolve_tag_aliases(tag_aliases);
+
+        Ok(draft_bookmark)
     }
 }
 
@@ -281,6 +443,43 @@ impl DraftBookmark {
     pub fn tags(&self) -> Vec<&str> {
         self.tags.iter().map(|t| t.name()).collect()
     }
+
+    pub fn notes(&self) -> Option<&str> {
+        self.notes.as_deref()
+    }
+
+    pub fn created_at(&self) -> Option<i64> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> Option<i64> {
+        self.updated_at
+    }
+
+    pub fn corrections(&self) -> &[String] {
+        &self.corrections
+    }
+
+    pub fn skipped_details(&self) -> bool {
+        self.skipped_details
+    }
+
+    /// Replaces tag aliases with the canonical tags they stand for
+    pub fn resolve_tag_aliases(&mut self, tag_aliases: &TagAliases) {
+        let mut any_resolved = false;
+        for tag in &mut self.tags {
+            if let Some(canonical_tag) = tag_aliases.resolve(tag) {
+                *tag = canonical_tag;
+                any_resolved = true;
+            }
+        }
+
+        // the canonical tag might've been present already
+        if any_resolved {
+            self.tags.sort();
+            self.tags.dedup();
+        }
+    }
 }
 
 #[derive(Debug, Serialize, sqlx::FromRow)]
@@ -288,11 +487,30 @@ pub struct SavedBookmark {
     pub uri: String,
     pub title: Option<String>,
     pub tags: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    // the part of the bookmark that matched a search query, with matching
+    // words wrapped in "**"; only set for search results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
+    pub snippet: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct BookmarkRecord {
+    pub uri: String,
+    pub title: Option<String>,
+    pub tags: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::TagAlias;
     use insta::assert_yaml_snapshot;
 
     //-------------//
@@ -322,6 +540,31 @@ mod tests {
         "#);
     }
 
+    #[test]
+    fn tag_aliases_get_resolved_while_creating_a_draft_bookmark() {
+        // GIVEN
+        let uri = "https://github.com/launchbadge/sqlx";
+        let tags = vec!["db", "rust", "database"];
+        let potential_bookmark = PotentialBookmark::from((uri, None, &tags));
+        let tag_aliases = TagAliases::from(vec![TagAlias {
+            alias: "db".into(),
+            tag: "database".into(),
+        }]);
+
+        // WHEN
+        let draft_bookmark = DraftBookmark::try_from((potential_bookmark, false, &tag_aliases))
+            .expect("draft bookmark should've been created");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmark, @r#"
+        uri: "https://github.com/launchbadge/sqlx"
+        title: ~
+        tags:
+          - database
+          - rust
+        "#);
+    }
+
     #[test]
     fn empty_tags_get_skipped_over_while_creating_a_draft_bookmark() {
         // GIVEN
@@ -397,10 +640,92 @@ mod tests {
         "#);
     }
 
+    #[test]
+    fn creating_a_draft_bookmark_with_timestamps_works() {
+        // GIVEN
+        let uri = "https://github.com/launchbadge/sqlx";
+        let potential_bookmark = PotentialBookmark::from((uri, None, None))
+            .with_timestamps(Some(1739897020), Some(1739897041));
+
+        // WHEN
+        let draft_bookmark = DraftBookmark::try_from(potential_bookmark)
+            .expect("draft bookmark should've been created");
+
+        // THEN
+        assert_yaml_snapshot!(draft_bookmark, @r#"
+        uri: "https://github.com/launchbadge/sqlx"
+        title: ~
+        tags: []
+        created_at: 1739897020
+        updated_at: 1739897041
+        "#);
+    }
+
+    #[test]
+    fn a_single_timestamp_is_used_for_both_creation_and_update_time() {
+        // GIVEN
+        let uri = "https://github.com/launchbadge/sqlx";
+        let only_created =
+            PotentialBookmark::from((uri, None, None)).with_timestamps(Some(1739897020), None);
+        let only_updated =
+            PotentialBookmark::from((uri, None, None)).with_timestamps(None, Some(1739897041));
+
+        // WHEN
+        let from_created =
+            DraftBookmark::try_from(only_created).expect("draft bookmark should've been created");
+        let from_updated =
+            DraftBookmark::try_from(only_updated).expect("draft bookmark should've been created");
+
+        // THEN
+        assert_eq!(
+            (from_created.created_at(), from_created.updated_at()),
+            (Some(1739897020), Some(1739897020))
+        );
+        assert_eq!(
+            (from_updated.created_at(), from_updated.updated_at()),
+            (Some(1739897041), Some(1739897041))
+        );
+    }
+
+    #[test]
+    fn force_creating_a_draft_bookmark_with_out_of_order_timestamps_works() {
+        // GIVEN
+        let uri = "https://github.com/launchbadge/sqlx";
+        let potential_bookmark = PotentialBookmark::from((uri, None, None))
+            .with_timestamps(Some(1739897041), Some(1739897020));
+
+        // WHEN
+        let draft_bookmark = DraftBookmark::try_from((potential_bookmark, true))
+            .expect("draft bookmark should've been created");
+
+        // THEN
+        assert_eq!(
+            (draft_bookmark.created_at(), draft_bookmark.updated_at()),
+            (Some(1739897041), Some(1739897041))
+        );
+    }
+
     //------------//
     //  FAILURES  //
     //------------//
 
+    #[test]
+    fn draft_bookmark_cannot_be_created_with_out_of_order_timestamps() {
+        // GIVEN
+        let uri = "https://github.com/launchbadge/sqlx";
+        let potential_bookmark = PotentialBookmark::from((uri, None, None))
+            .with_timestamps(Some(1739897041), Some(1739897020));
+
+        // WHEN
+        let result = DraftBookmark::try_from(potential_bookmark);
+
+        // THEN
+        match result {
+            Err(DraftBookmarkError::TimestampsOutOfOrder(1739897041, 1739897020)) => (),
+            _ => panic!("result is incorrect for {uri}"),
+        }
+    }
+
     #[test]
     fn draft_bookmark_cannot_be_created_with_an_incorrect_uri() {
         let faulty_uris = vec![
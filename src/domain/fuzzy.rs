@@ -0,0 +1,166 @@
+use super::SavedBookmark;
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use std::cmp::Reverse;
+
+/// A bookmark that matched a fuzzy query, along with how well it matched
+#[derive(Debug)]
+pub struct FuzzyMatch {
+    pub bookmark: SavedBookmark,
+    pub score: i64,
+    /// Positions (in characters) of the bookmark's URI that matched the query
+    pub uri_indices: Vec<usize>,
+}
+
+/// Ranks bookmarks by how well they match a query, the way fzf and skim do:
+/// every word in the query needs to match a subsequence of the bookmark's URI,
+/// title, or tags (eg. "srde" matches "serde"), and the scores of the best
+/// match for each word are added up. Bookmarks that don't match are dropped,
+/// and bookmarks with the same score keep the order they were provided in.
+pub fn fuzzy_rank(bookmarks: Vec<SavedBookmark>, query: &str) -> Vec<FuzzyMatch> {
+    let matcher = SkimMatcherV2::default();
+    let words = query.split_whitespace().collect::<Vec<_>>();
+    if words.is_empty() {
+        return vec![];
+    }
+
+    let mut matches = bookmarks
+        .into_iter()
+        .filter_map(|bookmark| {
+            let mut score = 0;
+            let mut uri_indices = vec![];
+
+            for word in &words {
+                let uri_match = matcher.fuzzy_indices(&bookmark.uri, word);
+                let other_score = [bookmark.title.as_deref(), bookmark.tags.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|value| matcher.fuzzy_match(value, word))
+                    .max();
+
+                score += uri_match.as_ref().map(|(s, _)| *s).max(other_score)?;
+                if let Some((_, indices)) = uri_match {
+                    uri_indices.extend(indices);
+                }
+            }
+
+            uri_indices.sort_unstable();
+            uri_indices.dedup();
+
+            Some(FuzzyMatch {
+                bookmark,
+                score,
+                uri_indices,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    matches.sort_by_key(|m| Reverse(m.score));
+
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_yaml_snapshot;
+
+    fn bookmark(uri: &str, title: Option<&str>, tags: Option<&str>) -> SavedBookmark {
+        SavedBookmark {
+            uri: uri.into(),
+            title: title.map(String::from),
+            tags: tags.map(String::from),
+            notes: None,
+            created_at: 0,
+            updated_at: 0,
+            snippet: None,
+        }
+    }
+
+    fn test_bookmarks() -> Vec<SavedBookmark> {
+        vec![
+            bookmark(
+                "https://github.com/serde-rs/serde",
+                Some("Serialization framework for Rust"),
+                Some("rust,serialization"),
+            ),
+            bookmark(
+                "https://github.com/launchbadge/sqlx",
+                Some("The Rust SQL Toolkit"),
+                Some("database,rust"),
+            ),
+            bookmark(
+                "https://github.com/dhth/bmm",
+                Some("get to your bookmarks in a flash"),
+                Some("tools"),
+            ),
+        ]
+    }
+
+    //-------------//
+    //  SUCCESSES  //
+    //-------------//
+
+    #[test]
+    fn fuzzy_ranking_matches_abbreviations() {
+        // GIVEN
+        // WHEN
+        let matches = fuzzy_rank(test_bookmarks(), "srde");
+
+        // THEN
+        let results = matches
+            .iter()
+            .map(|m| (m.bookmark.uri.as_str(), &m.uri_indices))
+            .collect::<Vec<_>>();
+        assert_yaml_snapshot!(results, @r#"
+        - - "https://github.com/serde-rs/serde"
+          - - 28
+            - 30
+            - 31
+            - 32
+        "#);
+    }
+
+    #[test]
+    fn fuzzy_ranking_orders_results_by_score() {
+        // GIVEN
+        // WHEN
+        let matches = fuzzy_rank(test_bookmarks(), "rust");
+
+        // THEN
+        let results = matches
+            .iter()
+            .map(|m| (m.bookmark.uri.as_str(), m.score))
+            .collect::<Vec<_>>();
+        assert_yaml_snapshot!(results, @r#"
+        - - "https://github.com/serde-rs/serde"
+          - 91
+        - - "https://github.com/launchbadge/sqlx"
+          - 82
+        "#);
+    }
+
+    #[test]
+    fn fuzzy_ranking_needs_every_word_to_match() {
+        // GIVEN
+        // WHEN
+        let matches = fuzzy_rank(test_bookmarks(), "rust tlkt");
+
+        // THEN
+        let results = matches
+            .iter()
+            .map(|m| m.bookmark.uri.as_str())
+            .collect::<Vec<_>>();
+        assert_yaml_snapshot!(results, @r#"- "https://github.com/launchbadge/sqlx""#);
+    }
+
+    #[test]
+    fn fuzzy_ranking_returns_nothing_for_an_empty_query() {
+        // GIVEN
+        // WHEN
+        let matches = fuzzy_rank(test_bookmarks(), "   ");
+
+        // THEN
+        assert!(matches.is_empty());
+    }
+}
@@ -1,5 +1,15 @@
 mod bookmark;
+mod fuzzy;
+mod save_report;
+mod search_query;
+mod tag_suggestions;
 mod tags;
+mod timestamps;
 
 pub use bookmark::*;
+pub use fuzzy::*;
+pub use save_report::*;
+pub use search_query::*;
+pub use tag_suggestions::*;
 pub use tags::*;
+pub use timestamps::*;
@@ -0,0 +1,188 @@
+use super::DraftBookmark;
+use serde::Serialize;
+
+// What saving a bookmark did to bmm's database
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SaveOutcome {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+impl SaveOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SaveOutcome::Created => "created",
+            SaveOutcome::Updated => "updated",
+            SaveOutcome::Unchanged => "unchanged",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookmarkSaveOutcome {
+    pub uri: String,
+    pub outcome: SaveOutcome,
+    // changes made to the bookmark's details because of
+    // --ignore-attribute-errors
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub corrections: Vec<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct SaveSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    // bookmarks whose details were corrected because of
+    // --ignore-attribute-errors
+    pub corrected: usize,
+    // bookmarks among the corrected ones that had invalid details (eg. tags)
+    // skipped
+    pub skipped: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SaveReport {
+    pub summary: SaveSummary,
+    // outcomes of individual bookmarks are only kept when they're to be listed,
+    // as inputs can be arbitrarily large
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bookmarks: Option<Vec<BookmarkSaveOutcome>>,
+}
+
+impl SaveReport {
+    pub fn new(list_bookmarks: bool) -> Self {
+        Self {
+            summary: SaveSummary::default(),
+            bookmarks: list_bookmarks.then(Vec::new),
+        }
+    }
+
+    pub fn num_bookmarks(&self) -> usize {
+        self.summary.created + self.summary.updated + self.summary.unchanged
+    }
+
+    // outcomes are expected in the same order as the bookmarks they're for
+    pub fn add(&mut self, draft_bookmarks: &[DraftBookmark], outcomes: &[SaveOutcome]) {
+        for (db, outcome) in draft_bookmarks.iter().zip(outcomes) {
+            match outcome {
+                SaveOutcome::Created => self.summary.created += 1,
+                SaveOutcome::Updated => self.summary.updated += 1,
+                SaveOutcome::Unchanged => self.summary.unchanged += 1,
+            }
+            if !db.corrections().is_empty() {
+                self.summary.corrected += 1;
+            }
+            if db.skipped_details() {
+                self.summary.skipped += 1;
+            }
+
+            if let Some(bookmarks) = &mut self.bookmarks {
+                bookmarks.push(BookmarkSaveOutcome {
+                    uri: db.uri().to_string(),
+                    outcome: *outcome,
+                    corrections: db.corrections().to_vec(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::PotentialBookmark;
+    use insta::assert_yaml_snapshot;
+
+    #[test]
+    fn adding_outcomes_to_a_save_report_works() {
+        // GIVEN
+        let long_title = "a".repeat(600);
+        let invalid_tags = vec!["valid", "has space", "not@valid"];
+        let draft_bookmarks = vec![
+            DraftBookmark::try_from(PotentialBookmark::from((
+                "https://github.com/dhth/bmm",
+                None,
+                None,
+            ))),
+            DraftBookmark::try_from((
+                PotentialBookmark::from((
+                    "https://github.com/dhth/omm",
+                    Some(long_title.as_str()),
+                    None,
+                )),
+                true,
+            )),
+            DraftBookmark::try_from((
+                PotentialBookmark::from(("https://github.com/dhth/hours", None, &invalid_tags)),
+                true,
+            )),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("draft bookmarks should've been initialized");
+        let mut report = SaveReport::new(true);
+
+        // WHEN
+        report.add(
+            &draft_bookmarks,
+            &[
+                SaveOutcome::Created,
+                SaveOutcome::Updated,
+                SaveOutcome::Unchanged,
+            ],
+        );
+
+        // THEN
+        assert_yaml_snapshot!(report, @r#"
+        summary:
+          created: 1
+          updated: 1
+          unchanged: 1
+          corrected: 2
+          skipped: 1
+        bookmarks:
+          - uri: "https://github.com/dhth/bmm"
+            outcome: created
+          - uri: "https://github.com/dhth/omm"
+            outcome: updated
+            corrections:
+              - title trimmed to 500 characters
+          - uri: "https://github.com/dhth/hours"
+            outcome: unchanged
+            corrections:
+              - "tag \"has space\" changed to \"has-space\""
+              - "invalid tag \"not@valid\" skipped"
+        "#);
+    }
+
+    #[test]
+    fn a_save_report_only_lists_bookmarks_if_asked_to() {
+        // GIVEN
+        let draft_bookmarks = vec![
+            DraftBookmark::try_from(PotentialBookmark::from((
+                "https://github.com/dhth/bmm",
+                None,
+                None,
+            )))
+            .expect("draft bookmark should've been initialized"),
+        ];
+        let mut report = SaveReport::new(false);
+
+        // WHEN
+        report.add(&draft_bookmarks, &[SaveOutcome::Created]);
+
+        // THEN
+        assert_eq!(report.num_bookmarks(), 1);
+        assert_yaml_snapshot!(report, @"
+        summary:
+          created: 1
+          updated: 0
+          unchanged: 0
+          corrected: 0
+          skipped: 0
+        ");
+    }
+}
@@ -0,0 +1,706 @@
+use super::parse_time_bound;
+
+const SEARCH_CONDITIONS_UPPER_LIMIT: usize = 32;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum SearchQueryError {
+    #[error("query is empty")]
+    QueryEmpty,
+    #[error("too many conditions (maximum allowed: {SEARCH_CONDITIONS_UPPER_LIMIT})")]
+    TooManyConditions,
+    #[error("quote at position {0} is never closed")]
+    UnterminatedQuote(usize),
+    #[error("\"{0}:\" at position {1} is missing a value")]
+    MissingValue(&'static str, usize),
+    #[error(
+        "\"{1}\" at position {2} is not a valid time for \"{0}:\"; expected a date (eg. 2025-02-18), an RFC 3339 date-time, a unix timestamp, or a relative time (eg. 12h, 3d, 2w)"
+    )]
+    InvalidTime(&'static str, String, usize),
+    #[error("\"OR\" at position {0} needs a condition on both sides")]
+    DanglingOr(usize),
+    #[error("\"-\" at position {0} needs a condition to negate")]
+    DanglingNegation(usize),
+    #[error("parenthesis at position {0} is never closed")]
+    UnclosedParenthesis(usize),
+    #[error("parenthesis at position {0} doesn't close anything")]
+    UnexpectedClosingParenthesis(usize),
+    #[error("parentheses at position {0} don't contain any conditions")]
+    EmptyGroup(usize),
+}
+
+/// A parsed search query, eg. `rust tag:cli -tag:old (site:github.com OR title:"exact phrase")`.
+///
+/// Conditions next to each other need to match together, `OR` binds looser than
+/// that, a leading `-` negates a condition or a group, and parentheses group
+/// conditions. Unquoted words match the start of words in any attribute, while
+/// quoted ones match an exact phrase. The `tag:`, `title:`, `uri:`, `site:`,
+/// `before:`, and `after:` qualifiers restrict a condition to a single attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchQuery(SearchExpr);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchExpr {
+    And(Vec<SearchExpr>),
+    Or(Vec<SearchExpr>),
+    Not(Box<SearchExpr>),
+    Condition(SearchCondition),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchCondition {
+    /// A word that needs to start a word in any attribute
+    Text(String),
+    /// A phrase that needs to appear as is in any attribute
+    Phrase(String),
+    /// A tag the bookmark needs to have; aliases are resolved to the tag they
+    /// stand for, and tags nested under it (eg. "lang/rust" for "lang") match
+    /// as well
+    Tag(String),
+    /// Text the bookmark's title needs to contain
+    Title(String),
+    /// Text the bookmark's URI needs to contain
+    Uri(String),
+    /// A domain the bookmark's URI needs to be on (subdomains included)
+    Site(String),
+    /// A time the bookmark needs to have been added before
+    Before(i64),
+    /// A time the bookmark needs to have been added at or after
+    After(i64),
+}
+
+impl SearchQuery {
+    pub fn parse(query: &str, now: i64) -> Result<Self, SearchQueryError> {
+        let tokens = tokenize(query)?;
+        let mut parser = Parser {
+            tokens,
+            index: 0,
+            now,
+        };
+
+        let expr = parser.parse_or()?;
+        if let Some((Token::RightParen, position)) = parser.peek() {
+            return Err(SearchQueryError::UnexpectedClosingParenthesis(*position));
+        }
+
+        let expr = expr.ok_or(SearchQueryError::QueryEmpty)?;
+        if expr.num_conditions() > SEARCH_CONDITIONS_UPPER_LIMIT {
+            return Err(SearchQueryError::TooManyConditions);
+        }
+
+        Ok(Self(expr))
+    }
+
+    pub fn expr(&self) -> &SearchExpr {
+        &self.0
+    }
+
+    /// Free-text conditions that aren't negated, ie. the ones that say how
+    /// relevant a result is.
+    pub fn text_conditions(&self) -> Vec<&SearchCondition> {
+        let mut conditions = vec![];
+        self.0.collect_text_conditions(&mut conditions);
+        conditions
+    }
+}
+
+impl SearchExpr {
+    fn num_conditions(&self) -> usize {
+        match self {
+            SearchExpr::And(exprs) | SearchExpr::Or(exprs) => {
+                exprs.iter().map(SearchExpr::num_conditions).sum()
+            }
+            SearchExpr::Not(expr) => expr.num_conditions(),
+            SearchExpr::Condition(_) => 1,
+        }
+    }
+
+    fn collect_text_conditions<'a>(&'a self, conditions: &mut Vec<&'a SearchCondition>) {
+        match self {
+            SearchExpr::And(exprs) | SearchExpr::Or(exprs) => {
+                for expr in exprs {
+                    expr.collect_text_conditions(conditions);
+                }
+            }
+            SearchExpr::Not(_) => {}
+            SearchExpr::Condition(c @ (SearchCondition::Text(_) | SearchCondition::Phrase(_))) => {
+                conditions.push(c)
+            }
+            SearchExpr::Condition(_) => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Qualifier {
+    Tag,
+    Title,
+    Uri,
+    Site,
+    Before,
+    After,
+}
+
+impl Qualifier {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "tag" => Some(Qualifier::Tag),
+            "title" => Some(Qualifier::Title),
+            "uri" => Some(Qualifier::Uri),
+            "site" => Some(Qualifier::Site),
+            "before" => Some(Qualifier::Before),
+            "after" => Some(Qualifier::After),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Qualifier::Tag => "tag",
+            Qualifier::Title => "title",
+            Qualifier::Uri => "uri",
+            Qualifier::Site => "site",
+            Qualifier::Before => "before",
+            Qualifier::After => "after",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LeftParen,
+    RightParen,
+    Minus,
+    Or,
+    Word(String),
+    Quoted(String),
+    Qualified(Qualifier, String),
+}
+
+// positions are 1-based character offsets, so they can be shown to users as is
+fn tokenize(query: &str) -> Result<Vec<(Token, usize)>, SearchQueryError> {
+    let chars = query.chars().collect::<Vec<_>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let position = i + 1;
+        match chars[i] {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push((Token::LeftParen, position));
+                i += 1;
+            }
+            ')' => {
+                tokens.push((Token::RightParen, position));
+                i += 1;
+            }
+            '-' => {
+                if chars
+                    .get(i + 1)
+                    .is_none_or(|c| c.is_whitespace() || *c == ')')
+                {
+                    return Err(SearchQueryError::DanglingNegation(position));
+                }
+                tokens.push((Token::Minus, position));
+                i += 1;
+            }
+            '"' => {
+                let (value, next) = read_quoted(&chars, i)?;
+                tokens.push((Token::Quoted(value), position));
+                i = next;
+            }
+            _ => {
+                let start = i;
+                while i < chars.len() && !is_word_boundary(chars[i]) && chars[i] != ':' {
+                    i += 1;
+                }
+
+                let name = chars[start..i].iter().collect::<String>();
+                if i < chars.len()
+                    && chars[i] == ':'
+                    && let Some(qualifier) = Qualifier::from_name(&name)
+                {
+                    i += 1;
+                    let value = if chars.get(i) == Some(&'"') {
+                        let (value, next) = read_quoted(&chars, i)?;
+                        i = next;
+                        value
+                    } else {
+                        let value_start = i;
+                        while i < chars.len() && !is_word_boundary(chars[i]) {
+                            i += 1;
+                        }
+                        chars[value_start..i].iter().collect()
+                    };
+
+                    if value.trim().is_empty() {
+                        return Err(SearchQueryError::MissingValue(qualifier.name(), position));
+                    }
+
+                    tokens.push((Token::Qualified(qualifier, value), position));
+                    continue;
+                }
+
+                while i < chars.len() && !is_word_boundary(chars[i]) {
+                    i += 1;
+                }
+
+                let word = chars[start..i].iter().collect::<String>();
+                if word == "OR" {
+                    tokens.push((Token::Or, position));
+                } else {
+                    tokens.push((Token::Word(word), position));
+                }
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_word_boundary(c: char) -> bool {
+    c.is_whitespace() || c == '(' || c == ')'
+}
+
+// returns the contents of the quoted string starting at `start`, and the index
+// right after its closing quote
+fn read_quoted(chars: &[char], start: usize) -> Result<(String, usize), SearchQueryError> {
+    let end = chars[start + 1..]
+        .iter()
+        .position(|c| *c == '"')
+        .map(|offset| start + 1 + offset)
+        .ok_or(SearchQueryError::UnterminatedQuote(start + 1))?;
+
+    Ok((chars[start + 1..end].iter().collect(), end + 1))
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    index: usize,
+    now: i64,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.index)
+    }
+
+    fn next(&mut self) -> Option<(Token, usize)> {
+        let token = self.tokens.get(self.index).cloned();
+        self.index += 1;
+        token
+    }
+
+    // or := and ("OR" and)*
+    fn parse_or(&mut self) -> Result<Option<SearchExpr>, SearchQueryError> {
+        let Some(first) = self.parse_and()? else {
+            if let Some((Token::Or, position)) = self.peek() {
+                return Err(SearchQueryError::DanglingOr(*position));
+            }
+            return Ok(None);
+        };
+
+        let mut branches = vec![first];
+        while let Some((Token::Or, position)) = self.peek() {
+            let position = *position;
+            self.index += 1;
+            let branch = self
+                .parse_and()?
+                .ok_or(SearchQueryError::DanglingOr(position))?;
+            branches.push(branch);
+        }
+
+        Ok(Some(match branches.len() {
+            1 => branches.remove(0),
+            _ => SearchExpr::Or(branches),
+        }))
+    }
+
+    // and := unary+
+    fn parse_and(&mut self) -> Result<Option<SearchExpr>, SearchQueryError> {
+        let mut exprs = vec![];
+        while let Some((token, _)) = self.peek() {
+            if matches!(token, Token::Or | Token::RightParen) {
+                break;
+            }
+            exprs.push(self.parse_unary()?);
+        }
+
+        Ok(match exprs.len() {
+            0 => None,
+            1 => Some(exprs.remove(0)),
+            _ => Some(SearchExpr::And(exprs)),
+        })
+    }
+
+    // unary := "-" unary | atom
+    fn parse_unary(&mut self) -> Result<SearchExpr, SearchQueryError> {
+        if let Some((Token::Minus, position)) = self.peek() {
+            let position = *position;
+            self.index += 1;
+            if matches!(self.peek(), None | Some((Token::Or | Token::RightParen, _))) {
+                return Err(SearchQueryError::DanglingNegation(position));
+            }
+            return Ok(SearchExpr::Not(Box::new(self.parse_unary()?)));
+        }
+
+        self.parse_atom()
+    }
+
+    // atom := "(" or ")" | word | quoted | qualifier ":" value
+    fn parse_atom(&mut self) -> Result<SearchExpr, SearchQueryError> {
+        let Some((token, position)) = self.next() else {
+            return Err(SearchQueryError::QueryEmpty);
+        };
+
+        let condition = match token {
+            Token::LeftParen => {
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some((Token::RightParen, _)) => {}
+                    _ => return Err(SearchQueryError::UnclosedParenthesis(position)),
+                }
+                return inner.ok_or(SearchQueryError::EmptyGroup(position));
+            }
+            Token::Word(word) => SearchCondition::Text(word),
+            Token::Quoted(phrase) => SearchCondition::Phrase(phrase),
+            Token::Qualified(qualifier, value) => match qualifier {
+                Qualifier::Tag => SearchCondition::Tag(value),
+                Qualifier::Title => SearchCondition::Title(value),
+                Qualifier::Uri => SearchCondition::Uri(value),
+                Qualifier::Site => SearchCondition::Site(value.trim().to_lowercase()),
+                Qualifier::Before | Qualifier::After => {
+                    let time = parse_time_bound(&value, self.now).ok_or_else(|| {
+                        SearchQueryError::InvalidTime(qualifier.name(), value.clone(), position)
+                    })?;
+                    match qualifier {
+                        Qualifier::Before => SearchCondition::Before(time),
+                        _ => SearchCondition::After(time),
+                    }
+                }
+            },
+            // the callers stop at these
+            Token::RightParen | Token::Minus | Token::Or => {
+                return Err(SearchQueryError::QueryEmpty);
+            }
+        };
+
+        Ok(SearchExpr::Condition(condition))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_debug_snapshot;
+
+    const NOW: i64 = 1739920697;
+
+    //-------------//
+    //  SUCCESSES  //
+    //-------------//
+
+    #[test]
+    fn parsing_plain_terms_works() {
+        // GIVEN
+        // WHEN
+        let query = SearchQuery::parse("  rust  keyboard-driven https://github.com ", NOW)
+            .expect("query should've been parsed");
+
+        // THEN
+        assert_debug_snapshot!(query, @r#"
+        SearchQuery(
+            And(
+                [
+                    Condition(
+                        Text(
+                            "rust",
+                        ),
+                    ),
+                    Condition(
+                        Text(
+                            "keyboard-driven",
+                        ),
+                    ),
+                    Condition(
+                        Text(
+                            "https://github.com",
+                        ),
+                    ),
+                ],
+            ),
+        )
+        "#);
+    }
+
+    #[test]
+    fn parsing_qualifiers_works() {
+        // GIVEN
+        // WHEN
+        let query = SearchQuery::parse(
+            r#"tag:rust -tag:old title:"exact phrase" uri:dhth/bmm site:GitHub.com before:2025-01-01 after:1w"#,
+            NOW,
+        )
+        .expect("query should've been parsed");
+
+        // THEN
+        assert_debug_snapshot!(query, @r#"
+        SearchQuery(
+            And(
+                [
+                    Condition(
+                        Tag(
+                            "rust",
+                        ),
+                    ),
+                    Not(
+                        Condition(
+                            Tag(
+                                "old",
+                            ),
+                        ),
+                    ),
+                    Condition(
+                        Title(
+                            "exact phrase",
+                        ),
+                    ),
+                    Condition(
+                        Uri(
+                            "dhth/bmm",
+                        ),
+                    ),
+                    Condition(
+                        Site(
+                            "github.com",
+                        ),
+                    ),
+                    Condition(
+                        Before(
+                            1735689600,
+                        ),
+                    ),
+                    Condition(
+                        After(
+                            1739315897,
+                        ),
+                    ),
+                ],
+            ),
+        )
+        "#);
+    }
+
+    #[test]
+    fn or_binds_looser_than_and() {
+        // GIVEN
+        // WHEN
+        let query = SearchQuery::parse(r#"rust cli OR "command line" tag:tools"#, NOW)
+            .expect("query should've been parsed");
+
+        // THEN
+        assert_debug_snapshot!(query, @r#"
+        SearchQuery(
+            Or(
+                [
+                    And(
+                        [
+                            Condition(
+                                Text(
+                                    "rust",
+                                ),
+                            ),
+                            Condition(
+                                Text(
+                                    "cli",
+                                ),
+                            ),
+                        ],
+                    ),
+                    And(
+                        [
+                            Condition(
+                                Phrase(
+                                    "command line",
+                                ),
+                            ),
+                            Condition(
+                                Tag(
+                                    "tools",
+                                ),
+                            ),
+                        ],
+                    ),
+                ],
+            ),
+        )
+        "#);
+    }
+
+    #[test]
+    fn parsing_groups_and_negated_groups_works() {
+        // GIVEN
+        // WHEN
+        let query = SearchQuery::parse("rust (tag:cli OR tag:tui) -(site:github.com OR old)", NOW)
+            .expect("query should've been parsed");
+
+        // THEN
+        assert_debug_snapshot!(query, @r#"
+        SearchQuery(
+            And(
+                [
+                    Condition(
+                        Text(
+                            "rust",
+                        ),
+                    ),
+                    Or(
+                        [
+                            Condition(
+                                Tag(
+                                    "cli",
+                                ),
+                            ),
+                            Condition(
+                                Tag(
+                                    "tui",
+                                ),
+                            ),
+                        ],
+                    ),
+                    Not(
+                        Or(
+                            [
+                                Condition(
+                                    Site(
+                                        "github.com",
+                                    ),
+                                ),
+                                Condition(
+                                    Text(
+                                        "old",
+                                    ),
+                                ),
+                            ],
+                        ),
+                    ),
+                ],
+            ),
+        )
+        "#);
+    }
+
+    #[test]
+    fn unknown_qualifiers_and_lowercase_or_are_treated_as_terms() {
+        // GIVEN
+        // WHEN
+        let query = SearchQuery::parse("note:rust or", NOW).expect("query should've been parsed");
+
+        // THEN
+        assert_debug_snapshot!(query, @r#"
+        SearchQuery(
+            And(
+                [
+                    Condition(
+                        Text(
+                            "note:rust",
+                        ),
+                    ),
+                    Condition(
+                        Text(
+                            "or",
+                        ),
+                    ),
+                ],
+            ),
+        )
+        "#);
+    }
+
+    #[test]
+    fn text_conditions_skip_negated_ones() {
+        // GIVEN
+        let query = SearchQuery::parse(r#"rust -old ("a phrase" OR tag:cli) -(-skipped)"#, NOW)
+            .expect("query should've been parsed");
+
+        // WHEN
+        let conditions = query.text_conditions();
+
+        // THEN
+        assert_debug_snapshot!(conditions, @r#"
+        [
+            Text(
+                "rust",
+            ),
+            Phrase(
+                "a phrase",
+            ),
+        ]
+        "#);
+    }
+
+    //------------//
+    //  FAILURES  //
+    //------------//
+
+    #[test]
+    fn parsing_invalid_queries_fails_with_clear_errors() {
+        // GIVEN
+        let queries = [
+            "   ",
+            r#"rust "command line"#,
+            "tag: rust",
+            r#"title:"""#,
+            "before:yesterday",
+            "OR rust",
+            "rust OR",
+            "rust OR OR cli",
+            "rust -",
+            "(rust cli",
+            "rust) cli",
+            "rust () cli",
+        ];
+
+        // WHEN
+        let errors = queries
+            .iter()
+            .map(|q| {
+                let error = SearchQuery::parse(q, NOW).expect_err("parsing should've failed");
+                format!("{q} => {error}")
+            })
+            .collect::<Vec<_>>();
+
+        // THEN
+        assert_debug_snapshot!(errors, @r#"
+        [
+            "    => query is empty",
+            "rust \"command line => quote at position 6 is never closed",
+            "tag: rust => \"tag:\" at position 1 is missing a value",
+            "title:\"\" => \"title:\" at position 1 is missing a value",
+            "before:yesterday => \"yesterday\" at position 1 is not a valid time for \"before:\"; expected a date (eg. 2025-02-18), an RFC 3339 date-time, a unix timestamp, or a relative time (eg. 12h, 3d, 2w)",
+            "OR rust => \"OR\" at position 1 needs a condition on both sides",
+            "rust OR => \"OR\" at position 6 needs a condition on both sides",
+            "rust OR OR cli => \"OR\" at position 6 needs a condition on both sides",
+            "rust - => \"-\" at position 6 needs a condition to negate",
+            "(rust cli => parenthesis at position 1 is never closed",
+            "rust) cli => parenthesis at position 5 doesn't close anything",
+            "rust () cli => parentheses at position 6 don't contain any conditions",
+        ]
+        "#);
+    }
+
+    #[test]
+    fn parsing_fails_if_query_has_too_many_conditions() {
+        // GIVEN
+        let query = (0..=SEARCH_CONDITIONS_UPPER_LIMIT)
+            .map(|i| format!("term{i}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        // WHEN
+        let error = SearchQuery::parse(&query, NOW).expect_err("parsing should've failed");
+
+        // THEN
+        assert_eq!(error, SearchQueryError::TooManyConditions);
+    }
+}
@@ -0,0 +1,271 @@
+use super::TagStats;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use url::Url;
+
+// tags that appear on less than this percentage of the related bookmarks are
+// not suggested
+const MIN_SUGGESTION_SHARE_PERCENT: i64 = 30;
+
+/// Why a tag is suggested
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagSuggestionSource {
+    /// The tag is common on bookmarks from the same domain
+    Domain,
+    /// The tag often appears along with the tags already chosen
+    CoOccurrence,
+}
+
+/// How often tags appear on a group of related bookmarks (eg. the ones from the
+/// same domain)
+#[derive(Debug, Default)]
+pub struct RelatedTagCounts {
+    pub num_bookmarks: i64,
+    pub tags: Vec<TagStats>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagSuggestion {
+    pub tag: String,
+    /// Number of related bookmarks with the tag
+    pub num_bookmarks: i64,
+    /// Number of related bookmarks
+    pub out_of: i64,
+    pub source: TagSuggestionSource,
+}
+
+impl TagSuggestion {
+    fn cmp_share(&self, other: &Self) -> Ordering {
+        (self.num_bookmarks * other.out_of).cmp(&(other.num_bookmarks * self.out_of))
+    }
+}
+
+impl std::fmt::Display for TagSuggestion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let related = match self.source {
+            TagSuggestionSource::Domain => "bookmarks from the same domain",
+            TagSuggestionSource::CoOccurrence => "bookmarks with the same tags",
+        };
+
+        write!(
+            f,
+            "{} (on {} of {} {related})",
+            self.tag, self.num_bookmarks, self.out_of
+        )
+    }
+}
+
+/// Returns the domain to look for related bookmarks on, eg. "docs.rs" for
+/// "https://docs.rs/sqlx"; a leading "www." is dropped so that bookmarks from
+/// the bare domain and its other subdomains are related as well.
+pub fn uri_domain(uri: &str) -> Option<String> {
+    let url = Url::parse(uri).ok()?;
+    let host = url.host_str()?.to_lowercase();
+    let domain = host.strip_prefix("www.").unwrap_or(&host);
+
+    Some(domain.to_string())
+}
+
+/// Ranks tags by the share of related bookmarks they appear on. Tags already
+/// chosen are left out, and a tag suggested for both reasons is reported with
+/// the one where it's more common.
+pub fn rank_tag_suggestions(
+    by_domain: &RelatedTagCounts,
+    by_co_occurrence: &RelatedTagCounts,
+    chosen_tags: &[String],
+    limit: usize,
+) -> Vec<TagSuggestion> {
+    let mut suggestions: HashMap<String, TagSuggestion> = HashMap::new();
+
+    for (counts, source) in [
+        (by_domain, TagSuggestionSource::Domain),
+        (by_co_occurrence, TagSuggestionSource::CoOccurrence),
+    ] {
+        if counts.num_bookmarks == 0 {
+            continue;
+        }
+
+        for stats in &counts.tags {
+            if chosen_tags.contains(&stats.name)
+                || stats.num_bookmarks * 100 < MIN_SUGGESTION_SHARE_PERCENT * counts.num_bookmarks
+            {
+                continue;
+            }
+
+            let suggestion = TagSuggestion {
+                tag: stats.name.clone(),
+                num_bookmarks: stats.num_bookmarks,
+                out_of: counts.num_bookmarks,
+                source,
+            };
+
+            match suggestions.get(&stats.name) {
+                Some(existing) if existing.cmp_share(&suggestion) != Ordering::Less => {}
+                _ => {
+                    suggestions.insert(stats.name.clone(), suggestion);
+                }
+            }
+        }
+    }
+
+    let mut suggestions = suggestions.into_values().collect::<Vec<_>>();
+    suggestions.sort_by(|a, b| {
+        b.cmp_share(a)
+            .then_with(|| b.num_bookmarks.cmp(&a.num_bookmarks))
+            .then_with(|| a.tag.cmp(&b.tag))
+    });
+    suggestions.truncate(limit);
+
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use insta::assert_yaml_snapshot;
+
+    fn counts(num_bookmarks: i64, tags: &[(&str, i64)]) -> RelatedTagCounts {
+        RelatedTagCounts {
+            num_bookmarks,
+            tags: tags
+                .iter()
+                .map(|(name, num_bookmarks)| TagStats {
+                    name: name.to_string(),
+                    num_bookmarks: *num_bookmarks,
+                })
+                .collect(),
+        }
+    }
+
+    //-------------//
+    //  SUCCESSES  //
+    //-------------//
+
+    #[test]
+    fn getting_uri_domain_works() {
+        // GIVEN
+        let uris = [
+            "https://docs.rs/sqlx",
+            "https://WWW.GitHub.com/dhth/bmm",
+            "http://localhost:8080/bookmarks",
+            "mailto:someone@example.com",
+            "not a uri",
+        ];
+
+        // WHEN
+        let domains = uris.iter().map(|u| uri_domain(u)).collect::<Vec<_>>();
+
+        // THEN
+        assert_yaml_snapshot!(domains, @r"
+        - docs.rs
+        - github.com
+        - localhost
+        - ~
+        - ~
+        ");
+    }
+
+    #[test]
+    fn ranking_tag_suggestions_orders_them_by_share_of_related_bookmarks() {
+        // GIVEN
+        let by_domain = counts(10, &[("rust", 9), ("docs", 6), ("async", 2)]);
+        let by_co_occurrence = counts(4, &[("crates", 3), ("docs", 1)]);
+
+        // WHEN
+        let suggestions = rank_tag_suggestions(&by_domain, &by_co_occurrence, &[], 5);
+
+        // THEN
+        assert_yaml_snapshot!(suggestions, @"
+        - tag: rust
+          num_bookmarks: 9
+          out_of: 10
+          source: domain
+        - tag: crates
+          num_bookmarks: 3
+          out_of: 4
+          source: co_occurrence
+        - tag: docs
+          num_bookmarks: 6
+          out_of: 10
+          source: domain
+        ");
+    }
+
+    #[test]
+    fn ranking_tag_suggestions_keeps_the_higher_share_for_tags_suggested_twice() {
+        // GIVEN
+        let by_domain = counts(10, &[("docs", 4)]);
+        let by_co_occurrence = counts(2, &[("docs", 2)]);
+
+        // WHEN
+        let suggestions = rank_tag_suggestions(&by_domain, &by_co_occurrence, &[], 5);
+
+        // THEN
+        assert_yaml_snapshot!(suggestions, @"
+        - tag: docs
+          num_bookmarks: 2
+          out_of: 2
+          source: co_occurrence
+        ");
+    }
+
+    #[test]
+    fn ranking_tag_suggestions_leaves_out_chosen_tags() {
+        // GIVEN
+        let by_domain = counts(3, &[("rust", 3), ("docs", 2)]);
+
+        // WHEN
+        let suggestions = rank_tag_suggestions(
+            &by_domain,
+            &RelatedTagCounts::default(),
+            &["rust".to_string()],
+            5,
+        );
+
+        // THEN
+        assert_yaml_snapshot!(suggestions, @"
+        - tag: docs
+          num_bookmarks: 2
+          out_of: 3
+          source: domain
+        ");
+    }
+
+    #[test]
+    fn ranking_tag_suggestions_respects_the_limit() {
+        // GIVEN
+        let by_domain = counts(2, &[("a", 2), ("b", 2), ("c", 1)]);
+
+        // WHEN
+        let suggestions = rank_tag_suggestions(&by_domain, &RelatedTagCounts::default(), &[], 2);
+
+        // THEN
+        assert_yaml_snapshot!(suggestions, @"
+        - tag: a
+          num_bookmarks: 2
+          out_of: 2
+          source: domain
+        - tag: b
+          num_bookmarks: 2
+          out_of: 2
+          source: domain
+        ");
+    }
+
+    #[test]
+    fn ranking_tag_suggestions_returns_nothing_without_related_bookmarks() {
+        // GIVEN
+        // WHEN
+        let suggestions = rank_tag_suggestions(
+            &RelatedTagCounts::default(),
+            &RelatedTagCounts::default(),
+            &[],
+            5,
+        );
+
+        // THEN
+        assert!(suggestions.is_empty());
+    }
+}
@@ -1,10 +1,15 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 use serde::Serialize;
+use std::collections::HashMap;
 
-pub const TAG_REGEX_STR: &str = r"^[a-zA-Z0-9_-]{1,30}$";
+// tags can be nested under other tags (up to 5 levels deep) by separating
+// their segments with a "/", eg. "lang/rust"
+pub const TAG_REGEX_STR: &str = r"^[a-zA-Z0-9_-]{1,30}(/[a-zA-Z0-9_-]{1,30}){0,4}$";
+pub const TAG_SEPARATOR: char = '/';
+const TAG_MAX_LENGTH: usize = 30;
 
-#[derive(PartialEq, Eq, Serialize, Debug, PartialOrd, Ord)]
+#[derive(Clone, PartialEq, Eq, Serialize, Debug, PartialOrd, Ord)]
 pub struct Tag(String);
 
 impl Tag {
@@ -40,6 +45,35 @@ impl TryFrom<&str> for Tag {
     }
 }
 
+/// Turns an arbitrary name (eg. a browser bookmark folder) into a tag that
+/// satisfies [`TAG_REGEX_STR`]: non-ASCII letters and digits are dropped, runs
+/// of other unsupported characters become a single hyphen, and the result is
+/// lowercased and truncated. Returns `None` if nothing usable is left.
+pub fn sanitize_tag_name(name: &str) -> Option<String> {
+    let mut sanitized = String::with_capacity(name.len());
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+            sanitized.push(c.to_ascii_lowercase());
+        } else if c.is_alphanumeric() {
+            continue;
+        } else if !sanitized.ends_with('-') {
+            sanitized.push('-');
+        }
+    }
+
+    let sanitized = sanitized.trim_matches('-');
+    let truncated = sanitized
+        .get(..TAG_MAX_LENGTH)
+        .unwrap_or(sanitized)
+        .trim_end_matches('-');
+
+    if truncated.is_empty() {
+        None
+    } else {
+        Some(truncated.to_string())
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct TagStats {
     pub name: String,
@@ -58,6 +92,102 @@ impl std::fmt::Display for TagStats {
     }
 }
 
+/// An alternative name for a tag, eg. "js" for "javascript"
+#[derive(Debug, Serialize)]
+pub struct TagAlias {
+    pub alias: String,
+    pub tag: String,
+}
+
+/// Maps tag aliases to the canonical tags they stand for
+#[derive(Debug, Default)]
+pub struct TagAliases(HashMap<String, String>);
+
+impl TagAliases {
+    /// Returns the canonical tag for a tag, if the tag is an alias
+    pub fn resolve(&self, tag: &Tag) -> Option<Tag> {
+        self.0
+            .get(tag.name())
+            .map(|canonical| Tag(canonical.clone()))
+    }
+}
+
+impl From<Vec<TagAlias>> for TagAliases {
+    fn from(aliases: Vec<TagAlias>) -> Self {
+        Self(aliases.into_iter().map(|a| (a.alias, a.tag)).collect())
+    }
+}
+
+/// A tag in the tag hierarchy, along with the tags nested under it. Tags that
+/// only exist as parents of other tags (eg. "lang" when only "lang/rust" is
+/// saved) are part of the tree as well.
+#[derive(Debug, Serialize)]
+pub struct TagNode {
+    /// Full name of the tag, eg. "lang/rust"
+    pub name: String,
+    /// Number of bookmarks with this tag, or any of the tags nested under it;
+    /// bookmarks with more than one tag in the subtree are counted once for
+    /// each of them
+    pub num_bookmarks: i64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<TagNode>,
+}
+
+impl TagNode {
+    /// The last segment of the tag's name, eg. "rust" for "lang/rust"
+    pub fn leaf_name(&self) -> &str {
+        self.name
+            .rsplit(TAG_SEPARATOR)
+            .next()
+            .unwrap_or(self.name.as_str())
+    }
+}
+
+/// Arranges tags into a tree based on their names, rolling up the number of
+/// bookmarks of nested tags into their parents. Tags at each level are sorted
+/// by name.
+pub fn build_tag_tree(tags: &[TagStats]) -> Vec<TagNode> {
+    let mut roots: Vec<TagNode> = vec![];
+
+    for tag in tags {
+        let mut level = &mut roots;
+        let mut name = String::new();
+        for segment in tag.name.split(TAG_SEPARATOR) {
+            if !name.is_empty() {
+                name.push(TAG_SEPARATOR);
+            }
+            name.push_str(segment);
+
+            let index = match level.iter().position(|node| node.name == name) {
+                Some(index) => index,
+                None => {
+                    level.push(TagNode {
+                        name: name.clone(),
+                        num_bookmarks: 0,
+                        children: vec![],
+                    });
+                    level.len() - 1
+                }
+            };
+
+            let node = &mut level[index];
+            node.num_bookmarks += tag.num_bookmarks;
+            level = &mut node.children;
+        }
+    }
+
+    sort_tag_nodes(&mut roots);
+
+    roots
+}
+
+fn sort_tag_nodes(nodes: &mut [TagNode]) {
+    nodes.sort_by(|a, b| a.name.cmp(&b.name));
+    for node in nodes {
+        sort_tag_nodes(&mut node.children);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,7 +200,16 @@ mod tests {
     #[test]
     fn parsing_valid_tag_works() {
         // GIVEN
-        let tags = ["tag", "tAg", "tag1", "t1ag2", "tag-1", "tag_1"];
+        let tags = [
+            "tag",
+            "tAg",
+            "tag1",
+            "t1ag2",
+            "tag-1",
+            "tag_1",
+            "lang/rust",
+            "a/b/c/d/e",
+        ];
 
         // WHEN
         let results: Vec<String> = tags
@@ -91,6 +230,8 @@ mod tests {
         - t1ag2
         - tag-1
         - tag_1
+        - lang/rust
+        - a/b/c/d/e
         ");
     }
 
@@ -115,13 +256,137 @@ mod tests {
         assert_eq!(result.name(), "upper-and-lower-case-chars");
     }
 
+    #[test]
+    fn sanitizing_tag_names_works() {
+        // GIVEN
+        let names = [
+            "productivity",
+            "Bookmarks Toolbar",
+            "  Rust & Go  ",
+            "github.com",
+            "déjà vu",
+            "a_folder-name",
+            "a folder with a name that goes well beyond the limit",
+        ];
+
+        // WHEN
+        let results: Vec<Option<String>> = names.iter().map(|n| sanitize_tag_name(n)).collect();
+
+        // THEN
+        assert_yaml_snapshot!(results, @"
+        - productivity
+        - bookmarks-toolbar
+        - rust-go
+        - github-com
+        - dj-vu
+        - a_folder-name
+        - a-folder-with-a-name-that-goes
+        ");
+        for tag in results.iter().flatten() {
+            assert!(Tag::try_from(tag.as_str()).is_ok());
+        }
+    }
+
+    #[test]
+    fn building_tag_tree_works() {
+        // GIVEN
+        let tags = [
+            ("lang/go", 2),
+            ("lang/rust", 3),
+            ("lang/rust/async", 1),
+            ("lang-old", 1),
+            ("tools", 4),
+            ("tools/cli", 1),
+        ]
+        .into_iter()
+        .map(|(name, num_bookmarks)| TagStats {
+            name: name.into(),
+            num_bookmarks,
+        })
+        .collect::<Vec<_>>();
+
+        // WHEN
+        let tree = build_tag_tree(&tags);
+
+        // THEN
+        assert_yaml_snapshot!(tree, @"
+        - name: lang
+          num_bookmarks: 6
+          children:
+            - name: lang/go
+              num_bookmarks: 2
+            - name: lang/rust
+              num_bookmarks: 4
+              children:
+                - name: lang/rust/async
+                  num_bookmarks: 1
+        - name: lang-old
+          num_bookmarks: 1
+        - name: tools
+          num_bookmarks: 5
+          children:
+            - name: tools/cli
+              num_bookmarks: 1
+        ");
+        assert_eq!(tree[0].children[1].leaf_name(), "rust");
+    }
+
+    #[test]
+    fn resolving_tag_aliases_works() {
+        // GIVEN
+        let aliases = TagAliases::from(vec![TagAlias {
+            alias: "js".into(),
+            tag: "javascript".into(),
+        }]);
+
+        // WHEN
+        let results = ["js", "rust", "javascript"]
+            .iter()
+            .map(|t| {
+                aliases
+                    .resolve(&Tag::try_from(*t).expect("should've parsed tag"))
+                    .map(|tag| tag.name().to_string())
+            })
+            .collect::<Vec<_>>();
+
+        // THEN
+        assert_yaml_snapshot!(results, @"
+        - javascript
+        - ~
+        - ~
+        ");
+    }
+
+    #[test]
+    fn sanitizing_tag_names_with_no_usable_characters_returns_none() {
+        // GIVEN
+        let names = ["", "   ", "???", "日本語"];
+
+        // WHEN
+        // THEN
+        for name in names {
+            assert!(sanitize_tag_name(name).is_none());
+        }
+    }
+
     //------------//
     //  FAILURES  //
     //------------//
 
     #[test]
     fn parsing_invalid_tag_fails() {
-        let invalid_tags = vec!["", "t ag", "tag??", "ta!g", "[tag]", "tag$"];
+        let invalid_tags = vec![
+            "",
+            "t ag",
+            "tag??",
+            "ta!g",
+            "[tag]",
+            "tag$",
+            "/tag",
+            "tag/",
+            "lang//rust",
+            "a/b/c/d/e/f",
+        ];
         for tag in invalid_tags {
             // GIVEN
             // WHEN
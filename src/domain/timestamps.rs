@@ -0,0 +1,351 @@
+// Unix timestamps beyond this (in the year 5138) are assumed to be in
+// milliseconds or microseconds
+const MAX_TIMESTAMP_SECS: u64 = 100_000_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+const SECONDS_PER_HOUR: i64 = 3_600;
+
+/// Parses a point in time into a unix timestamp (in seconds). Accepts:
+///
+/// - unix timestamps, in seconds, milliseconds, or microseconds
+/// - RFC 3339 date-times, eg. "2025-02-18T22:38:40Z" or "2025-02-18 22:38:40+05:30"
+///   (date-times without an offset are assumed to be in UTC)
+/// - dates, eg. "2025-02-18" (assumed to be midnight UTC)
+pub fn parse_timestamp(value: &str) -> Option<i64> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    if let Ok(mut timestamp) = value.parse::<i64>() {
+        // unsigned_abs, as abs overflows for i64::MIN
+        while timestamp.unsigned_abs() >= MAX_TIMESTAMP_SECS {
+            timestamp /= 1000;
+        }
+        return Some(timestamp);
+    }
+
+    parse_date_time(value)
+}
+
+/// Parses a bound for filtering bookmarks by time. Besides everything
+/// [`parse_timestamp`] accepts, this takes durations relative to `now`, in
+/// hours, days, or weeks, eg. "12h", "3d", or "1w".
+pub fn parse_time_bound(value: &str, now: i64) -> Option<i64> {
+    let value = value.trim();
+    let unit_secs = match value.chars().last()? {
+        'h' => SECONDS_PER_HOUR,
+        'd' => SECONDS_PER_DAY,
+        'w' => 7 * SECONDS_PER_DAY,
+        _ => return parse_timestamp(value),
+    };
+
+    let amount = &value[..value.len() - 1];
+    if amount.is_empty() || !amount.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let amount = amount.parse::<i64>().ok()?;
+    Some(now - amount.checked_mul(unit_secs)?)
+}
+
+/// Formats a unix timestamp as a UTC date-time, eg. "2025-02-18 23:18:17 UTC"
+pub fn format_timestamp(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(SECONDS_PER_DAY);
+    let seconds = timestamp.rem_euclid(SECONDS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        seconds / SECONDS_PER_HOUR,
+        (seconds % SECONDS_PER_HOUR) / 60,
+        seconds % 60
+    )
+}
+
+fn parse_date_time(value: &str) -> Option<i64> {
+    let (date, time) = match value.find(['T', 't', ' ']) {
+        Some(index) => (&value[..index], Some(&value[index + 1..])),
+        None => (value, None),
+    };
+
+    let days = parse_date(date)?;
+    let seconds = match time {
+        Some(t) => parse_time(t)?,
+        None => 0,
+    };
+
+    Some(days * SECONDS_PER_DAY + seconds)
+}
+
+// returns the number of days since the unix epoch
+fn parse_date(date: &str) -> Option<i64> {
+    let mut parts = date.splitn(3, '-');
+    let year = parse_number(parts.next()?, 4)?;
+    let month = parse_number(parts.next()?, 2)?;
+    let day = parse_number(parts.next()?, 2)?;
+
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    Some(days_from_civil(year, month, day))
+}
+
+// returns the number of seconds since midnight UTC, after applying the offset
+fn parse_time(time: &str) -> Option<i64> {
+    let (clock, offset_secs) = match time.find(['Z', 'z', '+', '-']) {
+        Some(index) => (&time[..index], parse_offset(&time[index..])?),
+        None => (time, 0),
+    };
+
+    // fractional seconds are ignored
+    let clock = clock.split('.').next()?;
+    let mut parts = clock.splitn(3, ':');
+    let hour = parse_number(parts.next()?, 2)?;
+    let minute = parse_number(parts.next()?, 2)?;
+    let second = match parts.next() {
+        Some(s) => parse_number(s, 2)?,
+        None => 0,
+    };
+
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    Some(hour * 3600 + minute * 60 + second - offset_secs)
+}
+
+fn parse_offset(offset: &str) -> Option<i64> {
+    let sign = match offset.chars().next()? {
+        'Z' | 'z' if offset.len() == 1 => return Some(0),
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+
+    let digits = offset[1..].replace(':', "");
+    if digits.len() != 4 {
+        return None;
+    }
+    let hours = parse_number(&digits[..2], 2)?;
+    let minutes = parse_number(&digits[2..], 2)?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+fn parse_number(value: &str, num_digits: usize) -> Option<i64> {
+    if value.len() != num_digits || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Howard Hinnant's days_from_civil algorithm
+// (http://howardhinnant.github.io/date_algorithms.html#days_from_civil)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+// Howard Hinnant's civil_from_days algorithm, the inverse of days_from_civil
+// (http://howardhinnant.github.io/date_algorithms.html#civil_from_days)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //-------------//
+    //  SUCCESSES  //
+    //-------------//
+
+    #[test]
+    fn parsing_unix_timestamps_works() {
+        // GIVEN
+        let values = ["1739920697", "1739920697123", "1739920697123456", " 0 "];
+
+        // WHEN
+        let results: Vec<Option<i64>> = values.iter().map(|v| parse_timestamp(v)).collect();
+
+        // THEN
+        assert_eq!(
+            results,
+            vec![
+                Some(1739920697),
+                Some(1739920697),
+                Some(1739920697),
+                Some(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn parsing_the_smallest_unix_timestamp_doesnt_overflow() {
+        // GIVEN
+        let value = i64::MIN.to_string();
+
+        // WHEN
+        let result = parse_timestamp(&value);
+
+        // THEN
+        assert_eq!(result, Some(-9223372036));
+    }
+
+    #[test]
+    fn parsing_date_times_works() {
+        // GIVEN
+        let values = [
+            "2025-02-18T23:18:17Z",
+            "2025-02-18t23:18:17z",
+            "2025-02-18T23:18:17.789Z",
+            "2025-02-18 23:18:17",
+            "2025-02-19T04:48:17+05:30",
+            "2025-02-18T18:18:17-0500",
+        ];
+
+        // WHEN
+        let results: Vec<Option<i64>> = values.iter().map(|v| parse_timestamp(v)).collect();
+
+        // THEN
+        for result in results {
+            assert_eq!(result, Some(1739920697));
+        }
+        assert_eq!(parse_timestamp("2025-02-18T23:18Z"), Some(1739920680));
+    }
+
+    #[test]
+    fn parsing_dates_works() {
+        // GIVEN
+        let values = ["1970-01-01", "2000-02-29", "2025-02-18", "1969-12-31"];
+
+        // WHEN
+        let results: Vec<Option<i64>> = values.iter().map(|v| parse_timestamp(v)).collect();
+
+        // THEN
+        assert_eq!(
+            results,
+            vec![Some(0), Some(951782400), Some(1739836800), Some(-86400)]
+        );
+    }
+
+    #[test]
+    fn parsing_relative_time_bounds_works() {
+        // GIVEN
+        let now = 1739920697;
+        let values = ["12h", "3d", "1w", "0d", "2025-02-18"];
+
+        // WHEN
+        let results: Vec<Option<i64>> = values.iter().map(|v| parse_time_bound(v, now)).collect();
+
+        // THEN
+        assert_eq!(
+            results,
+            vec![
+                Some(now - 12 * 3600),
+                Some(now - 3 * 86400),
+                Some(now - 7 * 86400),
+                Some(now),
+                Some(1739836800)
+            ]
+        );
+    }
+
+    #[test]
+    fn formatting_timestamps_works() {
+        // GIVEN
+        let timestamps = [1739920697, 0, 951782400, -1];
+
+        // WHEN
+        let results: Vec<String> = timestamps.iter().map(|t| format_timestamp(*t)).collect();
+
+        // THEN
+        assert_eq!(
+            results,
+            vec![
+                "2025-02-18 23:18:17 UTC",
+                "1970-01-01 00:00:00 UTC",
+                "2000-02-29 00:00:00 UTC",
+                "1969-12-31 23:59:59 UTC"
+            ]
+        );
+    }
+
+    #[test]
+    fn formatting_timestamps_is_the_inverse_of_parsing_them() {
+        // GIVEN
+        let values = ["1600-03-01", "1999-12-31", "2024-02-29", "2400-12-31"];
+
+        // WHEN
+        let results: Vec<Option<String>> = values
+            .iter()
+            .map(|v| parse_timestamp(v).map(format_timestamp))
+            .collect();
+
+        // THEN
+        for (value, result) in values.iter().zip(results) {
+            assert_eq!(result, Some(format!("{value} 00:00:00 UTC")));
+        }
+    }
+
+    //------------//
+    //  FAILURES  //
+    //------------//
+
+    #[test]
+    fn parsing_invalid_timestamps_fails() {
+        let values = [
+            "",
+            "yesterday",
+            "2025-13-01",
+            "2025-02-29",
+            "2025-2-18",
+            "2025-02-18T24:00:00Z",
+            "2025-02-18T23:18:17+5",
+            "2025-02-18T23:18:17Y",
+            "18/02/2025",
+        ];
+        for value in values {
+            // GIVEN
+            // WHEN
+            let result = parse_timestamp(value);
+
+            // THEN
+            assert!(result.is_none(), "{value} should've been invalid");
+        }
+    }
+}
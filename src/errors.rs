@@ -1,7 +1,8 @@
 use crate::cli::{
-    CouldntGetDetailsViaEditorError, DeleteBookmarksError, DeleteTagsError, ImportError,
-    ListBookmarksError, ListTagsError, ParsingTempFileContentError, RenameTagError,
-    SaveBookmarkError, SaveBookmarksError, SearchBookmarksError, ShowBookmarkError,
+    BulkTagsError, CouldntGetDetailsViaEditorError, DeleteBookmarksError, DeleteTagsError,
+    ExportError, ImportError, ListBookmarksError, ListTagsError, MergeTagsError,
+    ParsingTempFileContentError, RenameTagError, SaveBookmarkError, SaveBookmarksError,
+    SearchBookmarksError, ShowBookmarkError, SuggestTagsError, TagAliasError,
 };
 use crate::common::{ENV_VAR_BMM_EDITOR, ENV_VAR_EDITOR, IMPORT_FILE_FORMATS};
 use crate::persistence::DBError;
@@ -13,6 +14,9 @@ const IMPORT_EXAMPLE_JSON: &str = include_str!("static/import-example.json");
 const IGNORE_ERRORS_MESSAGE: &str = "Possible workaround: running with -i/--ignore-attribute-errors might fix some attribute errors.
 If a title is too long, it'll will be trimmed, and some invalid tags might be transformed to fit bmm's requirements.";
 
+const SEARCH_QUERY_SUGGESTION: &str = r#"Suggestion: combine terms, quoted phrases, and qualifiers (tag:, title:, uri:, site:, before:, after:), eg.
+    bmm search 'rust tag:cli -tag:old (site:github.com OR title:"command line")'"#;
+
 #[derive(thiserror::Error, Debug)]
 pub enum AppError {
     // data related
@@ -28,6 +32,8 @@ pub enum AppError {
     // bookmarks related
     #[error("couldn't import bookmarks: {0}")]
     CouldntImportBookmarks(#[from] ImportError),
+    #[error("couldn't export bookmarks: {0}")]
+    CouldntExportBookmarks(#[from] ExportError),
     #[error("couldn't list bookmarks: {0}")]
     CouldntListBookmarks(#[from] ListBookmarksError),
     #[error("couldn't search bookmarks: {0}")]
@@ -46,8 +52,16 @@ pub enum AppError {
     CouldntListTags(#[from] ListTagsError),
     #[error("couldn't rename tag: {0}")]
     CouldntRenameTag(#[from] RenameTagError),
+    #[error("couldn't merge tags: {0}")]
+    CouldntMergeTags(#[from] MergeTagsError),
     #[error("couldn't delete tag(s): {0}")]
     CouldntDeleteTag(#[from] DeleteTagsError),
+    #[error("couldn't manage tag aliases: {0}")]
+    CouldntManageTagAliases(#[from] TagAliasError),
+    #[error("couldn't change tags: {0}")]
+    CouldntChangeTags(#[from] BulkTagsError),
+    #[error("couldn't suggest tags: {0}")]
+    CouldntSuggestTags(#[from] SuggestTagsError),
 
     // tui related
     #[error("couldn't run bmm's TUI: {0}")]
@@ -70,15 +84,36 @@ impl AppError {
                 ImportError::FileDoesntExist => None,
                 ImportError::CouldntOpenFile(_) => None,
                 ImportError::CouldntReadFile(_) => None,
+                ImportError::CouldntReadStdin(_) => None,
+                ImportError::FormatNeededForStdin => None,
+                ImportError::FormatCantBeReadFromStdin(_) => None,
                 ImportError::CouldntDeserializeJSONInput(_) => None,
+                ImportError::CouldntDeserializeJSONLine(..) => None,
+                ImportError::CouldntParseDelimitedInput(_) => None,
+                ImportError::DelimitedInputHasNoURIColumn => None,
+                ImportError::CouldntParseTimestamp(..) => None,
+                ImportError::CouldntParseEntryTimestamp(..) => None,
+                ImportError::CouldntParseChromiumBookmarks(_) => None,
+                ImportError::CouldntParsePinboardExport(_) => None,
+                ImportError::CouldntReadFirefoxDatabase(_) => None,
+                ImportError::CouldntReadBukuDatabase(_) => None,
+                ImportError::UnsupportedExportVersion(_) => None,
                 ImportError::CouldntParseHTMLInput(_) => None,
                 ImportError::FileFormatNotSupported(_) => None,
+                ImportError::CouldntDisplayReport(_) => Some(303),
                 ImportError::UnexpectedError(_) => Some(300),
-                ImportError::TooManyBookmarks(_) => None,
                 ImportError::ValidationError { .. } => None,
                 ImportError::SaveError(_) => Some(301),
+                ImportError::CouldntGetTagAliases(_) => Some(304),
+            },
+            AppError::CouldntExportBookmarks(e) => match e {
+                ExportError::CouldntGetBookmarksFromDB(_) => Some(4000),
+                ExportError::CouldntCreateFile(_) => None,
+                ExportError::CouldntWriteOutput(_) => Some(4001),
+                ExportError::CouldntSerializeToJSON(_) => Some(4002),
             },
             AppError::CouldntListBookmarks(e) => match e {
+                ListBookmarksError::InvalidRegex(_) => None,
                 ListBookmarksError::CouldntGetBookmarksFromDB(_) => Some(400),
                 ListBookmarksError::CouldntDisplayResults(_) => Some(401),
             },
@@ -87,6 +122,8 @@ impl AppError {
                 SaveBookmarkError::UriAlreadySaved => None,
                 SaveBookmarkError::BookmarkDetailsAreInvalid(_) => None,
                 SaveBookmarkError::CouldntSaveBookmark(_) => Some(501),
+                SaveBookmarkError::CouldntGetTagAliases(_) => Some(502),
+                SaveBookmarkError::CouldntGetTagSuggestions(_) => Some(503),
                 SaveBookmarkError::CouldntUseTextEditor(se) => match se {
                     CouldntGetDetailsViaEditorError::CreateTempFile(_) => Some(550),
                     CouldntGetDetailsViaEditorError::OpenTempFile(_) => Some(551),
@@ -113,6 +150,7 @@ impl AppError {
                 ListTagsError::CouldntRunTui(e) => Some(e.code()),
             },
             AppError::CouldntDeleteBookmarks(e) => match e {
+                DeleteBookmarksError::InvalidRegex(_) => None,
                 DeleteBookmarksError::CouldntDeleteBookmarksInDB(_) => Some(800),
                 DeleteBookmarksError::CouldntFlushStdout(_) => Some(801),
                 DeleteBookmarksError::CouldntReadUserInput(_) => Some(802),
@@ -122,6 +160,13 @@ impl AppError {
                 RenameTagError::NoSuchTag => None,
                 RenameTagError::CouldntRenameTag(_) => Some(900),
                 RenameTagError::TagIsInvalid => None,
+                RenameTagError::NestedTagIsInvalid(_) => None,
+                RenameTagError::TagIsAnAlias(_) => None,
+                RenameTagError::TargetInsideSource => None,
+                RenameTagError::InvalidRegex(_) => None,
+                RenameTagError::RenamesOverlap(_) => None,
+                RenameTagError::CouldntFlushStdout(_) => Some(901),
+                RenameTagError::CouldntReadUserInput(_) => Some(902),
             },
             AppError::CouldntRunTui(e) => Some(e.code()),
             AppError::CouldntDeleteTag(e) => match e {
@@ -131,18 +176,55 @@ impl AppError {
                 DeleteTagsError::TagsDoNotExist(_) => None,
                 DeleteTagsError::CouldntDeleteTags(_) => Some(1003),
             },
+            AppError::CouldntManageTagAliases(e) => match e {
+                TagAliasError::AliasIsInvalid => None,
+                TagAliasError::TagIsInvalid => None,
+                TagAliasError::AliasAndTagSame => None,
+                TagAliasError::TagIsAnAlias(_) => None,
+                TagAliasError::AliasHasAliases(_) => None,
+                TagAliasError::AliasesDoNotExist(_) => None,
+                TagAliasError::CouldntInteractWithDB(_) => Some(1100),
+                TagAliasError::CouldntDisplayResults(_) => Some(1101),
+            },
+            AppError::CouldntChangeTags(e) => match e {
+                BulkTagsError::TagsAreInvalid(_) => None,
+                BulkTagsError::QueryInvalid(_) => None,
+                BulkTagsError::CouldntInteractWithDB(_) => Some(1200),
+                BulkTagsError::CouldntFlushStdout(_) => Some(1201),
+                BulkTagsError::CouldntReadUserInput(_) => Some(1202),
+                BulkTagsError::UnexpectedError(_) => Some(1203),
+            },
+            AppError::CouldntMergeTags(e) => match e {
+                MergeTagsError::TagIsInvalid => None,
+                MergeTagsError::TagsAreInvalid(_) => None,
+                MergeTagsError::TagIsAnAlias(_) => None,
+                MergeTagsError::NothingToMerge => None,
+                MergeTagsError::TagsDoNotExist(_) => None,
+                MergeTagsError::CouldntMergeTags(_) => Some(1300),
+                MergeTagsError::CouldntFlushStdout(_) => Some(1301),
+                MergeTagsError::CouldntReadUserInput(_) => Some(1302),
+            },
+            AppError::CouldntSuggestTags(e) => match e {
+                SuggestTagsError::CouldntParseUri(_) => None,
+                SuggestTagsError::TagsAreInvalid(_) => None,
+                SuggestTagsError::CouldntInteractWithDB(_) => Some(1400),
+                SuggestTagsError::CouldntDisplayResults(_) => Some(1401),
+            },
             AppError::CouldntSaveBookmarks(e) => match e {
                 SaveBookmarksError::CouldntReadStdin(_) => Some(2001),
-                SaveBookmarksError::TooManyBookmarks(_) => None,
                 SaveBookmarksError::ValidationError { .. } => None,
                 SaveBookmarksError::SaveError(_) => Some(2002),
                 SaveBookmarksError::UnexpectedError(_) => Some(2003),
+                SaveBookmarksError::CouldntDisplayReport(_) => Some(2004),
+                SaveBookmarksError::CouldntGetTagAliases(_) => Some(2005),
             },
             AppError::CouldntSearchBookmarks(e) => match e {
                 SearchBookmarksError::SearchQueryInvalid(_) => None,
+                SearchBookmarksError::FuzzyQueryEmpty => None,
                 SearchBookmarksError::CouldntGetBookmarksFromDB(_) => Some(3000),
                 SearchBookmarksError::CouldntDisplayResults(_) => Some(3001),
                 SearchBookmarksError::CouldntRunTui(e) => Some(e.code()),
+                SearchBookmarksError::UnexpectedError(_) => Some(3002),
             },
         }
     }
@@ -158,12 +240,20 @@ Read more here: https://specifications.freedesktop.org/basedir-spec/latest/#basi
                     Some("Possible workaround: manually specify the path for bmm's database using --db-path".into())
             },
             AppError::CouldntImportBookmarks(e) => match e {
-                ImportError::FileHasNoExtension => Some(format!("bmm can only import from files with one of these extensions: {IMPORT_FILE_FORMATS:?}")),
+                ImportError::FileHasNoExtension => Some(format!("bmm can only infer the format of files with one of these extensions: {IMPORT_FILE_FORMATS:?}; use --format to specify it explicitly")),
+                ImportError::FormatNeededForStdin => Some(r#"Suggestion: specify the format, eg. "curl -s https://example.com/bookmarks.html | bmm import - --format html""#.into()),
                 ImportError::ValidationError { .. } => Some(IGNORE_ERRORS_MESSAGE.into()),
                 ImportError::CouldntDeserializeJSONInput(_) =>
                     Some(format!("Suggestion: ensure the file is valid JSON and looks like the following:
 
 {IMPORT_EXAMPLE_JSON}" )),
+                ImportError::CouldntParseTimestamp(..) | ImportError::CouldntParseEntryTimestamp(..) =>
+                    Some(format!(r#"Suggestion: use unix timestamps, RFC 3339 date-times (eg. "2025-02-18T22:38:40Z"), or dates (eg. "2025-02-18").
+{IGNORE_ERRORS_MESSAGE}"#)),
+                ImportError::CouldntReadFirefoxDatabase(_) =>
+                    Some("Suggestion: ensure the file is a Firefox places.sqlite database (found in your Firefox profile directory)".into()),
+                ImportError::CouldntReadBukuDatabase(_) =>
+                    Some("Suggestion: ensure the file is a buku database (usually found at ~/.local/share/buku/bookmarks.db)".into()),
                 _ => None,
             },
             AppError::CouldntSaveBookmark(e) => match e {
@@ -182,6 +272,9 @@ Check if "{editor_exe}" actually points to your text editor's executable."#)),
                 _ => None,
             },
             AppError::CouldntSaveBookmarks(SaveBookmarksError::ValidationError { .. }) => Some(IGNORE_ERRORS_MESSAGE.into()),
+            AppError::CouldntSearchBookmarks(SearchBookmarksError::SearchQueryInvalid(_))
+            | AppError::CouldntChangeTags(BulkTagsError::QueryInvalid(_)) =>
+                Some(SEARCH_QUERY_SUGGESTION.into()),
             _ => None,
         }
     }
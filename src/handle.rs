@@ -1,8 +1,11 @@
-use crate::args::{Args, BmmCommand, TagsCommand};
+use crate::args::{Args, BmmCommand, Order, TagAliasCommand, TagsCommand};
 use crate::cli::*;
 use crate::domain::PotentialBookmark;
 use crate::errors::AppError;
-use crate::persistence::get_db_pool;
+use crate::persistence::{
+    BookmarkFilter, DateRange, Page, PatternMatch, SortField, SortOrder, TagMatch, UriMatchMode,
+    get_db_pool,
+};
 use crate::tui::{TuiContext, run_tui};
 use crate::utils::get_data_dir;
 use std::fs;
@@ -39,55 +42,148 @@ pub async fn handle(args: Args) -> Result<(), AppError> {
         BmmCommand::Delete {
             uris,
             match_pattern,
+            match_regex,
             skip_confirmation,
         } => {
-            delete_bookmarks(&pool, uris, match_pattern, skip_confirmation).await?;
+            let match_mode = if match_regex {
+                UriMatchMode::Regex
+            } else if match_pattern {
+                UriMatchMode::Pattern
+            } else {
+                UriMatchMode::Exact
+            };
+            delete_bookmarks(&pool, uris, match_mode, skip_confirmation).await?;
         }
 
         BmmCommand::Import {
             file,
+            format,
             reset_missing,
             dry_run,
             ignore_attribute_errors,
+            folders_as_tags,
+            report,
         } => {
-            let result = import_bookmarks(
-                &pool,
-                &file,
+            let options = ImportOptions {
                 reset_missing,
                 dry_run,
                 ignore_attribute_errors,
-            )
-            .await?;
+                folders_as_tags,
+                list_bookmarks: report.is_some(),
+            };
+            let result = import_bookmarks(&pool, &file, format.as_deref(), options).await?;
             if let Some(stats) = result {
-                println!("imported {} bookmarks", stats.num_bookmarks_imported);
+                match report {
+                    Some(format) => display_save_report(&stats.report, &format)
+                        .map_err(ImportError::CouldntDisplayReport)?,
+                    None => println!("imported {} bookmarks", stats.report.num_bookmarks()),
+                }
+            }
+        }
+
+        BmmCommand::Export {
+            uri,
+            title,
+            tags,
+            format,
+            output,
+        } => {
+            let filter = BookmarkFilter::default()
+                .with_uri_pattern(uri)
+                .with_title_pattern(title)
+                .with_tags(tags, TagMatch::All);
+            let result = export_bookmarks(&pool, &filter, format, output).await?;
+            if let Some(stats) = result {
+                if stats.num_bookmarks_exported == 1 {
+                    println!("exported 1 bookmark");
+                } else {
+                    println!("exported {} bookmarks", stats.num_bookmarks_exported);
+                }
             }
         }
 
         BmmCommand::List {
             uri,
             title,
+            regex,
             tags,
+            any_tag,
+            excluded_tags,
+            domains,
+            untagged,
+            since,
+            until,
+            modified,
             format,
             limit,
-        } => list_bookmarks(&pool, uri, title, tags, format, limit).await?,
+            sort,
+            order,
+            offset,
+            page,
+        } => {
+            let filter = BookmarkFilter::default()
+                .with_uri_pattern(uri)
+                .with_title_pattern(title)
+                .with_pattern_match(if regex {
+                    PatternMatch::Regex
+                } else {
+                    PatternMatch::Substring
+                })
+                .with_tags(
+                    tags,
+                    if any_tag {
+                        TagMatch::Any
+                    } else {
+                        TagMatch::All
+                    },
+                )
+                .without_tags(excluded_tags)
+                .with_domains(domains)
+                .untagged_only(untagged)
+                .with_date_range(DateRange::from((since, until, modified)));
+            let page = get_page(SortField::from(sort), order, limit, offset, page);
+            list_bookmarks(&pool, &filter, &page, format).await?
+        }
 
         BmmCommand::Search {
             query_terms,
+            since,
+            until,
+            modified,
             format,
             limit,
+            sort,
+            order,
+            offset,
+            page,
+            snippets,
+            fuzzy,
             tui,
-        } => search_bookmarks(&pool, &query_terms, format, limit, tui).await?,
+        } => {
+            let filter = BookmarkFilter::default()
+                .with_date_range(DateRange::from((since, until, modified)));
+            let sort_field = sort.map(SortField::from).unwrap_or(SortField::Relevance);
+            let page = get_page(sort_field, order, limit, offset, page);
+            let options = SearchOptions {
+                fuzzy,
+                snippets,
+                tui,
+            };
+            search_bookmarks(&pool, &query_terms, &filter, &page, format, options).await?
+        }
 
         BmmCommand::Save {
             uri,
             title,
             tags,
+            notes,
             use_editor,
             fail_if_uri_already_saved,
             reset_missing,
             ignore_attribute_errors,
+            suggest,
         } => {
-            let potential_bookmark = PotentialBookmark::from((uri, title, &tags));
+            let potential_bookmark = PotentialBookmark::from((uri, title, &tags)).with_notes(notes);
 
             save_bookmark(
                 &pool,
@@ -96,6 +192,7 @@ pub async fn handle(args: Args) -> Result<(), AppError> {
                 fail_if_uri_already_saved,
                 reset_missing,
                 ignore_attribute_errors,
+                suggest,
             )
             .await?
         }
@@ -106,6 +203,7 @@ pub async fn handle(args: Args) -> Result<(), AppError> {
             use_stdin,
             reset_missing,
             ignore_attribute_errors,
+            report,
         } => {
             let result = save_all_bookmarks(
                 &pool,
@@ -114,13 +212,15 @@ pub async fn handle(args: Args) -> Result<(), AppError> {
                 use_stdin,
                 reset_missing,
                 ignore_attribute_errors,
+                report.is_some(),
             )
             .await?;
             if let Some(stats) = result {
-                if stats.num_bookmarks == 1 {
-                    println!("saved 1 bookmark");
-                } else {
-                    println!("saved {} bookmarks", stats.num_bookmarks);
+                match report {
+                    Some(format) => display_save_report(&stats.report, &format)
+                        .map_err(SaveBookmarksError::CouldntDisplayReport)?,
+                    None if stats.report.num_bookmarks() == 1 => println!("saved 1 bookmark"),
+                    None => println!("saved {} bookmarks", stats.report.num_bookmarks()),
                 }
             }
         }
@@ -128,22 +228,99 @@ pub async fn handle(args: Args) -> Result<(), AppError> {
         BmmCommand::Show { uri } => show_bookmark(&pool, uri).await?,
 
         BmmCommand::Tags { tags_command } => match tags_command {
+            TagsCommand::Alias { alias_command } => match alias_command {
+                TagAliasCommand::Add { alias, tag } => add_tag_alias(&pool, alias, tag).await?,
+                TagAliasCommand::Remove { aliases } => remove_tag_aliases(&pool, aliases).await?,
+                TagAliasCommand::List { format } => list_tag_aliases(&pool, format).await?,
+                TagAliasCommand::Apply => apply_tag_aliases(&pool).await?,
+            },
             TagsCommand::List {
                 format,
                 show_stats,
+                tree,
                 tui,
-            } => list_tags(&pool, format, show_stats, tui).await?,
+            } => list_tags(&pool, format, show_stats, tree, tui).await?,
+            TagsCommand::Suggest {
+                uri,
+                tags,
+                limit,
+                format,
+            } => suggest_tags(&pool, uri, tags, usize::from(limit), format).await?,
+            TagsCommand::Merge {
+                source_tags,
+                target_tag,
+                dry_run,
+                skip_confirmation,
+            } => merge_tags(&pool, source_tags, target_tag, dry_run, skip_confirmation).await?,
             TagsCommand::Rename {
                 source_tag,
                 target_tag,
-            } => rename_tag(&pool, source_tag, target_tag).await?,
+                subtree,
+                pattern,
+                replacement,
+                dry_run,
+                skip_confirmation,
+            } => match (pattern, replacement) {
+                (Some(pattern), Some(replacement)) => {
+                    rename_tags_matching_pattern(
+                        &pool,
+                        pattern,
+                        replacement,
+                        dry_run,
+                        skip_confirmation,
+                    )
+                    .await?
+                }
+                // clap makes sure both tags are present when there's no pattern
+                _ => {
+                    rename_tag(
+                        &pool,
+                        source_tag.unwrap_or_default(),
+                        target_tag.unwrap_or_default(),
+                        subtree,
+                    )
+                    .await?
+                }
+            },
             TagsCommand::Delete {
                 tags,
                 skip_confirmation,
             } => delete_tags(&pool, tags, skip_confirmation).await?,
+            TagsCommand::Add {
+                tags,
+                query,
+                dry_run,
+                skip_confirmation,
+            } => add_tags(&pool, tags, query, dry_run, skip_confirmation).await?,
+            TagsCommand::Remove {
+                tags,
+                query,
+                dry_run,
+                skip_confirmation,
+            } => remove_tags(&pool, tags, query, dry_run, skip_confirmation).await?,
         },
         BmmCommand::Tui => run_tui(&pool, TuiContext::Initial).await?,
     }
 
     Ok(())
 }
+
+// a page number, when provided, takes the place of an offset
+fn get_page(
+    sort_field: SortField,
+    order: Option<Order>,
+    limit: u16,
+    offset: u32,
+    page_number: Option<u32>,
+) -> Page {
+    Page {
+        sort_field,
+        sort_order: order
+            .map(SortOrder::from)
+            .unwrap_or(sort_field.default_order()),
+        limit,
+        offset: page_number
+            .map(|n| n.saturating_sub(1).saturating_mul(u32::from(limit)))
+            .unwrap_or(offset),
+    }
+}
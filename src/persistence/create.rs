@@ -1,7 +1,8 @@
 use super::errors::DBError;
-use crate::domain::DraftBookmark;
+use crate::domain::{DraftBookmark, SaveOutcome, Tag};
 use sqlx::Row;
-use sqlx::{Pool, Sqlite};
+use sqlx::{Pool, Sqlite, Transaction};
+use std::collections::HashSet;
 
 #[derive(Clone, Copy, Default)]
 pub struct SaveBookmarkOptions {
@@ -23,21 +24,24 @@ pub async fn create_or_update_bookmark(
     {
         let uri = bookmark.uri();
         let title = bookmark.title();
+        let notes = bookmark.notes();
         match options.reset_missing_attributes {
             true => {
                 sqlx::query!(
                     "
 INSERT INTO
-    bookmarks (uri, title, created_at, updated_at)
+    bookmarks (uri, title, notes, created_at, updated_at)
 VALUES
-    (?, ?, ?, ?) ON CONFLICT (uri) DO
+    (?, ?, ?, ?, ?) ON CONFLICT (uri) DO
 UPDATE
 SET
     title = excluded.title,
+    notes = excluded.notes,
     updated_at = excluded.updated_at
 ",
                     uri,
                     title,
+                    notes,
                     now,
                     now,
                 )
@@ -50,16 +54,18 @@ SET
                 sqlx::query!(
                     "
 INSERT INTO
-    bookmarks (uri, title, created_at, updated_at)
+    bookmarks (uri, title, notes, created_at, updated_at)
 VALUES
-    (?, ?, ?, ?) ON CONFLICT (uri) DO
+    (?, ?, ?, ?, ?) ON CONFLICT (uri) DO
 UPDATE
 SET
     title = COALESCE(excluded.title, bookmarks.title),
+    notes = COALESCE(excluded.notes, bookmarks.notes),
     updated_at = excluded.updated_at
 ",
                     uri,
                     title,
+                    notes,
                     now,
                     now,
                 )
@@ -195,33 +201,48 @@ pub async fn create_or_update_bookmarks(
     bookmarks: &Vec<DraftBookmark>,
     now: i64,
     options: SaveBookmarkOptions,
-) -> Result<(), DBError> {
+) -> Result<Vec<SaveOutcome>, DBError> {
     let mut tx = pool
         .begin()
         .await
         .map_err(DBError::CouldntBeginTransaction)?;
 
+    let mut outcomes = Vec::with_capacity(bookmarks.len());
     {
         for bookmark in bookmarks {
+            let outcome = get_save_outcome(&mut tx, bookmark, options).await?;
+            outcomes.push(outcome);
+            // bookmarks that wouldn't change are left as is, so that their
+            // last update time stays the same
+            if outcome == SaveOutcome::Unchanged {
+                continue;
+            }
+
             let uri = bookmark.uri();
             let title = bookmark.title();
+            let notes = bookmark.notes();
+            let created_at = bookmark.created_at().unwrap_or(now);
+            let updated_at = bookmark.updated_at().unwrap_or(now);
             match options.reset_missing_attributes {
                 true => {
                     sqlx::query!(
                         "
 INSERT INTO
-    bookmarks (uri, title, created_at, updated_at)
+    bookmarks (uri, title, notes, created_at, updated_at)
 VALUES
-    (?, ?, ?, ?) ON CONFLICT (uri) DO
+    (?, ?, ?, ?, ?) ON CONFLICT (uri) DO
 UPDATE
 SET
     title = excluded.title,
-    updated_at = excluded.updated_at
+    notes = excluded.notes,
+    created_at = MIN(bookmarks.created_at, excluded.created_at),
+    updated_at = MAX(bookmarks.updated_at, excluded.updated_at)
 ",
                         uri,
                         title,
-                        now,
-                        now,
+                        notes,
+                        created_at,
+                        updated_at,
                     )
                     .execute(&mut *tx)
                     .await
@@ -231,18 +252,21 @@ SET
                     sqlx::query!(
                         "
 INSERT INTO
-    bookmarks (uri, title, created_at, updated_at)
+    bookmarks (uri, title, notes, created_at, updated_at)
 VALUES
-    (?, ?, ?, ?) ON CONFLICT (uri) DO
+    (?, ?, ?, ?, ?) ON CONFLICT (uri) DO
 UPDATE
 SET
     title = COALESCE(excluded.title, bookmarks.title),
-    updated_at = excluded.updated_at
+    notes = COALESCE(excluded.notes, bookmarks.notes),
+    created_at = MIN(bookmarks.created_at, excluded.created_at),
+    updated_at = MAX(bookmarks.updated_at, excluded.updated_at)
 ",
                         uri,
                         title,
-                        now,
-                        now,
+                        notes,
+                        created_at,
+                        updated_at,
                     )
                     .execute(&mut *tx)
                     .await
@@ -373,21 +397,132 @@ WHERE
         .await
         .map_err(DBError::CouldntCommitTransaction)?;
 
+    Ok(outcomes)
+}
+
+async fn get_save_outcome(
+    tx: &mut Transaction<'_, Sqlite>,
+    bookmark: &DraftBookmark,
+    options: SaveBookmarkOptions,
+) -> Result<SaveOutcome, DBError> {
+    let uri = bookmark.uri();
+    let maybe_existing = sqlx::query!(
+        "
+SELECT
+    id,
+    title,
+    notes,
+    created_at,
+    updated_at
+FROM
+    bookmarks
+WHERE
+    uri = ?
+LIMIT 1
+",
+        uri
+    )
+    .fetch_optional(&mut **tx)
+    .await
+    .map_err(|e| DBError::CouldntExecuteQuery("fetch existing bookmark".into(), e))?;
+
+    let Some(existing) = maybe_existing else {
+        return Ok(SaveOutcome::Created);
+    };
+
+    let existing_tags = sqlx::query!(
+        "
+SELECT
+    t.name
+FROM
+    tags t
+    INNER JOIN bookmark_tags bt ON bt.tag_id = t.id
+WHERE
+    bt.bookmark_id = ?
+",
+        existing.id
+    )
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|e| DBError::CouldntExecuteQuery("fetch existing tags".into(), e))?
+    .into_iter()
+    .map(|r| r.name)
+    .collect::<HashSet<_>>();
+
+    let (new_title, new_notes) = match options.reset_missing_attributes {
+        true => (bookmark.title(), bookmark.notes()),
+        false => (
+            bookmark.title().or(existing.title.as_deref()),
+            bookmark.notes().or(existing.notes.as_deref()),
+        ),
+    };
+    let tags = bookmark.tags();
+    let tags_unchanged = match options.reset_tags {
+        true => {
+            tags.len() == existing_tags.len() && tags.iter().all(|t| existing_tags.contains(*t))
+        }
+        false => tags.iter().all(|t| existing_tags.contains(*t)),
+    };
+    let created_at_unchanged = bookmark
+        .created_at()
+        .is_none_or(|c| c >= existing.created_at);
+    // an older update time than the saved one isn't a change, as it's never
+    // written over a newer one
+    let updated_at_unchanged = bookmark
+        .updated_at()
+        .is_none_or(|u| u <= existing.updated_at);
+
+    if new_title == existing.title.as_deref()
+        && new_notes == existing.notes.as_deref()
+        && tags_unchanged
+        && created_at_unchanged
+        && updated_at_unchanged
+    {
+        Ok(SaveOutcome::Unchanged)
+    } else {
+        Ok(SaveOutcome::Updated)
+    }
+}
+
+// an alias that already exists gets pointed to the new tag
+pub async fn create_or_update_tag_alias(
+    pool: &Pool<Sqlite>,
+    alias: &Tag,
+    tag: &Tag,
+) -> Result<(), DBError> {
+    let alias_name = alias.name();
+    let tag_name = tag.name();
+    sqlx::query!(
+        "
+INSERT INTO
+    tag_aliases (alias, tag)
+VALUES
+    (?, ?)
+ON CONFLICT (alias) DO UPDATE
+SET
+    tag = excluded.tag
+",
+        alias_name,
+        tag_name
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| DBError::CouldntExecuteQuery("save tag alias".into(), e))?;
+
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::get::{
-        get_all_bookmarks, get_bookmark_with_exact_uri, get_num_bookmarks, get_tags,
+        BookmarkFilter, get_all_bookmarks, get_bookmark_records, get_bookmark_with_exact_uri,
+        get_num_bookmarks, get_tags,
     };
-    use super::super::test_fixtures::DBPoolFixture;
+    use super::super::test_fixtures::{DBPoolFixture, NOW};
     use super::*;
     use crate::domain::PotentialBookmark;
     use insta::assert_yaml_snapshot;
 
-    use std::time::{SystemTime, UNIX_EPOCH};
-
     #[tokio::test]
     async fn creating_a_bookmark_with_all_attributes_works() {
         // GIVEN
@@ -398,9 +533,7 @@ mod tests {
         let draft_bookmark =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title), &tags)))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         // WHEN
         create_or_update_bookmark(
@@ -421,6 +554,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: "sqlx's github page"
         tags: "rust,sqlite"
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
         "#);
     }
 
@@ -432,9 +568,7 @@ mod tests {
         let uri = "https://github.com/launchbadge/sqlx";
         let draft_bookmark = DraftBookmark::try_from(PotentialBookmark::from((uri, None, &tags)))
             .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         // WHEN
         create_or_update_bookmark(
@@ -455,6 +589,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: ~
         tags: "rust,sqlite"
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
         "#);
     }
 
@@ -467,9 +604,7 @@ mod tests {
         let draft_bookmark =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title), &vec![])))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         // WHEN
         create_or_update_bookmark(
@@ -490,6 +625,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: "sqlx's github page"
         tags: ~
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
         "#);
     }
 
@@ -503,9 +641,7 @@ mod tests {
         let draft_bookmark_old =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title_old), &old_tags)))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let created_at = now - 60 * 60;
 
         create_or_update_bookmark(
@@ -544,6 +680,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: "sqlx's github page"
         tags: "rust,sqlite"
+        notes: ~
+        created_at: 1739917097
+        updated_at: 1739920697
         "#);
     }
 
@@ -557,9 +696,7 @@ mod tests {
         let draft_bookmark_old =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title_old), &old_tags)))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let created_at = now - 60 * 60;
 
         create_or_update_bookmark(
@@ -600,6 +737,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: "sqlx's github page"
         tags: "database,github,rust,sqlite"
+        notes: ~
+        created_at: 1739917097
+        updated_at: 1739920697
         "#);
 
         let tags = get_tags(&fx.pool)
@@ -623,9 +763,7 @@ mod tests {
         let draft_bookmark_old =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title_old), &old_tags)))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let created_at = now - 60 * 60;
 
         create_or_update_bookmark(
@@ -665,6 +803,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: "sqlx's github repository"
         tags: "rust,sqlite"
+        notes: ~
+        created_at: 1739917097
+        updated_at: 1739920697
         "#);
     }
 
@@ -678,9 +819,7 @@ mod tests {
         let draft_bookmark_old =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title_old), &old_tags)))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let created_at = now - 60 * 60;
 
         create_or_update_bookmark(
@@ -719,6 +858,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: "sqlx's github page"
         tags: "database,github,rust"
+        notes: ~
+        created_at: 1739917097
+        updated_at: 1739920697
         "#);
     }
 
@@ -731,9 +873,7 @@ mod tests {
         let draft_bookmark_old =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title_old), &vec![])))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let created_at = now - 60 * 60;
 
         create_or_update_bookmark(
@@ -766,6 +906,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: ~
         tags: ~
+        notes: ~
+        created_at: 1739917097
+        updated_at: 1739920697
         "#);
     }
 
@@ -779,9 +922,7 @@ mod tests {
         let draft_bookmark_old =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title), &old_tags)))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let created_at = now - 60 * 60;
 
         create_or_update_bookmark(
@@ -819,6 +960,9 @@ mod tests {
         uri: "https://github.com/launchbadge/sqlx"
         title: "sqlx's github page"
         tags: ~
+        notes: ~
+        created_at: 1739917097
+        updated_at: 1739920697
         "#);
     }
 
@@ -832,9 +976,7 @@ mod tests {
         let draft_bookmark_old =
             DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title), &old_tags)))
                 .expect("draft bookmark should've been created");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let created_at = now - 60 * 60;
 
         create_or_update_bookmark(
@@ -878,9 +1020,7 @@ mod tests {
             ("https://uri-five.com", None, vec!["tag3", "tag4"]),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         let draft_bookmarks = uris
             .into_iter()
@@ -908,18 +1048,33 @@ mod tests {
         - uri: "https://uri-one.com"
           title: ~
           tags: "tag2,tag5"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-two.com"
           title: ~
           tags: "tag2,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-three.com"
           title: ~
           tags: "tag2,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-four.com"
           title: ~
           tags: "tag1,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-five.com"
           title: ~
           tags: "tag3,tag4"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         "#);
 
         let tags = get_tags(&fx.pool)
@@ -946,9 +1101,7 @@ mod tests {
             ("https://uri-five.com", None, vec!["tag3", "tag4"]),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         let draft_bookmarks_original = uris
             .into_iter()
@@ -1003,21 +1156,39 @@ mod tests {
         - uri: "https://uri-one.com"
           title: title
           tags: "tag2,tag5"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-two.com"
           title: ~
           tags: "tag2,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-three.com"
           title: ~
           tags: "tag2,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-four.com"
           title: ~
           tags: "tag1,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-five.com"
           title: ~
           tags: "tag3,tag4"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-six.com"
           title: ~
           tags: "tag6,tag7"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         "#);
 
         let tags = get_tags(&fx.pool)
@@ -1046,9 +1217,7 @@ mod tests {
             ("https://uri-five.com", None, vec!["tag3", "tag4"]),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         let draft_bookmarks_original = uris
             .into_iter()
@@ -1101,21 +1270,39 @@ mod tests {
         - uri: "https://uri-one.com"
           title: ~
           tags: "tag2,tag5"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-two.com"
           title: ~
           tags: "tag2,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-three.com"
           title: ~
           tags: "tag2,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-four.com"
           title: ~
           tags: "tag1,tag3"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-five.com"
           title: ~
           tags: "tag3,tag4"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-six.com"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         "#);
 
         let tags = get_tags(&fx.pool)
@@ -1142,9 +1329,7 @@ mod tests {
             ("https://uri-five.com", None, vec!["tag3", "tag4"]),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         let draft_bookmarks_original = uris
             .into_iter()
@@ -1197,21 +1382,39 @@ mod tests {
         - uri: "https://uri-one.com"
           title: title
           tags: ~
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-two.com"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-three.com"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-four.com"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-five.com"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://uri-six.com"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         "#);
 
         let tags = get_tags(&fx.pool)
@@ -1219,4 +1422,234 @@ mod tests {
             .expect("tags should've been fetched");
         assert_yaml_snapshot!(tags, @"[]");
     }
+
+    #[tokio::test]
+    async fn creating_multiple_bookmarks_with_timestamps_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let draft_bookmarks = [
+            ("https://uri-one.com", Some(100), Some(200)),
+            ("https://uri-two.com", None, None),
+        ]
+        .into_iter()
+        .map(|(uri, created_at, updated_at)| {
+            DraftBookmark::try_from(
+                PotentialBookmark::from((uri, None, None)).with_timestamps(created_at, updated_at),
+            )
+            .expect("draft bookmarks should've been initialized")
+        })
+        .collect::<Vec<_>>();
+
+        // WHEN
+        create_or_update_bookmarks(
+            &fx.pool,
+            &draft_bookmarks,
+            1000,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmarks should've been created");
+
+        // THEN
+        let records = get_bookmark_records(&fx.pool, &BookmarkFilter::default())
+            .await
+            .expect("bookmark records should've been fetched");
+        assert_yaml_snapshot!(records, @r#"
+        - uri: "https://uri-one.com"
+          title: ~
+          tags: ~
+          notes: ~
+          created_at: 100
+          updated_at: 200
+        - uri: "https://uri-two.com"
+          title: ~
+          tags: ~
+          notes: ~
+          created_at: 1000
+          updated_at: 1000
+        "#);
+    }
+
+    #[tokio::test]
+    async fn updating_multiple_bookmarks_with_timestamps_keeps_the_widest_time_range() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let uri = "https://uri-one.com";
+        let original = DraftBookmark::try_from(PotentialBookmark::from((uri, None, None)))
+            .expect("draft bookmark should've been initialized");
+        create_or_update_bookmarks(
+            &fx.pool,
+            &vec![original],
+            500,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmark should've been created");
+
+        let older = DraftBookmark::try_from(
+            PotentialBookmark::from((uri, None, None)).with_timestamps(Some(100), Some(200)),
+        )
+        .expect("draft bookmark should've been initialized");
+        let newer = DraftBookmark::try_from(
+            PotentialBookmark::from((uri, None, None)).with_timestamps(Some(800), Some(900)),
+        )
+        .expect("draft bookmark should've been initialized");
+
+        let only_updated_earlier = DraftBookmark::try_from(
+            PotentialBookmark::from((uri, None, None)).with_timestamps(None, Some(300)),
+        )
+        .expect("draft bookmark should've been initialized");
+
+        // WHEN
+        create_or_update_bookmarks(&fx.pool, &vec![older], 1000, SaveBookmarkOptions::default())
+            .await
+            .expect("bookmark should've been updated");
+        let outcomes = create_or_update_bookmarks(
+            &fx.pool,
+            &vec![only_updated_earlier],
+            1000,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmark should've been saved");
+        let after_older = get_bookmark_records(&fx.pool, &BookmarkFilter::default())
+            .await
+            .expect("bookmark records should've been fetched");
+        create_or_update_bookmarks(&fx.pool, &vec![newer], 1000, SaveBookmarkOptions::default())
+            .await
+            .expect("bookmark should've been updated");
+        let after_newer = get_bookmark_records(&fx.pool, &BookmarkFilter::default())
+            .await
+            .expect("bookmark records should've been fetched");
+
+        // THEN
+        assert_eq!(outcomes, vec![SaveOutcome::Unchanged]);
+        assert_yaml_snapshot!(after_older, @r#"
+        - uri: "https://uri-one.com"
+          title: ~
+          tags: ~
+          notes: ~
+          created_at: 100
+          updated_at: 500
+        "#);
+        assert_yaml_snapshot!(after_newer, @r#"
+        - uri: "https://uri-one.com"
+          title: ~
+          tags: ~
+          notes: ~
+          created_at: 100
+          updated_at: 900
+        "#);
+    }
+
+    #[tokio::test]
+    async fn saving_multiple_bookmarks_returns_their_outcomes() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let draft_bookmarks = [
+            ("https://uri-one.com", Some("title one"), Some("tag1")),
+            ("https://uri-two.com", None, Some("tag1")),
+        ]
+        .into_iter()
+        .map(|(uri, title, tags)| {
+            DraftBookmark::try_from(PotentialBookmark::from((uri, title, tags)))
+                .expect("draft bookmark should've been initialized")
+        })
+        .collect::<Vec<_>>();
+        create_or_update_bookmarks(
+            &fx.pool,
+            &draft_bookmarks,
+            1000,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmarks should've been created");
+
+        let draft_bookmarks = [
+            ("https://uri-one.com", None, Some("tag1")),
+            ("https://uri-two.com", Some("title two"), Some("tag1")),
+            ("https://uri-three.com", None, None),
+        ]
+        .into_iter()
+        .map(|(uri, title, tags)| {
+            DraftBookmark::try_from(PotentialBookmark::from((uri, title, tags)))
+                .expect("draft bookmark should've been initialized")
+        })
+        .collect::<Vec<_>>();
+
+        // WHEN
+        let outcomes = create_or_update_bookmarks(
+            &fx.pool,
+            &draft_bookmarks,
+            2000,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmarks should've been saved");
+
+        // THEN
+        assert_eq!(
+            outcomes,
+            vec![
+                SaveOutcome::Unchanged,
+                SaveOutcome::Updated,
+                SaveOutcome::Created
+            ]
+        );
+        let records = get_bookmark_records(&fx.pool, &BookmarkFilter::default())
+            .await
+            .expect("bookmark records should've been fetched");
+        assert_yaml_snapshot!(records, @r#"
+        - uri: "https://uri-one.com"
+          title: title one
+          tags: tag1
+          notes: ~
+          created_at: 1000
+          updated_at: 1000
+        - uri: "https://uri-two.com"
+          title: title two
+          tags: tag1
+          notes: ~
+          created_at: 1000
+          updated_at: 2000
+        - uri: "https://uri-three.com"
+          title: ~
+          tags: ~
+          notes: ~
+          created_at: 2000
+          updated_at: 2000
+        "#);
+    }
+
+    #[tokio::test]
+    async fn resetting_missing_details_counts_dropped_tags_as_an_update() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let uri = "https://uri-one.com";
+        let original =
+            DraftBookmark::try_from(PotentialBookmark::from((uri, None, Some("tag1,tag2"))))
+                .expect("draft bookmark should've been initialized");
+        create_or_update_bookmarks(
+            &fx.pool,
+            &vec![original],
+            1000,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmark should've been created");
+        let updated = DraftBookmark::try_from(PotentialBookmark::from((uri, None, Some("tag1"))))
+            .expect("draft bookmark should've been initialized");
+        let save_options = SaveBookmarkOptions {
+            reset_missing_attributes: true,
+            reset_tags: true,
+        };
+
+        // WHEN
+        let outcomes = create_or_update_bookmarks(&fx.pool, &vec![updated], 2000, save_options)
+            .await
+            .expect("bookmark should've been updated");
+
+        // THEN
+        assert_eq!(outcomes, vec![SaveOutcome::Updated]);
+    }
 }
@@ -96,9 +96,36 @@ WHERE
     Ok(result.rows_affected())
 }
 
+pub async fn delete_tag_aliases(pool: &Pool<Sqlite>, aliases: &[String]) -> Result<u64, DBError> {
+    let query = format!(
+        r#"
+DELETE FROM
+    tag_aliases
+WHERE
+    alias IN ({})
+"#,
+        aliases
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<&str>>()
+            .join(", ")
+    );
+    let mut query_builder = sqlx::query(&query);
+    for alias in aliases {
+        query_builder = query_builder.bind(alias);
+    }
+
+    let result = query_builder
+        .execute(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("delete tag aliases".into(), e))?;
+
+    Ok(result.rows_affected())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::test_fixtures::DBPoolFixture;
+    use super::super::test_fixtures::{DBPoolFixture, NOW};
     use super::super::{
         create_or_update_bookmark, create_or_update_bookmarks, get_all_bookmarks,
         get_num_bookmarks, get_tags,
@@ -108,8 +135,6 @@ mod tests {
     use crate::persistence::SaveBookmarkOptions;
     use insta::assert_yaml_snapshot;
 
-    use std::time::{SystemTime, UNIX_EPOCH};
-
     //-------------//
     //  SUCCESSES  //
     //-------------//
@@ -118,9 +143,7 @@ mod tests {
     async fn deleting_uris_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let num_bookmarks = 10;
 
         for i in 1..=num_bookmarks {
@@ -160,9 +183,7 @@ mod tests {
     async fn deleting_uris_works_when_uris_dont_exist() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         let uri = "https://uri.com";
         let draft_bookmark = DraftBookmark::try_from(PotentialBookmark::from((uri, None, &vec![])))
@@ -198,9 +219,7 @@ mod tests {
     async fn deleting_uris_cleans_up_unused_tags() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         let uri = "https://uri.com";
         let draft_bookmark =
@@ -234,11 +253,7 @@ mod tests {
     async fn deleting_more_uris_than_the_batch_size_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
-        let start = SystemTime::now();
-        let since_the_epoch = start
-            .duration_since(UNIX_EPOCH)
-            .expect("system time should've been after the Unix epoch");
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let num_bookmarks_to_delete = DELETE_BATCH_SIZE * 2 + 1;
         let mut uris_to_delete = Vec::with_capacity(num_bookmarks_to_delete);
         let mut draft_bookmarks = Vec::with_capacity(num_bookmarks_to_delete + 2);
@@ -296,9 +311,15 @@ mod tests {
         - uri: "https://retained-one.com"
           title: retained bookmark
           tags: "retained-only,shared"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         - uri: "https://retained-two.com"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
         "#);
 
         let tags_in_db = get_tags(&fx.pool)
@@ -322,9 +343,7 @@ mod tests {
             ("https://uri-five.com", None, vec!["tag3", "tag4"]),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         for (uri, title, tags) in uris {
             let draft_bookmark =
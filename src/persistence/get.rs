@@ -1,83 +1,406 @@
 use super::DBError;
-use crate::domain::{SavedBookmark, TagStats};
+use crate::domain::{
+    BookmarkRecord, RelatedTagCounts, SavedBookmark, SearchCondition, SearchExpr, SearchQuery,
+    TAG_SEPARATOR, TagAlias, TagStats,
+};
+use regex::Regex;
 use sqlx::{Pool, QueryBuilder, Sqlite};
 
-const SEARCH_TERMS_UPPER_LIMIT: usize = 10;
+const SELECT_BOOKMARKS: &str = r#"
+SELECT
+    b.uri,
+    b.title,
+    (
+        SELECT
+            GROUP_CONCAT(
+                t.name,
+                ','
+                ORDER BY
+                    t.name ASC
+            )
+        FROM
+            tags t
+            JOIN bookmark_tags bt ON t.id = bt.tag_id
+        WHERE
+            bt.bookmark_id = b.id
+    ) AS tags,
+    b.notes,
+    b.created_at,
+    b.updated_at
+FROM
+    bookmarks b
+WHERE
+    1 = 1
+"#;
+
+// results are joined with the full-text matches of the query's (non-negated)
+// free-text conditions, which say how relevant each result is, and which part
+// of it matched
+const SEARCH_BOOKMARKS_WITH_MATCHES: &str = r#"
+WITH matches AS MATERIALIZED (
+    SELECT
+        rowid,
+        rank,
+        snippet(bookmarks_fts, -1, '**', '**', '...', 12) AS snippet
+    FROM
+        bookmarks_fts
+    WHERE
+        bookmarks_fts MATCH "#;
+
+// used when a query doesn't have any free-text conditions to rank results by
+const NO_MATCHES: &str = r#"
+WITH matches AS (
+    SELECT
+        NULL AS rowid,
+        NULL AS rank,
+        NULL AS snippet
+    WHERE
+        0
+)"#;
+
+const SEARCH_BOOKMARKS: &str = r#"
+SELECT
+    b.uri,
+    b.title,
+    (
+        SELECT
+            GROUP_CONCAT(
+                t.name,
+                ','
+                ORDER BY
+                    t.name ASC
+            )
+        FROM
+            tags t
+            JOIN bookmark_tags bt ON t.id = bt.tag_id
+        WHERE
+            bt.bookmark_id = b.id
+    ) AS tags,
+    b.notes,
+    b.created_at,
+    b.updated_at,
+    matches.snippet,
+    matches.rank AS relevance
+FROM
+    bookmarks b
+    LEFT JOIN matches ON matches.rowid = b.id
+WHERE
+    "#;
+
+// the (lowercased) host of a bookmark's URI, eg. "github.com" for
+// "https://github.com/dhth/bmm" or "localhost" for "http://localhost:8080"
+const URI_HOST: &str = "lower(substr(replace(replace(replace(substr(b.uri, instr(b.uri, '://') + 3), '?', '/'), '#', '/'), ':', '/') || '/', 1, instr(replace(replace(replace(substr(b.uri, instr(b.uri, '://') + 3), '?', '/'), '#', '/'), ':', '/') || '/', '/') - 1))";
+
+#[derive(Clone, Copy, Debug)]
+pub enum UriMatchMode {
+    Exact,
+    Pattern,
+    Regex,
+}
+
+// how uri and title patterns are matched
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PatternMatch {
+    // patterns are matched literally, anywhere in the value (ignoring case)
+    #[default]
+    Substring,
+    // patterns are regular expressions, as supported by the regex crate
+    Regex,
+}
 
-#[derive(thiserror::Error, Debug)]
-pub enum SearchTermsError {
-    #[error("query is empty")]
-    QueryEmpty,
-    #[error("too many terms (maximum allowed: {SEARCH_TERMS_UPPER_LIMIT})")]
-    TooManyTerms,
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimestampField {
+    #[default]
+    Created,
+    Updated,
 }
 
-#[derive(Debug, Clone)]
-pub struct SearchTerms(Vec<String>);
+// Restricts bookmarks to the ones whose creation (or last update) time falls
+// in [since, until)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DateRange {
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub field: TimestampField,
+}
+
+impl From<(Option<i64>, Option<i64>, bool)> for DateRange {
+    fn from(value: (Option<i64>, Option<i64>, bool)) -> Self {
+        let (since, until, modified) = value;
+
+        Self {
+            since,
+            until,
+            field: if modified {
+                TimestampField::Updated
+            } else {
+                TimestampField::Created
+            },
+        }
+    }
+}
 
-impl SearchTerms {
-    pub fn iter(&self) -> std::slice::Iter<'_, String> {
-        self.0.iter()
+impl DateRange {
+    fn column(&self) -> &'static str {
+        match self.field {
+            TimestampField::Created => "b.created_at",
+            TimestampField::Updated => "b.updated_at",
+        }
     }
 }
 
-impl TryFrom<&str> for SearchTerms {
-    type Error = SearchTermsError;
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortField {
+    Uri,
+    Title,
+    Created,
+    #[default]
+    Updated,
+    TagCount,
+    // only applies to search results
+    Relevance,
+}
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.trim().is_empty() {
-            return Err(SearchTermsError::QueryEmpty);
+impl SortField {
+    // the order results are sorted in when one isn't asked for: most recent
+    // (or most tagged) first, and alphabetical otherwise
+    pub fn default_order(&self) -> SortOrder {
+        match self {
+            SortField::Uri | SortField::Title => SortOrder::Asc,
+            SortField::Created
+            | SortField::Updated
+            | SortField::TagCount
+            | SortField::Relevance => SortOrder::Desc,
         }
+    }
+}
 
-        let mut terms = value
-            .trim()
-            .split(" ")
-            .filter(|t| !t.trim().is_empty())
-            .collect::<Vec<_>>();
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+// Which slice of the matching bookmarks to fetch, and in what order; bookmarks
+// that tie on the sort field are ordered by when they were saved, so that
+// paging through results is deterministic
+#[derive(Clone, Copy, Debug)]
+pub struct Page {
+    pub sort_field: SortField,
+    pub sort_order: SortOrder,
+    pub limit: u16,
+    pub offset: u32,
+}
 
-        terms.sort();
-        terms.dedup();
+impl Page {
+    pub fn new(limit: u16) -> Self {
+        Self {
+            sort_field: SortField::default(),
+            sort_order: SortOrder::default(),
+            limit,
+            offset: 0,
+        }
+    }
 
-        if terms.len() > SEARCH_TERMS_UPPER_LIMIT {
-            return Err(SearchTermsError::TooManyTerms);
+    fn order_by_clause(&self) -> String {
+        let direction = match self.sort_order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        };
+
+        match self.sort_field {
+            SortField::Uri => format!("b.uri {direction}"),
+            // untitled bookmarks go last, whichever the direction
+            SortField::Title => format!("b.title {direction} NULLS LAST, b.id ASC"),
+            SortField::Created => format!("b.created_at {direction}, b.id ASC"),
+            SortField::Updated => format!("b.updated_at {direction}, b.id ASC"),
+            SortField::TagCount => format!(
+                "(SELECT COUNT(*) FROM bookmark_tags WHERE bookmark_id = b.id) {direction}, b.id ASC"
+            ),
+            // lower ranks are better matches, so the most relevant results
+            // come first when sorting in descending order
+            SortField::Relevance => match self.sort_order {
+                SortOrder::Asc => "relevance DESC NULLS FIRST, b.id ASC".into(),
+                SortOrder::Desc => "relevance ASC NULLS LAST, b.id ASC".into(),
+            },
         }
+    }
 
-        Ok(Self(
-            terms.into_iter().map(|t| t.to_string()).collect::<Vec<_>>(),
-        ))
+    fn push_clauses(&self, query_builder: &mut QueryBuilder<'_, Sqlite>) {
+        query_builder
+            .push("\nORDER BY\n    ")
+            .push(self.order_by_clause())
+            .push("\nLIMIT\n    ")
+            .push_bind(self.limit)
+            .push("\nOFFSET\n    ")
+            .push_bind(self.offset);
     }
 }
 
-impl TryFrom<&Vec<String>> for SearchTerms {
-    type Error = SearchTermsError;
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TagMatch {
+    // bookmarks need to have every one of the tags
+    #[default]
+    All,
+    // bookmarks need to have at least one of the tags
+    Any,
+}
+
+// Conditions bookmarks need to meet to be fetched; they're ANDed together and
+// compiled into a single query, eg.
+//
+// BookmarkFilter::default()
+//     .with_tags(vec!["rust".into(), "cli".into()], TagMatch::Any)
+//     .without_tags(vec!["archived".into()])
+//     .with_domains(vec!["github.com".into()])
+#[derive(Clone, Debug, Default)]
+pub struct BookmarkFilter {
+    uri_pattern: Option<String>,
+    title_pattern: Option<String>,
+    pattern_match: PatternMatch,
+    tags: Vec<String>,
+    tag_match: TagMatch,
+    excluded_tags: Vec<String>,
+    domains: Vec<String>,
+    untagged_only: bool,
+    date_range: DateRange,
+}
+
+impl BookmarkFilter {
+    pub fn with_uri_pattern(mut self, pattern: Option<String>) -> Self {
+        self.uri_pattern = pattern;
+        self
+    }
+
+    pub fn with_title_pattern(mut self, pattern: Option<String>) -> Self {
+        self.title_pattern = pattern;
+        self
+    }
+
+    pub fn with_pattern_match(mut self, pattern_match: PatternMatch) -> Self {
+        self.pattern_match = pattern_match;
+        self
+    }
 
-    fn try_from(value: &Vec<String>) -> Result<Self, Self::Error> {
-        if value.is_empty() {
-            return Err(SearchTermsError::QueryEmpty);
+    // sqlite can't report why a regex is invalid, so they're checked up front
+    pub fn check_patterns(&self) -> Result<(), regex::Error> {
+        if self.pattern_match == PatternMatch::Regex {
+            for pattern in self.uri_pattern.iter().chain(self.title_pattern.iter()) {
+                Regex::new(pattern)?;
+            }
         }
 
-        let mut terms = value
+        Ok(())
+    }
+
+    pub fn with_tags(mut self, tags: Vec<String>, tag_match: TagMatch) -> Self {
+        self.tags = tags;
+        self.tag_match = tag_match;
+        self
+    }
+
+    pub fn without_tags(mut self, tags: Vec<String>) -> Self {
+        self.excluded_tags = tags;
+        self
+    }
+
+    // domains match their subdomains as well, eg. "github.com" matches
+    // "gist.github.com"
+    pub fn with_domains(mut self, domains: Vec<String>) -> Self {
+        self.domains = domains
             .iter()
-            .filter(|t| !t.trim().is_empty())
-            .collect::<Vec<_>>();
+            .map(|d| d.trim().to_lowercase())
+            .filter(|d| !d.is_empty())
+            .collect();
+        self
+    }
 
-        terms.sort();
-        terms.dedup();
+    pub fn untagged_only(mut self, untagged_only: bool) -> Self {
+        self.untagged_only = untagged_only;
+        self
+    }
+
+    pub fn with_date_range(mut self, date_range: DateRange) -> Self {
+        self.date_range = date_range;
+        self
+    }
 
-        if terms.len() > SEARCH_TERMS_UPPER_LIMIT {
-            return Err(SearchTermsError::TooManyTerms);
+    // expects the query to end in a WHERE clause that conditions can be ANDed
+    // to
+    fn push_conditions(&self, query_builder: &mut QueryBuilder<'_, Sqlite>) {
+        for (column, pattern) in [
+            ("b.uri", &self.uri_pattern),
+            ("b.title", &self.title_pattern),
+        ] {
+            let Some(pattern) = pattern else {
+                continue;
+            };
+
+            query_builder.push(format!(" AND {column} "));
+            match self.pattern_match {
+                PatternMatch::Substring => query_builder
+                    .push("LIKE ")
+                    .push_bind(literal_like_substring_pattern(pattern))
+                    .push(" ESCAPE '\\'"),
+                PatternMatch::Regex => query_builder.push("REGEXP ").push_bind(pattern.clone()),
+            };
         }
 
-        Ok(Self(
-            terms.into_iter().map(|t| t.to_string()).collect::<Vec<_>>(),
-        ))
-    }
-}
+        if !self.tags.is_empty() {
+            match self.tag_match {
+                TagMatch::All => {
+                    for tag in &self.tags {
+                        query_builder.push(" AND ");
+                        push_has_any_tag(std::slice::from_ref(tag), query_builder);
+                    }
+                }
+                TagMatch::Any => {
+                    query_builder.push(" AND ");
+                    push_has_any_tag(&self.tags, query_builder);
+                }
+            }
+        }
 
-#[derive(Clone, Copy, Debug)]
-pub enum UriMatchMode {
-    Exact,
-    Pattern,
+        if !self.excluded_tags.is_empty() {
+            query_builder.push(" AND NOT ");
+            push_has_any_tag(&self.excluded_tags, query_builder);
+        }
+
+        if !self.domains.is_empty() {
+            query_builder.push(" AND (");
+            let mut domains_builder = query_builder.separated(" OR ");
+            for domain in &self.domains {
+                domains_builder
+                    .push(URI_HOST)
+                    .push_unseparated(" = ")
+                    .push_bind_unseparated(domain.clone())
+                    .push(URI_HOST)
+                    .push_unseparated(" LIKE ")
+                    .push_bind_unseparated(format!("%.{}", escape_like_metacharacters(domain)))
+                    .push_unseparated(" ESCAPE '\\'");
+            }
+            domains_builder.push_unseparated(")");
+        }
+
+        if self.untagged_only {
+            query_builder.push(
+                " AND NOT EXISTS (SELECT 1 FROM bookmark_tags bt WHERE bt.bookmark_id = b.id)",
+            );
+        }
+
+        let column = self.date_range.column();
+        if let Some(since) = self.date_range.since {
+            query_builder
+                .push(format!(" AND {column} >= "))
+                .push_bind(since);
+        }
+        if let Some(until) = self.date_range.until {
+            query_builder
+                .push(format!(" AND {column} < "))
+                .push_bind(until);
+        }
+    }
 }
 
 pub async fn get_matching_bookmark_uris(
@@ -117,6 +440,14 @@ WHERE
                     .push_unseparated(" ESCAPE '\\'");
             }
         }
+        UriMatchMode::Regex => {
+            let mut patterns_builder = query_builder.separated(" OR ");
+            for value in values {
+                patterns_builder
+                    .push("uri REGEXP ")
+                    .push_bind_unseparated(value);
+            }
+        }
     }
 
     query_builder.push(" ORDER BY uri");
@@ -146,7 +477,11 @@ SELECT
             JOIN bookmark_tags bt ON t.id = bt.tag_id
         WHERE
             bt.bookmark_id = b.id
-    ) AS "tags: String"
+    ) AS "tags: String",
+    b.notes,
+    b.created_at,
+    b.updated_at,
+    NULL AS "snippet: String"
 FROM
     bookmarks b
 WHERE
@@ -163,455 +498,129 @@ WHERE
 
 pub async fn get_bookmarks(
     pool: &Pool<Sqlite>,
-    uri_query: Option<String>,
-    title_query: Option<String>,
-    tags_query: Vec<String>,
-    limit: u16,
+    filter: &BookmarkFilter,
+    page: &Page,
 ) -> Result<Vec<SavedBookmark>, DBError> {
-    match (uri_query, title_query, tags_query.as_slice()) {
-        (None, None, []) => sqlx::query_as!(
-            SavedBookmark,
-            r#"
-SELECT
-    uri,
-    title,
-    (
-        SELECT
-            GROUP_CONCAT(
-                t.name,
-                ','
-                ORDER BY
-                    t.name ASC
-            )
-        FROM
-            tags t
-            JOIN bookmark_tags bt ON t.id = bt.tag_id
-        WHERE
-            bt.bookmark_id = b.id
-    ) AS tags
-FROM
-    bookmarks b
-ORDER BY
-    updated_at DESC
-LIMIT
-    ?
-"#,
-            limit
-        )
+    let mut query_builder = QueryBuilder::<Sqlite>::new(SELECT_BOOKMARKS);
+    filter.push_conditions(&mut query_builder);
+    page.push_clauses(&mut query_builder);
+
+    query_builder
+        .build_query_as::<SavedBookmark>()
         .fetch_all(pool)
         .await
-        .map_err(|e| DBError::CouldntExecuteQuery("query bookmarks".into(), e)),
-        (Some(u), None, []) => {
-            let uri_query = literal_like_substring_pattern(&u);
+        .map_err(|e| DBError::CouldntExecuteQuery("query bookmarks".into(), e))
+}
 
-            sqlx::query_as!(
-                SavedBookmark,
-                r#"
-SELECT
-    uri,
-    title,
-    (
-        SELECT
-            GROUP_CONCAT(
-                t.name,
-                ','
-                ORDER BY
-                    t.name ASC
-            )
-        FROM
-            tags t
-            JOIN bookmark_tags bt ON t.id = bt.tag_id
-        WHERE
-            bt.bookmark_id = b.id
-    ) AS tags
-FROM
-    bookmarks b
-WHERE
-    b.uri LIKE ? ESCAPE '\'
-ORDER BY
-    b.updated_at DESC
-LIMIT
-    ?
-"#,
-                uri_query,
-                limit
-            )
-            .fetch_all(pool)
-            .await
-            .map_err(|e| DBError::CouldntExecuteQuery("query bookmarks by uri".into(), e))
-        }
-        (None, Some(d), []) => {
-            let title_query = literal_like_substring_pattern(&d);
+pub async fn get_bookmarks_by_query(
+    pool: &Pool<Sqlite>,
+    search_query: &SearchQuery,
+    filter: &BookmarkFilter,
+    page: &Page,
+) -> Result<Vec<SavedBookmark>, DBError> {
+    let match_expression = search_query
+        .text_conditions()
+        .into_iter()
+        .filter_map(fts_match_expression)
+        .collect::<Vec<_>>()
+        .join(" OR ");
+
+    let mut query_builder = if match_expression.is_empty() {
+        QueryBuilder::<Sqlite>::new(NO_MATCHES)
+    } else {
+        let mut query_builder = QueryBuilder::<Sqlite>::new(SEARCH_BOOKMARKS_WITH_MATCHES);
+        query_builder.push_bind(match_expression).push("\n)");
+        query_builder
+    };
+    query_builder.push(SEARCH_BOOKMARKS);
+    push_search_expr(search_query.expr(), &mut query_builder);
 
-            sqlx::query_as!(
-                SavedBookmark,
-                r#"
-SELECT
-    uri,
-    title,
-    (
-        SELECT
-            GROUP_CONCAT(
-                t.name,
-                ','
-                ORDER BY
-                    t.name ASC
+    filter.push_conditions(&mut query_builder);
+    page.push_clauses(&mut query_builder);
+
+    query_builder
+        .build_query_as::<SavedBookmark>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            DBError::CouldntExecuteQuery(
+                "get bookmarks where any attribute matches query".into(),
+                e,
             )
-        FROM
-            tags t
-            JOIN bookmark_tags bt ON t.id = bt.tag_id
-        WHERE
-            bt.bookmark_id = b.id
-    ) AS tags
-FROM
-    bookmarks b
-WHERE
-    title LIKE ? ESCAPE '\'
+        })
+}
+
+// fetches every bookmark the filter lets through, most recently updated first,
+// so that they can be ranked outside of the database (eg. by a fuzzy matcher)
+pub async fn get_all_bookmarks_matching(
+    pool: &Pool<Sqlite>,
+    filter: &BookmarkFilter,
+) -> Result<Vec<SavedBookmark>, DBError> {
+    let mut query_builder = QueryBuilder::<Sqlite>::new(SELECT_BOOKMARKS);
+    filter.push_conditions(&mut query_builder);
+    query_builder.push(
+        r#"
 ORDER BY
-    updated_at DESC
-LIMIT
-    ?
+    b.updated_at DESC,
+    b.id ASC
 "#,
-                title_query,
-                limit
-            )
-            .fetch_all(pool)
-            .await
-            .map_err(|e| DBError::CouldntExecuteQuery("query bookmarks by title".into(), e))
-        }
-        (None, None, tags) => {
-            let query = format!(
-                r#"
-SELECT
-    b.uri,
-    b.title,
-    (
-        SELECT
-            GROUP_CONCAT(
-                t.name,
-                ','
-                ORDER BY
-                    t.name ASC
-            )
-        FROM
-            tags t
-            JOIN bookmark_tags bt ON t.id = bt.tag_id
-        WHERE
-            bt.bookmark_id = b.id
-    ) AS tags
-FROM
-    bookmarks b
-    JOIN bookmark_tags bt ON b.id = bt.bookmark_id
-    JOIN tags t ON bt.tag_id = t.id
-WHERE
-    t.name IN ({})
-GROUP BY
-    b.id,
-    b.uri,
-    b.title,
-    b.updated_at
-HAVING
-    COUNT(DISTINCT t.name) = ?
+    );
+
+    query_builder
+        .build_query_as::<SavedBookmark>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("fetch all bookmarks matching filter".into(), e))
+}
+
+// fetches every bookmark matching a search query, in alphabetical order of
+// their URIs, so that all of them can be changed at once
+pub async fn get_all_bookmarks_matching_query(
+    pool: &Pool<Sqlite>,
+    search_query: &SearchQuery,
+) -> Result<Vec<SavedBookmark>, DBError> {
+    let mut query_builder = QueryBuilder::<Sqlite>::new(SELECT_BOOKMARKS);
+    query_builder.push(" AND ");
+    push_search_expr(search_query.expr(), &mut query_builder);
+    query_builder.push(
+        r#"
 ORDER BY
-    updated_at DESC
-LIMIT
-    ?
+    b.uri ASC
 "#,
-                tags.iter().map(|_| "?").collect::<Vec<&str>>().join(", ")
-            );
-            let mut query_builder = sqlx::query_as::<_, SavedBookmark>(&query);
-            for tag in tags {
-                query_builder = query_builder.bind(tag);
-            }
+    );
 
-            query_builder = query_builder.bind(tags.len() as i64);
-            query_builder = query_builder.bind(limit);
+    query_builder
+        .build_query_as::<SavedBookmark>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("fetch all bookmarks matching query".into(), e))
+}
 
-            let bookmarks = query_builder
-                .fetch_all(pool)
-                .await
-                .map_err(|e| DBError::CouldntExecuteQuery("query bookmarks by tags".into(), e))?;
+pub async fn get_bookmark_records(
+    pool: &Pool<Sqlite>,
+    filter: &BookmarkFilter,
+) -> Result<Vec<BookmarkRecord>, DBError> {
+    let mut query_builder = QueryBuilder::<Sqlite>::new(SELECT_BOOKMARKS);
+    filter.push_conditions(&mut query_builder);
+    query_builder.push(
+        r#"
+ORDER BY
+    b.created_at ASC,
+    b.id ASC
+"#,
+    );
 
-            Ok(bookmarks)
-        }
-        (Some(u), Some(d), []) => {
-            let uri_query = literal_like_substring_pattern(&u);
-            let title_query = literal_like_substring_pattern(&d);
+    query_builder
+        .build_query_as::<BookmarkRecord>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("fetch bookmark records".into(), e))
+}
 
-            sqlx::query_as!(
-                SavedBookmark,
-                r#"
-SELECT
-    uri,
-    title,
-    (
-        SELECT
-            GROUP_CONCAT(
-                t.name,
-                ','
-                ORDER BY
-                    t.name ASC
-            )
-        FROM
-            tags t
-            JOIN bookmark_tags bt ON t.id = bt.tag_id
-        WHERE
-            bt.bookmark_id = b.id
-    ) AS tags
-FROM
-    bookmarks b
-WHERE
-    uri LIKE ? ESCAPE '\'
-    AND title LIKE ? ESCAPE '\'
-ORDER BY
-    updated_at DESC
-LIMIT
-    ?
-"#,
-                uri_query,
-                title_query,
-                limit
-            )
-            .fetch_all(pool)
-            .await
-            .map_err(|e| DBError::CouldntExecuteQuery("query bookmarks by uri and title".into(), e))
-        }
-        (Some(u), None, tags) => {
-            let query = format!(
-                r#"
-SELECT
-    b.uri,
-    b.title,
-    (
-        SELECT
-            GROUP_CONCAT(
-                t.name,
-                ','
-                ORDER BY
-                    t.name ASC
-            )
-        FROM
-            tags t
-            JOIN bookmark_tags bt ON t.id = bt.tag_id
-        WHERE
-            bt.bookmark_id = b.id
-    ) AS tags
-FROM
-    bookmarks b
-    JOIN bookmark_tags bt ON b.id = bt.bookmark_id
-    JOIN tags t ON bt.tag_id = t.id
-WHERE
-    b.uri LIKE ? ESCAPE '\'
-    AND t.name IN ({})
-GROUP BY
-    b.id,
-    b.uri,
-    b.title,
-    b.updated_at
-HAVING
-    COUNT(DISTINCT t.name) = ?
-ORDER BY
-    b.updated_at DESC
-LIMIT
-    ?
-"#,
-                tags.iter().map(|_| "?").collect::<Vec<&str>>().join(", ")
-            );
-            let mut query_builder = sqlx::query_as::<_, SavedBookmark>(&query);
-            query_builder = query_builder.bind(literal_like_substring_pattern(&u));
-            for tag in tags {
-                query_builder = query_builder.bind(tag);
-            }
-
-            query_builder = query_builder.bind(tags.len() as i64);
-            query_builder = query_builder.bind(limit);
-
-            let bookmarks = query_builder.fetch_all(pool).await.map_err(|e| {
-                DBError::CouldntExecuteQuery("query bookmarks by uri and tags".into(), e)
-            })?;
-
-            Ok(bookmarks)
-        }
-        (None, Some(d), tags) => {
-            let query = format!(
-                r#"
-SELECT
-    b.uri,
-    b.title,
-    (
-        SELECT
-            GROUP_CONCAT(
-                t.name,
-                ','
-                ORDER BY
-                    t.name ASC
-            )
-        FROM
-            tags t
-            JOIN bookmark_tags bt ON t.id = bt.tag_id
-        WHERE
-            bt.bookmark_id = b.id
-    ) AS tags
-FROM
-    bookmarks b
-    JOIN bookmark_tags bt ON b.id = bt.bookmark_id
-    JOIN tags t ON bt.tag_id = t.id
-WHERE
-    b.title LIKE ? ESCAPE '\'
-    AND t.name IN ({})
-GROUP BY
-    b.id,
-    b.uri,
-    b.title,
-    b.updated_at
-HAVING
-    COUNT(DISTINCT t.name) = ?
-ORDER BY
-    b.updated_at DESC
-LIMIT
-    ?
-"#,
-                tags.iter().map(|_| "?").collect::<Vec<&str>>().join(", ")
-            );
-            let mut query_builder = sqlx::query_as::<_, SavedBookmark>(&query);
-            query_builder = query_builder.bind(literal_like_substring_pattern(&d));
-            for tag in tags {
-                query_builder = query_builder.bind(tag);
-            }
-
-            query_builder = query_builder.bind(tags.len() as i64);
-            query_builder = query_builder.bind(limit);
-
-            let bookmarks = query_builder.fetch_all(pool).await.map_err(|e| {
-                DBError::CouldntExecuteQuery("query bookmarks by title and tags".into(), e)
-            })?;
-
-            Ok(bookmarks)
-        }
-        (Some(u), Some(d), tags) => {
-            let query = format!(
-                r#"
-SELECT
-    b.uri,
-    b.title,
-    (
-        SELECT
-            GROUP_CONCAT(
-                t.name,
-                ','
-                ORDER BY
-                    t.name ASC
-            )
-        FROM
-            tags t
-            JOIN bookmark_tags bt ON t.id = bt.tag_id
-        WHERE
-            bt.bookmark_id = b.id
-    ) AS tags
-FROM
-    bookmarks b
-    JOIN bookmark_tags bt ON b.id = bt.bookmark_id
-    JOIN tags t ON bt.tag_id = t.id
-WHERE
-    b.uri LIKE ? ESCAPE '\'
-    AND b.title LIKE ? ESCAPE '\'
-    AND t.name IN ({})
-GROUP BY
-    b.id,
-    b.uri,
-    b.title,
-    b.updated_at
-HAVING
-    COUNT(DISTINCT t.name) = ?
-ORDER BY
-    b.updated_at DESC
-LIMIT
-    ?
-"#,
-                tags.iter().map(|_| "?").collect::<Vec<&str>>().join(", ")
-            );
-            let mut query_builder = sqlx::query_as::<_, SavedBookmark>(&query);
-            query_builder = query_builder.bind(literal_like_substring_pattern(&u));
-            query_builder = query_builder.bind(literal_like_substring_pattern(&d));
-            for tag in tags {
-                query_builder = query_builder.bind(tag);
-            }
-
-            query_builder = query_builder.bind(tags.len() as i64);
-            query_builder = query_builder.bind(limit);
-
-            let bookmarks = query_builder.fetch_all(pool).await.map_err(|e| {
-                DBError::CouldntExecuteQuery("query bookmarks by uri and tags".into(), e)
-            })?;
-
-            Ok(bookmarks)
-        }
-    }
-}
-
-pub async fn get_bookmarks_by_query(
-    pool: &Pool<Sqlite>,
-    search_terms: &SearchTerms,
-    limit: u16,
-) -> Result<Vec<SavedBookmark>, DBError> {
-    let query = format!(
-        r#"
-SELECT
-    b.uri,
-    b.title,
-    GROUP_CONCAT(
-        t.name,
-        ','
-        ORDER BY
-            t.name ASC
-    ) AS tags
-FROM
-    bookmarks b
-    LEFT JOIN bookmark_tags bt ON b.id = bt.bookmark_id
-    LEFT JOIN tags t ON bt.tag_id = t.id
-WHERE
-    {}
-GROUP BY
-    b.id,
-    b.uri,
-    b.title,
-    b.updated_at
-ORDER BY
-    b.updated_at DESC
-LIMIT
-    ?
-"#,
-        search_terms
-            .iter()
-        .map(|_| "(b.uri LIKE ? ESCAPE '\\' OR b.title LIKE ? ESCAPE '\\' OR EXISTS (SELECT 1 FROM tags t JOIN bookmark_tags bt ON t.id = bt.tag_id WHERE bt.bookmark_id = b.id AND t.name LIKE ? ESCAPE '\\'))")
-            .collect::<Vec<&str>>()
-            .join(" AND ")
-    );
-
-    let mut query_builder = sqlx::query_as::<_, SavedBookmark>(&query);
-
-    let search_terms_with_like_markers = search_terms
-        .iter()
-        .map(|t| literal_like_substring_pattern(t))
-        .collect::<Vec<_>>();
-
-    for term in search_terms_with_like_markers.iter() {
-        query_builder = query_builder.bind(term);
-        query_builder = query_builder.bind(term);
-        query_builder = query_builder.bind(term);
-    }
-
-    query_builder = query_builder.bind(limit);
-
-    query_builder.fetch_all(pool).await.map_err(|e| {
-        DBError::CouldntExecuteQuery("get bookmarks where any attribute matches query".into(), e)
-    })
-}
-
-#[allow(unused)]
-pub(super) async fn get_num_bookmarks(pool: &Pool<Sqlite>) -> Result<i64, DBError> {
-    sqlx::query_scalar!(
-        "
+#[allow(unused)]
+pub(super) async fn get_num_bookmarks(pool: &Pool<Sqlite>) -> Result<i64, DBError> {
+    sqlx::query_scalar!(
+        "
 SELECT
     COUNT(*)
 FROM
@@ -685,41 +694,334 @@ ORDER BY name
     Ok(tag_names)
 }
 
+// tags are matched the same way as when filtering bookmarks (see
+// push_has_any_tag); bookmarks with more than one of the tags are counted once
+pub async fn count_bookmarks_with_any_tag(
+    pool: &Pool<Sqlite>,
+    tags: &[String],
+) -> Result<i64, DBError> {
+    if tags.is_empty() {
+        return Ok(0);
+    }
+
+    let mut query_builder = QueryBuilder::<Sqlite>::new(
+        r#"
+SELECT
+    COUNT(*)
+FROM
+    bookmarks b
+WHERE
+    "#,
+    );
+    push_has_any_tag(tags, &mut query_builder);
+
+    query_builder
+        .build_query_scalar::<i64>()
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("count bookmarks with tags".into(), e))
+}
+
+// counts tags on bookmarks from a domain (or its subdomains), leaving out the
+// bookmark with the given uri (ie. the one tags are being suggested for)
+pub async fn get_domain_tag_counts(
+    pool: &Pool<Sqlite>,
+    domain: &str,
+    excluded_uri: &str,
+) -> Result<RelatedTagCounts, DBError> {
+    let site = SearchCondition::Site(domain.to_string());
+
+    let mut count_builder =
+        QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM bookmarks b WHERE b.uri != ");
+    count_builder.push_bind(excluded_uri).push(" AND ");
+    push_search_condition(&site, &mut count_builder);
+
+    let num_bookmarks = count_builder
+        .build_query_scalar::<i64>()
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("count bookmarks from domain".into(), e))?;
+
+    let mut tags_builder = QueryBuilder::<Sqlite>::new(
+        r#"
+SELECT
+    t.name,
+    COUNT(*)
+FROM
+    bookmarks b
+    JOIN bookmark_tags bt ON bt.bookmark_id = b.id
+    JOIN tags t ON bt.tag_id = t.id
+WHERE
+    b.uri != "#,
+    );
+    tags_builder.push_bind(excluded_uri).push(" AND ");
+    push_search_condition(&site, &mut tags_builder);
+    tags_builder.push(" GROUP BY t.id ORDER BY t.name");
+
+    let tags = tags_builder
+        .build_query_as::<(String, i64)>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("count tags on bookmarks from domain".into(), e))?
+        .into_iter()
+        .map(|(name, num_bookmarks)| TagStats {
+            name,
+            num_bookmarks,
+        })
+        .collect();
+
+    Ok(RelatedTagCounts {
+        num_bookmarks,
+        tags,
+    })
+}
+
+// counts the other tags on bookmarks that have any of the given tags, leaving
+// out the bookmark with the given uri (ie. the one tags are being suggested for)
+pub async fn get_co_occurring_tag_counts(
+    pool: &Pool<Sqlite>,
+    tags: &[String],
+    excluded_uri: &str,
+) -> Result<RelatedTagCounts, DBError> {
+    if tags.is_empty() {
+        return Ok(RelatedTagCounts::default());
+    }
+
+    let push_related_bookmark_ids = |query_builder: &mut QueryBuilder<'_, Sqlite>| {
+        query_builder.push(
+            r#"
+SELECT DISTINCT
+    bt.bookmark_id
+FROM
+    bookmark_tags bt
+    JOIN tags t ON bt.tag_id = t.id
+    JOIN bookmarks b ON bt.bookmark_id = b.id
+WHERE
+    b.uri != "#,
+        );
+        query_builder
+            .push_bind(excluded_uri.to_string())
+            .push(" AND t.name IN (");
+        let mut names_builder = query_builder.separated(", ");
+        for tag in tags {
+            names_builder.push_bind(tag.clone());
+        }
+        names_builder.push_unseparated(")");
+    };
+
+    let mut count_builder = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM (");
+    push_related_bookmark_ids(&mut count_builder);
+    count_builder.push(")");
+
+    let num_bookmarks = count_builder
+        .build_query_scalar::<i64>()
+        .fetch_one(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("count bookmarks with tags".into(), e))?;
+
+    let mut tags_builder = QueryBuilder::<Sqlite>::new(
+        r#"
+SELECT
+    t.name,
+    COUNT(*)
+FROM
+    bookmark_tags bt
+    JOIN tags t ON bt.tag_id = t.id
+WHERE
+    bt.bookmark_id IN ("#,
+    );
+    push_related_bookmark_ids(&mut tags_builder);
+    tags_builder.push(") AND t.name NOT IN (");
+    let mut names_builder = tags_builder.separated(", ");
+    for tag in tags {
+        names_builder.push_bind(tag.clone());
+    }
+    names_builder.push_unseparated(")");
+    tags_builder.push(" GROUP BY t.id ORDER BY t.name");
+
+    let co_occurring_tags = tags_builder
+        .build_query_as::<(String, i64)>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("count co-occurring tags".into(), e))?
+        .into_iter()
+        .map(|(name, num_bookmarks)| TagStats {
+            name,
+            num_bookmarks,
+        })
+        .collect();
+
+    Ok(RelatedTagCounts {
+        num_bookmarks,
+        tags: co_occurring_tags,
+    })
+}
+
+pub async fn get_tag_aliases(pool: &Pool<Sqlite>) -> Result<Vec<TagAlias>, DBError> {
+    let aliases = sqlx::query_as!(
+        TagAlias,
+        "
+SELECT
+    alias, tag
+FROM
+    tag_aliases
+ORDER BY
+    alias
+"
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| DBError::CouldntExecuteQuery("fetch tag aliases".into(), e))?;
+
+    Ok(aliases)
+}
+
 #[cfg(test)]
 pub async fn get_all_bookmarks(pool: &Pool<Sqlite>) -> Result<Vec<SavedBookmark>, DBError> {
-    get_bookmarks(pool, None, None, vec![], 1000).await
+    get_bookmarks(pool, &BookmarkFilter::default(), &Page::new(1000)).await
+}
+
+fn push_search_expr(expr: &SearchExpr, query_builder: &mut QueryBuilder<'_, Sqlite>) {
+    match expr {
+        SearchExpr::And(exprs) | SearchExpr::Or(exprs) => {
+            let operator = match expr {
+                SearchExpr::And(_) => " AND ",
+                _ => " OR ",
+            };
+            query_builder.push("(");
+            for (i, expr) in exprs.iter().enumerate() {
+                if i > 0 {
+                    query_builder.push(operator);
+                }
+                push_search_expr(expr, query_builder);
+            }
+            query_builder.push(")");
+        }
+        SearchExpr::Not(expr) => {
+            query_builder.push("NOT ");
+            push_search_expr(expr, query_builder);
+        }
+        SearchExpr::Condition(condition) => push_search_condition(condition, query_builder),
+    }
+}
+
+fn push_search_condition(
+    condition: &SearchCondition,
+    query_builder: &mut QueryBuilder<'_, Sqlite>,
+) {
+    match condition {
+        SearchCondition::Text(_) | SearchCondition::Phrase(_) => {
+            match fts_match_expression(condition) {
+                Some(match_expression) => query_builder
+                    .push("b.id IN (SELECT rowid FROM bookmarks_fts WHERE bookmarks_fts MATCH ")
+                    .push_bind(match_expression)
+                    .push(")"),
+                // nothing can match text without any word characters
+                None => query_builder.push("(0 = 1)"),
+            };
+        }
+        SearchCondition::Tag(tag) => {
+            push_has_any_tag(std::slice::from_ref(tag), query_builder);
+        }
+        SearchCondition::Title(title) => {
+            query_builder
+                .push("COALESCE(b.title LIKE ")
+                .push_bind(literal_like_substring_pattern(title))
+                .push(" ESCAPE '\\', 0)");
+        }
+        SearchCondition::Uri(uri) => {
+            query_builder
+                .push("b.uri LIKE ")
+                .push_bind(literal_like_substring_pattern(uri))
+                .push(" ESCAPE '\\'");
+        }
+        SearchCondition::Site(domain) => {
+            query_builder
+                .push("(")
+                .push(URI_HOST)
+                .push(" = ")
+                .push_bind(domain.clone())
+                .push(" OR ")
+                .push(URI_HOST)
+                .push(" LIKE ")
+                .push_bind(format!("%.{}", escape_like_metacharacters(domain)))
+                .push(" ESCAPE '\\')");
+        }
+        SearchCondition::Before(time) => {
+            query_builder.push("b.created_at < ").push_bind(*time);
+        }
+        SearchCondition::After(time) => {
+            query_builder.push("b.created_at >= ").push_bind(*time);
+        }
+    }
+}
+
+// compiles free-text conditions into FTS5 queries: words need to match the
+// start of a word (eg. git -> "git"*), and phrases need to match as is;
+// returns None for text without any word characters, which can't match
+// anything
+fn fts_match_expression(condition: &SearchCondition) -> Option<String> {
+    let (text, prefix) = match condition {
+        SearchCondition::Text(word) => (word, "*"),
+        SearchCondition::Phrase(phrase) => (phrase, ""),
+        _ => return None,
+    };
+
+    if !text.chars().any(char::is_alphanumeric) {
+        return None;
+    }
+
+    Some(format!("\"{}\"{prefix}", text.replace('"', "\"\"")))
+}
+
+// bookmarks have a tag if they have it, or any of the tags nested under it (eg.
+// "lang" matches "lang/rust"); tag aliases stand for the tags they point to
+fn push_has_any_tag(tags: &[String], query_builder: &mut QueryBuilder<'_, Sqlite>) {
+    query_builder
+        .push("EXISTS (SELECT 1 FROM bookmark_tags bt JOIN tags t ON bt.tag_id = t.id JOIN (");
+    let mut tags_builder = query_builder.separated(" UNION ALL ");
+    for tag in tags {
+        tags_builder
+            .push("SELECT COALESCE((SELECT ta.tag FROM tag_aliases ta WHERE ta.alias = ")
+            .push_bind_unseparated(tag.clone())
+            .push_unseparated("), ")
+            .push_bind_unseparated(tag.clone())
+            .push_unseparated(") AS name");
+    }
+    query_builder.push(format!(
+        ") ft ON t.name = ft.name OR substr(t.name, 1, length(ft.name) + 1) = ft.name || '{TAG_SEPARATOR}' WHERE bt.bookmark_id = b.id)"
+    ));
 }
 
 fn literal_like_substring_pattern(value: &str) -> String {
-    let mut pattern = String::with_capacity(value.len() + 2);
-    pattern.push('%');
+    format!("%{}%", escape_like_metacharacters(value))
+}
+
+fn escape_like_metacharacters(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
     for character in value.chars() {
         if matches!(character, '\\' | '%' | '_') {
-            pattern.push('\\');
+            escaped.push('\\');
         }
-        pattern.push(character);
+        escaped.push(character);
     }
-    pattern.push('%');
-    pattern
+    escaped
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::create_or_update_bookmark;
-    use super::super::test_fixtures::DBPoolFixture;
+    use super::super::test_fixtures::{DBPoolFixture, NOW};
+    use super::super::{create_or_update_bookmark, create_or_update_tag_alias};
     use super::*;
     use crate::{
-        domain::{DraftBookmark, PotentialBookmark},
-        persistence::SaveBookmarkOptions,
+        domain::{DraftBookmark, PotentialBookmark, Tag},
+        persistence::{SaveBookmarkOptions, delete_bookmarks_with_uris, rename_tag_name},
     };
 
     use insta::assert_yaml_snapshot;
-    use std::time::{SystemTime, UNIX_EPOCH};
 
     async fn save_test_bookmarks(pool: &Pool<Sqlite>) {
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
         let uris = [
             (
                 "https://github.com/launchbadge/sqlx",
@@ -839,14 +1141,37 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn getting_matching_bookmark_uris_returns_nothing_when_no_values_match() {
+    async fn getting_matching_bookmark_uris_by_regex_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
-        let values = vec!["does-not-exist".to_string()];
+        let values = vec![
+            r"^https://crates\.io/crates/a".to_string(),
+            "/(clap|serde)$".to_string(),
+        ];
 
         // WHEN
-        let exact_uris = get_matching_bookmark_uris(&fx.pool, &values, UriMatchMode::Exact)
+        let uris = get_matching_bookmark_uris(&fx.pool, &values, UriMatchMode::Regex)
+            .await
+            .expect("matching bookmark uris should've been fetched");
+
+        // THEN
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://crates.io/crates/anyhow"
+        - "https://github.com/clap-rs/clap"
+        - "https://github.com/serde-rs/serde"
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_matching_bookmark_uris_returns_nothing_when_no_values_match() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+        let values = vec!["does-not-exist".to_string()];
+
+        // WHEN
+        let exact_uris = get_matching_bookmark_uris(&fx.pool, &values, UriMatchMode::Exact)
             .await
             .expect("matching bookmark uris should've been fetched");
         let pattern_uris = get_matching_bookmark_uris(&fx.pool, &values, UriMatchMode::Pattern)
@@ -878,320 +1203,882 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn get_bookmark_with_uri_returns_none_if_bookmark_doesnt_exist() {
+    async fn get_bookmark_with_uri_returns_none_if_bookmark_doesnt_exist() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+
+        // WHEN
+        let maybe_bookmark = get_bookmark_with_exact_uri(&fx.pool, "https://blah.com")
+            .await
+            .unwrap();
+
+        // THEN
+        assert!(maybe_bookmark.is_none())
+    }
+
+    #[tokio::test]
+    async fn get_bookmark_with_uri_returns_bookmark_when_present() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let uri = "https://github.com/launchbadge/sqlx";
+        let title = Some("sqlx's github page");
+        let tags = vec!["sql", "crate", "github"];
+        let draft_bookmark = DraftBookmark::try_from(PotentialBookmark::from((uri, title, &tags)))
+            .expect("draft bookmark should be initialized");
+        let now = NOW;
+        create_or_update_bookmark(
+            &fx.pool,
+            &draft_bookmark,
+            now,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmark should be saved in db");
+
+        // WHEN
+        let bookmark = get_bookmark_with_exact_uri(&fx.pool, uri)
+            .await
+            .unwrap()
+            .expect("result should've been a bookmark");
+
+        // THEN
+        assert_yaml_snapshot!(bookmark, @r#"
+        uri: "https://github.com/launchbadge/sqlx"
+        title: "sqlx's github page"
+        tags: "crate,github,sql"
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_by_uri_only_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let uri_query = Some("github.com".into());
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_uri_pattern(uri_query),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://github.com/launchbadge/sqlx"
+          title: "sqlx's github page"
+          tags: "crate,github,sql,sqlite"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
+        - uri: "https://github.com/serde-rs/serde"
+          title: ~
+          tags: "github,serde"
+          notes: ~
+          created_at: 1739920597
+          updated_at: 1739920597
+        - uri: "https://github.com/clap-rs/clap"
+          title: clap repository on github
+          tags: "clap,cli"
+          notes: ~
+          created_at: 1739920497
+          updated_at: 1739920497
+        "#);
+    }
+
+    #[tokio::test]
+    async fn bookmark_filters_treat_like_metacharacters_literally() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        for (uri, title) in [
+            (
+                "https://example.com/under_score",
+                r"100% literal back\slash",
+            ),
+            (
+                "https://example.com/underXscore",
+                "100 percent literal backXslash",
+            ),
+            ("https://example.com/percent%20value", "encoded value"),
+            ("https://example.com/percentZZ20value", "plain value"),
+        ] {
+            let bookmark =
+                DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title), None)))
+                    .expect("draft bookmark should've been initialized");
+            create_or_update_bookmark(&fx.pool, &bookmark, 0, SaveBookmarkOptions::default())
+                .await
+                .expect("bookmark should be saved in db");
+        }
+
+        // WHEN
+        let by_uri = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_uri_pattern(Some("under_score".into())),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+        let by_title = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_title_pattern(Some("100%".into())),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+        let by_escape_character = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_title_pattern(Some(r"back\slash".into())),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+        let search_query = SearchQuery::parse("%20", NOW).expect("search query should be valid");
+        let by_search = get_bookmarks_by_query(
+            &fx.pool,
+            &search_query,
+            &BookmarkFilter::default(),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+
+        // THEN
+        assert_yaml_snapshot!(by_uri, @r#"
+        - uri: "https://example.com/under_score"
+          title: "100% literal back\\slash"
+          tags: ~
+          notes: ~
+          created_at: 0
+          updated_at: 0
+        "#);
+        assert_yaml_snapshot!(by_title, @r#"
+        - uri: "https://example.com/under_score"
+          title: "100% literal back\\slash"
+          tags: ~
+          notes: ~
+          created_at: 0
+          updated_at: 0
+        "#);
+        assert_yaml_snapshot!(by_escape_character, @r#"
+        - uri: "https://example.com/under_score"
+          title: "100% literal back\\slash"
+          tags: ~
+          notes: ~
+          created_at: 0
+          updated_at: 0
+        "#);
+        assert_yaml_snapshot!(by_search, @r#"
+        - uri: "https://example.com/percent%20value"
+          title: encoded value
+          tags: ~
+          notes: ~
+          created_at: 0
+          updated_at: 0
+          snippet: "https://example.com/percent%**20value**"
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_by_title_only_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let title_query = Some("page".into());
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_title_pattern(title_query),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://github.com/launchbadge/sqlx"
+          title: "sqlx's github page"
+          tags: "crate,github,sql,sqlite"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
+        - uri: "https://crates.io/crates/anyhow"
+          title: anyhow on crates.io PaGe
+          tags: "crate,error-handling,github"
+          notes: ~
+          created_at: 1739920397
+          updated_at: 1739920397
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_by_a_tag_only_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let tags_query = vec!["serde".into()];
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_tags(tags_query, TagMatch::All),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://github.com/serde-rs/serde"
+          title: ~
+          tags: "github,serde"
+          notes: ~
+          created_at: 1739920597
+          updated_at: 1739920597
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_by_multiple_tags_only_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let tags_query = vec!["github".into(), "crate".into()];
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_tags(tags_query, TagMatch::All),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://github.com/launchbadge/sqlx"
+          title: "sqlx's github page"
+          tags: "crate,github,sql,sqlite"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
+        - uri: "https://crates.io/crates/anyhow"
+          title: anyhow on crates.io PaGe
+          tags: "crate,error-handling,github"
+          notes: ~
+          created_at: 1739920397
+          updated_at: 1739920397
+        - uri: "https://crates.io/crates/thiserror"
+          title: ~
+          tags: "crate,error-handling,github"
+          notes: ~
+          created_at: 1739920297
+          updated_at: 1739920297
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_by_both_uri_and_tags_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let uri_query = Some("crate".into());
+        let tags_query = vec!["github".into()];
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default()
+                .with_uri_pattern(uri_query)
+                .with_tags(tags_query, TagMatch::All),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://crates.io/crates/anyhow"
+          title: anyhow on crates.io PaGe
+          tags: "crate,error-handling,github"
+          notes: ~
+          created_at: 1739920397
+          updated_at: 1739920397
+        - uri: "https://crates.io/crates/thiserror"
+          title: ~
+          tags: "crate,error-handling,github"
+          notes: ~
+          created_at: 1739920297
+          updated_at: 1739920297
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_by_both_uri_and_title_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let uri_query = Some("github".into());
+        let title_query = Some("repository".into());
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default()
+                .with_uri_pattern(uri_query)
+                .with_title_pattern(title_query),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://github.com/clap-rs/clap"
+          title: clap repository on github
+          tags: "clap,cli"
+          notes: ~
+          created_at: 1739920497
+          updated_at: 1739920497
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_by_both_title_and_tags_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let title_query = Some("crate".into());
+        let tags_query = vec!["error-handling".into()];
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default()
+                .with_title_pattern(title_query)
+                .with_tags(tags_query, TagMatch::All),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://crates.io/crates/anyhow"
+          title: anyhow on crates.io PaGe
+          tags: "crate,error-handling,github"
+          notes: ~
+          created_at: 1739920397
+          updated_at: 1739920397
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_by_all_three_attributes_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let uri_query = Some("github".into());
+        let title_query = Some("page".into());
+        let tags_query = vec!["sql".into(), "crate".into()];
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default()
+                .with_uri_pattern(uri_query)
+                .with_title_pattern(title_query)
+                .with_tags(tags_query, TagMatch::All),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://github.com/launchbadge/sqlx"
+          title: "sqlx's github page"
+          tags: "crate,github,sql,sqlite"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
+        "#);
+    }
+
+    #[tokio::test]
+    async fn limiting_search_results_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let uri_query = Some("github.com".into());
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_uri_pattern(uri_query),
+            &Page::new(2),
+        )
+        .await
+        .unwrap();
+
+        // THEN
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://github.com/launchbadge/sqlx"
+          title: "sqlx's github page"
+          tags: "crate,github,sql,sqlite"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
+        - uri: "https://github.com/serde-rs/serde"
+          title: ~
+          tags: "github,serde"
+          notes: ~
+          created_at: 1739920597
+          updated_at: 1739920597
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmarks_added_in_a_date_range_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+        let date_range = DateRange {
+            since: Some(NOW - 400),
+            until: Some(NOW - 100),
+            field: TimestampField::Created,
+        };
 
         // WHEN
-        let maybe_bookmark = get_bookmark_with_exact_uri(&fx.pool, "https://blah.com")
-            .await
-            .unwrap();
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default()
+                .with_tags(vec!["github".into()], TagMatch::All)
+                .with_date_range(date_range),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
 
         // THEN
-        assert!(maybe_bookmark.is_none())
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://crates.io/crates/anyhow"
+        - "https://crates.io/crates/thiserror"
+        "#);
     }
 
     #[tokio::test]
-    async fn get_bookmark_with_uri_returns_bookmark_when_present() {
+    async fn getting_bookmarks_by_query_modified_in_a_date_range_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
-        let uri = "https://github.com/launchbadge/sqlx";
-        let title = Some("sqlx's github page");
-        let tags = vec!["sql", "crate", "github"];
-        let draft_bookmark = DraftBookmark::try_from(PotentialBookmark::from((uri, title, &tags)))
-            .expect("draft bookmark should be initialized");
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        save_test_bookmarks(&fx.pool).await;
+        let draft_bookmark = DraftBookmark::try_from(PotentialBookmark::from((
+            "https://crates.io/crates/thiserror",
+            None,
+            &vec!["rust"],
+        )))
+        .expect("draft bookmark should've been initialized");
         create_or_update_bookmark(
             &fx.pool,
             &draft_bookmark,
-            now,
+            NOW + 100,
             SaveBookmarkOptions::default(),
         )
         .await
-        .expect("bookmark should be saved in db");
+        .expect("bookmark should've been updated");
+        let search_query = SearchQuery::parse("crate", NOW).expect("search query should be valid");
+        let date_range = DateRange {
+            since: Some(NOW),
+            until: None,
+            field: TimestampField::Updated,
+        };
 
         // WHEN
-        let bookmark = get_bookmark_with_exact_uri(&fx.pool, uri)
-            .await
-            .unwrap()
-            .expect("result should've been a bookmark");
+        let bookmarks = get_bookmarks_by_query(
+            &fx.pool,
+            &search_query,
+            &BookmarkFilter::default().with_date_range(date_range),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmark, @r#"
-        uri: "https://github.com/launchbadge/sqlx"
-        title: "sqlx's github page"
-        tags: "crate,github,sql"
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - uri: "https://crates.io/crates/thiserror"
+          title: ~
+          tags: "crate,error-handling,github,rust"
+          notes: ~
+          created_at: 1739920297
+          updated_at: 1739920797
+          snippet: "https://**crates**.io/**crates**/thiserror"
+        - uri: "https://github.com/launchbadge/sqlx"
+          title: "sqlx's github page"
+          tags: "crate,github,sql,sqlite"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
+          snippet: "**crate** github sql sqlite"
         "#);
     }
 
     #[tokio::test]
-    async fn getting_bookmarks_by_uri_only_works() {
+    async fn getting_bookmarks_by_regex_patterns_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
 
         // WHEN
-        let uri_query = Some("github.com".into());
-        let bookmarks = get_bookmarks(&fx.pool, uri_query, None, Vec::new(), 10)
+        let filter = BookmarkFilter::default()
+            .with_uri_pattern(Some(r"^https://github\.com/[^/]+-rs/".into()))
+            .with_title_pattern(Some("(?i)^clap".into()))
+            .with_pattern_match(PatternMatch::Regex);
+        let bookmarks = get_bookmarks(&fx.pool, &filter, &Page::new(10))
             .await
-            .unwrap();
+            .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://github.com/launchbadge/sqlx"
-          title: "sqlx's github page"
-          tags: "crate,github,sql,sqlite"
-        - uri: "https://github.com/serde-rs/serde"
-          title: ~
-          tags: "github,serde"
-        - uri: "https://github.com/clap-rs/clap"
-          title: clap repository on github
-          tags: "clap,cli"
-        "#);
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"- "https://github.com/clap-rs/clap""#);
+    }
+
+    #[test]
+    fn checking_patterns_fails_for_invalid_regexes() {
+        // GIVEN
+        let substring_filter = BookmarkFilter::default().with_uri_pattern(Some("(".into()));
+        let regex_filter = substring_filter
+            .clone()
+            .with_pattern_match(PatternMatch::Regex);
+
+        // WHEN
+        // THEN
+        assert!(substring_filter.check_patterns().is_ok());
+        assert!(regex_filter.check_patterns().is_err());
     }
 
     #[tokio::test]
-    async fn bookmark_filters_treat_like_metacharacters_literally() {
+    async fn filtering_bookmarks_by_tags_matches_nested_tags() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
-        for (uri, title) in [
-            (
-                "https://example.com/under_score",
-                r"100% literal back\slash",
-            ),
-            (
-                "https://example.com/underXscore",
-                "100 percent literal backXslash",
-            ),
-            ("https://example.com/percent%20value", "encoded value"),
-            ("https://example.com/percentZZ20value", "plain value"),
-        ] {
-            let bookmark =
-                DraftBookmark::try_from(PotentialBookmark::from((uri, Some(title), None)))
+        let uris = [
+            ("https://rust-lang.org", vec!["lang/rust"]),
+            ("https://go.dev", vec!["lang/go", "tools"]),
+            ("https://tokio.rs", vec!["lang/rust/async"]),
+            ("https://example.com", vec!["lang-old"]),
+        ];
+        for (uri, tags) in uris {
+            let draft_bookmark =
+                DraftBookmark::try_from(PotentialBookmark::from((uri, None, &tags)))
                     .expect("draft bookmark should've been initialized");
-            create_or_update_bookmark(&fx.pool, &bookmark, 0, SaveBookmarkOptions::default())
-                .await
-                .expect("bookmark should be saved in db");
+            create_or_update_bookmark(
+                &fx.pool,
+                &draft_bookmark,
+                NOW,
+                SaveBookmarkOptions::default(),
+            )
+            .await
+            .expect("bookmark should've been saved");
         }
+        let uris_for = |bookmarks: Vec<SavedBookmark>| {
+            let mut uris = bookmarks.into_iter().map(|b| b.uri).collect::<Vec<_>>();
+            uris.sort();
+            uris
+        };
 
         // WHEN
-        let by_uri = get_bookmarks(&fx.pool, Some("under_score".into()), None, vec![], 10)
-            .await
-            .expect("bookmarks should've been fetched");
-        let by_title = get_bookmarks(&fx.pool, None, Some("100%".into()), vec![], 10)
-            .await
-            .expect("bookmarks should've been fetched");
-        let by_escape_character =
-            get_bookmarks(&fx.pool, None, Some(r"back\slash".into()), vec![], 10)
-                .await
-                .expect("bookmarks should've been fetched");
-        let search_terms = SearchTerms::try_from("%20").expect("search terms should be valid");
-        let by_search = get_bookmarks_by_query(&fx.pool, &search_terms, 10)
-            .await
-            .expect("bookmarks should've been fetched");
+        let subtree = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_tags(vec!["lang".into()], TagMatch::All),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+        let all = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default()
+                .with_tags(vec!["lang".into(), "tools".into()], TagMatch::All),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+        let excluded = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().without_tags(vec!["lang/rust".into()]),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+        let searched = get_bookmarks_by_query(
+            &fx.pool,
+            &SearchQuery::parse("tag:lang/rust", NOW).expect("query should've been parsed"),
+            &BookmarkFilter::default(),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(by_uri, @r#"
-        - uri: "https://example.com/under_score"
-          title: "100% literal back\\slash"
-          tags: ~
+        assert_yaml_snapshot!(uris_for(subtree), @r#"
+        - "https://go.dev"
+        - "https://rust-lang.org"
+        - "https://tokio.rs"
         "#);
-        assert_yaml_snapshot!(by_title, @r#"
-        - uri: "https://example.com/under_score"
-          title: "100% literal back\\slash"
-          tags: ~
-        "#);
-        assert_yaml_snapshot!(by_escape_character, @r#"
-        - uri: "https://example.com/under_score"
-          title: "100% literal back\\slash"
-          tags: ~
+        assert_yaml_snapshot!(uris_for(all), @r#"- "https://go.dev""#);
+        assert_yaml_snapshot!(uris_for(excluded), @r#"
+        - "https://example.com"
+        - "https://go.dev"
         "#);
-        assert_yaml_snapshot!(by_search, @r#"
-        - uri: "https://example.com/percent%20value"
-          title: encoded value
-          tags: ~
+        assert_yaml_snapshot!(uris_for(searched), @r#"
+        - "https://rust-lang.org"
+        - "https://tokio.rs"
         "#);
     }
 
     #[tokio::test]
-    async fn getting_bookmarks_by_title_only_works() {
+    async fn filtering_bookmarks_by_tags_resolves_tag_aliases() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
+        let alias = Tag::try_from("errors").expect("alias should've been parsed");
+        let tag = Tag::try_from("error-handling").expect("tag should've been parsed");
+        create_or_update_tag_alias(&fx.pool, &alias, &tag)
+            .await
+            .expect("tag alias should've been saved");
 
         // WHEN
-        let title_query = Some("page".into());
-        let bookmarks = get_bookmarks(&fx.pool, None, title_query, Vec::new(), 10)
-            .await
-            .unwrap();
+        let filtered = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_tags(vec!["errors".into()], TagMatch::All),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+        let excluded = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().without_tags(vec!["errors".into(), "cli".into()]),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+        let searched = get_bookmarks_by_query(
+            &fx.pool,
+            &SearchQuery::parse("tag:errors", NOW).expect("query should've been parsed"),
+            &BookmarkFilter::default(),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://github.com/launchbadge/sqlx"
-          title: "sqlx's github page"
-          tags: "crate,github,sql,sqlite"
-        - uri: "https://crates.io/crates/anyhow"
-          title: anyhow on crates.io PaGe
-          tags: "crate,error-handling,github"
+        let uris = |bookmarks: Vec<SavedBookmark>| {
+            bookmarks.into_iter().map(|b| b.uri).collect::<Vec<_>>()
+        };
+        assert_yaml_snapshot!(uris(filtered), @r#"
+        - "https://crates.io/crates/anyhow"
+        - "https://crates.io/crates/thiserror"
+        "#);
+        assert_yaml_snapshot!(uris(excluded), @r#"
+        - "https://github.com/launchbadge/sqlx"
+        - "https://github.com/serde-rs/serde"
+        "#);
+        assert_yaml_snapshot!(uris(searched), @r#"
+        - "https://crates.io/crates/anyhow"
+        - "https://crates.io/crates/thiserror"
         "#);
     }
 
     #[tokio::test]
-    async fn getting_bookmarks_by_a_tag_only_works() {
+    async fn getting_bookmarks_with_any_of_the_tags_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
+        let filter =
+            BookmarkFilter::default().with_tags(vec!["serde".into(), "cli".into()], TagMatch::Any);
 
         // WHEN
-        let tags_query = vec!["serde".into()];
-        let bookmarks = get_bookmarks(&fx.pool, None, None, tags_query, 10)
+        let bookmarks = get_bookmarks(&fx.pool, &filter, &Page::new(10))
             .await
-            .unwrap();
+            .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://github.com/serde-rs/serde"
-          title: ~
-          tags: "github,serde"
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://github.com/serde-rs/serde"
+        - "https://github.com/clap-rs/clap"
         "#);
     }
 
     #[tokio::test]
-    async fn getting_bookmarks_by_multiple_tags_only_works() {
+    async fn getting_bookmarks_without_excluded_tags_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
+        let filter = BookmarkFilter::default().without_tags(vec!["crate".into()]);
 
         // WHEN
-        let tags_query = vec!["github".into(), "crate".into()];
-        let bookmarks = get_bookmarks(&fx.pool, None, None, tags_query, 10)
+        let bookmarks = get_bookmarks(&fx.pool, &filter, &Page::new(10))
             .await
-            .unwrap();
+            .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://github.com/launchbadge/sqlx"
-          title: "sqlx's github page"
-          tags: "crate,github,sql,sqlite"
-        - uri: "https://crates.io/crates/anyhow"
-          title: anyhow on crates.io PaGe
-          tags: "crate,error-handling,github"
-        - uri: "https://crates.io/crates/thiserror"
-          title: ~
-          tags: "crate,error-handling,github"
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://github.com/serde-rs/serde"
+        - "https://github.com/clap-rs/clap"
         "#);
     }
 
     #[tokio::test]
-    async fn getting_bookmarks_by_both_uri_and_tags_works() {
+    async fn getting_bookmarks_by_domain_matches_hosts_and_their_subdomains() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
-        save_test_bookmarks(&fx.pool).await;
+        let uris = [
+            "https://github.com/dhth/bmm",
+            "https://gist.GitHub.com/dhth",
+            "https://notgithub.com/dhth",
+            "https://github.com.example.org",
+            "https://example.org/?ref=https://github.com/",
+            "http://localhost:8080/bookmarks",
+        ];
+        for uri in uris {
+            let draft_bookmark =
+                DraftBookmark::try_from(PotentialBookmark::from((uri, None, None)))
+                    .expect("draft bookmark should've been initialized");
+            create_or_update_bookmark(
+                &fx.pool,
+                &draft_bookmark,
+                NOW,
+                SaveBookmarkOptions::default(),
+            )
+            .await
+            .expect("bookmark should be saved in db");
+        }
+        let filter =
+            BookmarkFilter::default().with_domains(vec!["GitHub.com".into(), "localhost".into()]);
 
         // WHEN
-        let uri_query = Some("crate".into());
-        let tags_query = vec!["github".into()];
-        let bookmarks = get_bookmarks(&fx.pool, uri_query, None, tags_query, 10)
+        let bookmarks = get_bookmarks(&fx.pool, &filter, &Page::new(10))
             .await
-            .unwrap();
+            .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://crates.io/crates/anyhow"
-          title: anyhow on crates.io PaGe
-          tags: "crate,error-handling,github"
-        - uri: "https://crates.io/crates/thiserror"
-          title: ~
-          tags: "crate,error-handling,github"
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://github.com/dhth/bmm"
+        - "https://gist.GitHub.com/dhth"
+        - "http://localhost:8080/bookmarks"
         "#);
     }
 
     #[tokio::test]
-    async fn getting_bookmarks_by_both_uri_and_title_works() {
+    async fn getting_untagged_bookmarks_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
+        let draft_bookmark = DraftBookmark::try_from(PotentialBookmark::from((
+            "https://github.com/dhth/bmm",
+            None,
+            None,
+        )))
+        .expect("draft bookmark should've been initialized");
+        create_or_update_bookmark(
+            &fx.pool,
+            &draft_bookmark,
+            NOW,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmark should be saved in db");
+        let filter = BookmarkFilter::default().untagged_only(true);
 
         // WHEN
-        let uri_query = Some("github".into());
-        let title_query = Some("repository".into());
-        let bookmarks = get_bookmarks(&fx.pool, uri_query, title_query, Vec::new(), 10)
+        let bookmarks = get_bookmarks(&fx.pool, &filter, &Page::new(10))
             .await
-            .unwrap();
+            .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://github.com/clap-rs/clap"
-          title: clap repository on github
-          tags: "clap,cli"
-        "#);
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"- "https://github.com/dhth/bmm""#);
     }
 
     #[tokio::test]
-    async fn getting_bookmarks_by_both_title_and_tags_works() {
+    async fn combining_bookmark_filters_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
+        let filter = BookmarkFilter::default()
+            .with_domains(vec!["github.com".into()])
+            .with_tags(vec!["crate".into(), "cli".into()], TagMatch::Any)
+            .without_tags(vec!["sqlite".into()]);
 
         // WHEN
-        let title_query = Some("crate".into());
-        let tags_query = vec!["error-handling".into()];
-        let bookmarks = get_bookmarks(&fx.pool, None, title_query, tags_query, 10)
+        let bookmarks = get_bookmarks(&fx.pool, &filter, &Page::new(10))
             .await
-            .unwrap();
+            .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://crates.io/crates/anyhow"
-          title: anyhow on crates.io PaGe
-          tags: "crate,error-handling,github"
-        "#);
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"- "https://github.com/clap-rs/clap""#);
     }
 
     #[tokio::test]
-    async fn getting_bookmarks_by_all_three_attributes_works() {
+    async fn paging_through_sorted_bookmarks_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
+        let page = Page {
+            sort_field: SortField::TagCount,
+            sort_order: SortOrder::Desc,
+            limit: 2,
+            offset: 1,
+        };
 
         // WHEN
-        let uri_query = Some("github".into());
-        let title_query = Some("page".into());
-        let tags_query = vec!["sql".into(), "crate".into()];
-        let bookmarks = get_bookmarks(&fx.pool, uri_query, title_query, tags_query, 10)
+        let bookmarks = get_bookmarks(&fx.pool, &BookmarkFilter::default(), &page)
             .await
-            .unwrap();
+            .expect("bookmarks should've been fetched");
 
         // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://github.com/launchbadge/sqlx"
-          title: "sqlx's github page"
-          tags: "crate,github,sql,sqlite"
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://crates.io/crates/anyhow"
+        - "https://crates.io/crates/thiserror"
         "#);
     }
 
     #[tokio::test]
-    async fn limiting_search_results_works() {
+    async fn searching_bookmarks_sorted_by_title_works() {
         // GIVEN
         let fx = DBPoolFixture::new().await;
         save_test_bookmarks(&fx.pool).await;
+        let search_query = SearchQuery::parse("github", NOW).expect("search query should be valid");
+        let page = Page {
+            sort_field: SortField::Title,
+            sort_order: SortOrder::Asc,
+            limit: 10,
+            offset: 0,
+        };
 
         // WHEN
-        let uri_query = Some("github.com".into());
-        let bookmarks = get_bookmarks(&fx.pool, uri_query, None, Vec::new(), 2)
-            .await
-            .unwrap();
+        let bookmarks =
+            get_bookmarks_by_query(&fx.pool, &search_query, &BookmarkFilter::default(), &page)
+                .await
+                .expect("bookmarks should've been fetched");
 
-        // THEN
-        assert_yaml_snapshot!(bookmarks, @r#"
-        - uri: "https://github.com/launchbadge/sqlx"
-          title: "sqlx's github page"
-          tags: "crate,github,sql,sqlite"
-        - uri: "https://github.com/serde-rs/serde"
-          title: ~
-          tags: "github,serde"
+        // THEN
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://crates.io/crates/anyhow"
+        - "https://github.com/clap-rs/clap"
+        - "https://github.com/launchbadge/sqlx"
+        - "https://github.com/serde-rs/serde"
+        - "https://crates.io/crates/thiserror"
         "#);
     }
 
@@ -1200,9 +2087,7 @@ mod tests {
         // GIVEN
         let fx = DBPoolFixture::new().await;
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         create_or_update_bookmark(
             &fx.pool,
@@ -1262,21 +2147,34 @@ mod tests {
 
         // WHEN
         let uri_query = Some("github.com".into());
-        let bookmarks = get_bookmarks(&fx.pool, uri_query, None, Vec::new(), 10)
-            .await
-            .unwrap();
+        let bookmarks = get_bookmarks(
+            &fx.pool,
+            &BookmarkFilter::default().with_uri_pattern(uri_query),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
 
         // THEN
         assert_yaml_snapshot!(bookmarks, @r#"
         - uri: "https://github.com/clap-rs/clap"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739914697
+          updated_at: 1739914697
         - uri: "https://github.com/serde-rs/serde"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739911697
+          updated_at: 1739911697
         - uri: "https://github.com/launchbadge/sqlx"
           title: ~
           tags: ~
+          notes: ~
+          created_at: 1739908697
+          updated_at: 1739908697
         "#);
     }
 
@@ -1308,9 +2206,7 @@ mod tests {
             ),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         for (uri, title, tags) in uris {
             let draft_bookmark =
@@ -1326,30 +2222,37 @@ mod tests {
             .expect("bookmark should be saved in db");
         }
 
-        let test_cases: Vec<(SearchTerms, usize)> = vec![
-            (SearchTerms::try_from("absent").unwrap(), 0),   // none
-            (SearchTerms::try_from("uri").unwrap(), 2),      // uri only
-            (SearchTerms::try_from("title").unwrap(), 2),    // title only
-            (SearchTerms::try_from("prefix2").unwrap(), 2),  // tags only
-            (SearchTerms::try_from("keyword1").unwrap(), 2), // uri + title
-            (SearchTerms::try_from("keyword2").unwrap(), 2), // title + tags
-            (SearchTerms::try_from("keyword3").unwrap(), 2), // uri + tags
-            (SearchTerms::try_from("keyword4").unwrap(), 3), // uri + title + tags
+        let test_cases: Vec<(SearchQuery, usize)> = vec![
+            (SearchQuery::parse("absent", NOW).unwrap(), 0), // none
+            (SearchQuery::parse("uri", NOW).unwrap(), 2),    // uri only
+            (SearchQuery::parse("title", NOW).unwrap(), 2),  // title only
+            (SearchQuery::parse("prefix2", NOW).unwrap(), 2), // tags only
+            (SearchQuery::parse("keyword1", NOW).unwrap(), 2), // uri + title
+            (SearchQuery::parse("keyword2", NOW).unwrap(), 2), // title + tags
+            (SearchQuery::parse("keyword3", NOW).unwrap(), 2), // uri + tags
+            (SearchQuery::parse("keyword4", NOW).unwrap(), 3), // uri + title + tags
             (
-                SearchTerms::try_from("https keyword one prefix2-tag tag-suffix1").unwrap(),
+                SearchQuery::parse("https keyword one prefix2-tag tag-suffix1", NOW).unwrap(),
                 1,
             ), // multiple terms
-            (SearchTerms::try_from("uri prefix2 keyword3").unwrap(), 2), // multiple terms
-            (SearchTerms::try_from("three keyword").unwrap(), 1), // multiple terms
+            (SearchQuery::parse("uri prefix2 keyword3", NOW).unwrap(), 2), // multiple terms
+            (SearchQuery::parse("three keyword", NOW).unwrap(), 1), // multiple terms
             (
-                SearchTerms::try_from("title prefix2 uri one tag-suffix1").unwrap(),
+                SearchQuery::parse("title prefix2 uri one tag-suffix1", NOW).unwrap(),
                 1,
             ), // multiple terms
         ];
 
         // WHEN
         for (query, expected_num_bookmarks) in test_cases {
-            let bookmarks = get_bookmarks_by_query(&fx.pool, &query, 10).await.unwrap();
+            let bookmarks = get_bookmarks_by_query(
+                &fx.pool,
+                &query,
+                &BookmarkFilter::default(),
+                &Page::new(10),
+            )
+            .await
+            .unwrap();
 
             // THEN
             assert_eq!(
@@ -1368,22 +2271,282 @@ mod tests {
         save_test_bookmarks(&fx.pool).await;
 
         // WHEN
-        let search_terms = SearchTerms::try_from("crate page").unwrap();
-        let bookmarks = get_bookmarks_by_query(&fx.pool, &search_terms, 10)
-            .await
-            .unwrap();
+        let search_query = SearchQuery::parse("crate page", NOW).unwrap();
+        let bookmarks = get_bookmarks_by_query(
+            &fx.pool,
+            &search_query,
+            &BookmarkFilter::default(),
+            &Page::new(10),
+        )
+        .await
+        .unwrap();
 
         // THEN
         assert_yaml_snapshot!(bookmarks, @r#"
         - uri: "https://github.com/launchbadge/sqlx"
           title: "sqlx's github page"
           tags: "crate,github,sql,sqlite"
+          notes: ~
+          created_at: 1739920697
+          updated_at: 1739920697
+          snippet: "sqlx's github **page**"
         - uri: "https://crates.io/crates/anyhow"
           title: anyhow on crates.io PaGe
           tags: "crate,error-handling,github"
+          notes: ~
+          created_at: 1739920397
+          updated_at: 1739920397
+          snippet: anyhow on **crates**.io **PaGe**
+        "#);
+    }
+
+    #[tokio::test]
+    async fn searching_bookmarks_matches_the_start_of_words() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let mut results = vec![];
+        for query in ["sq", "error-hand", "hub"] {
+            let search_query =
+                SearchQuery::parse(query, NOW).expect("search query should be valid");
+            let bookmarks = get_bookmarks_by_query(
+                &fx.pool,
+                &search_query,
+                &BookmarkFilter::default(),
+                &Page::new(10),
+            )
+            .await
+            .expect("bookmarks should've been fetched");
+            results.push((
+                query,
+                bookmarks.into_iter().map(|b| b.uri).collect::<Vec<_>>(),
+            ));
+        }
+
+        // THEN
+        assert_yaml_snapshot!(results, @r#"
+        - - sq
+          - - "https://github.com/launchbadge/sqlx"
+        - - error-hand
+          - - "https://crates.io/crates/anyhow"
+            - "https://crates.io/crates/thiserror"
+        - - hub
+          - []
+        "#);
+    }
+
+    #[tokio::test]
+    async fn searching_bookmarks_with_structured_queries_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let mut results = vec![];
+        for query in [
+            r#"(tag:cli OR title:"github page") -site:crates.io"#,
+            "-tag:github",
+            "-title:github",
+            "site:crates.io -(thiserror OR tag:sql)",
+            "before:2025-02-18T23:13:00Z github",
+        ] {
+            let search_query =
+                SearchQuery::parse(query, NOW).expect("search query should be valid");
+            let bookmarks = get_bookmarks_by_query(
+                &fx.pool,
+                &search_query,
+                &BookmarkFilter::default(),
+                &Page::new(10),
+            )
+            .await
+            .expect("bookmarks should've been fetched");
+            results.push((
+                query,
+                bookmarks.into_iter().map(|b| b.uri).collect::<Vec<_>>(),
+            ));
+        }
+
+        // THEN
+        assert_yaml_snapshot!(results, @r#"
+        - - "(tag:cli OR title:\"github page\") -site:crates.io"
+          - - "https://github.com/launchbadge/sqlx"
+            - "https://github.com/clap-rs/clap"
+        - - "-tag:github"
+          - - "https://github.com/clap-rs/clap"
+        - - "-title:github"
+          - - "https://github.com/serde-rs/serde"
+            - "https://crates.io/crates/anyhow"
+            - "https://crates.io/crates/thiserror"
+        - - "site:crates.io -(thiserror OR tag:sql)"
+          - - "https://crates.io/crates/anyhow"
+        - - "before:2025-02-18T23:13:00Z github"
+          - - "https://crates.io/crates/thiserror"
+        "#);
+    }
+
+    #[tokio::test]
+    async fn searching_bookmarks_ranks_results_by_relevance() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        for (uri, title, tags, notes, now) in [
+            (
+                "https://example.com/one",
+                Some("a post about a database"),
+                vec!["blog"],
+                Some("worth a read; shows rust code"),
+                NOW,
+            ),
+            (
+                "https://example.com/two",
+                Some("a post about a database"),
+                vec!["blog", "rust"],
+                None,
+                NOW - 100,
+            ),
+            (
+                "https://example.com/three",
+                Some("a post about rust"),
+                vec!["blog"],
+                None,
+                NOW - 200,
+            ),
+        ] {
+            let draft_bookmark = DraftBookmark::try_from(
+                PotentialBookmark::from((uri, title, &tags)).with_notes(notes.map(String::from)),
+            )
+            .expect("draft bookmark should've been initialized");
+            create_or_update_bookmark(
+                &fx.pool,
+                &draft_bookmark,
+                now,
+                SaveBookmarkOptions::default(),
+            )
+            .await
+            .expect("bookmark should be saved in db");
+        }
+        let search_query = SearchQuery::parse("rust", NOW).expect("search query should be valid");
+        let page = Page {
+            sort_field: SortField::Relevance,
+            sort_order: SortOrder::Desc,
+            limit: 10,
+            offset: 0,
+        };
+
+        // WHEN
+        let bookmarks =
+            get_bookmarks_by_query(&fx.pool, &search_query, &BookmarkFilter::default(), &page)
+                .await
+                .expect("bookmarks should've been fetched");
+
+        // THEN
+        let results = bookmarks
+            .iter()
+            .map(|b| (b.uri.as_str(), b.snippet.as_deref()))
+            .collect::<Vec<_>>();
+        assert_yaml_snapshot!(results, @r#"
+        - - "https://example.com/three"
+          - a post about **rust**
+        - - "https://example.com/two"
+          - blog **rust**
+        - - "https://example.com/one"
+          - worth a read; shows **rust** code
+        "#);
+    }
+
+    #[tokio::test]
+    async fn search_index_is_kept_in_sync_with_bookmarks_and_tags() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+        let draft_bookmark = DraftBookmark::try_from(PotentialBookmark::from((
+            "https://github.com/launchbadge/sqlx",
+            Some("the rust sql toolkit"),
+            &vec!["sql", "crate"],
+        )))
+        .expect("draft bookmark should've been initialized");
+        create_or_update_bookmark(
+            &fx.pool,
+            &draft_bookmark,
+            NOW,
+            SaveBookmarkOptions {
+                reset_missing_attributes: true,
+                reset_tags: true,
+            },
+        )
+        .await
+        .expect("bookmark should be saved in db");
+        rename_tag_name(
+            &fx.pool,
+            "crate".into(),
+            Tag::try_from("library").expect("tag should've been created"),
+        )
+        .await
+        .expect("tag should've been renamed");
+        delete_bookmarks_with_uris(&fx.pool, &["https://crates.io/crates/anyhow".into()])
+            .await
+            .expect("bookmark should've been deleted");
+
+        // WHEN
+        let mut results = vec![];
+        for query in ["toolkit", "page", "sqlite", "crate", "library", "anyhow"] {
+            let search_query =
+                SearchQuery::parse(query, NOW).expect("search query should be valid");
+            let bookmarks = get_bookmarks_by_query(
+                &fx.pool,
+                &search_query,
+                &BookmarkFilter::default(),
+                &Page::new(10),
+            )
+            .await
+            .expect("bookmarks should've been fetched");
+            results.push((
+                query,
+                bookmarks.into_iter().map(|b| b.uri).collect::<Vec<_>>(),
+            ));
+        }
+
+        // THEN
+        assert_yaml_snapshot!(results, @r#"
+        - - toolkit
+          - - "https://github.com/launchbadge/sqlx"
+        - - page
+          - []
+        - - sqlite
+          - []
+        - - crate
+          - - "https://crates.io/crates/thiserror"
+        - - library
+          - - "https://github.com/launchbadge/sqlx"
+            - "https://crates.io/crates/thiserror"
+        - - anyhow
+          - []
         "#);
     }
 
+    #[tokio::test]
+    async fn searching_bookmarks_with_terms_without_word_characters_returns_nothing() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+        let search_query =
+            SearchQuery::parse("... ***", NOW).expect("search query should be valid");
+
+        // WHEN
+        let bookmarks = get_bookmarks_by_query(
+            &fx.pool,
+            &search_query,
+            &BookmarkFilter::default(),
+            &Page::new(10),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+
+        // THEN
+        assert!(bookmarks.is_empty());
+    }
+
     #[tokio::test]
     async fn getting_tags_works() {
         // GIVEN
@@ -1396,9 +2559,7 @@ mod tests {
             ("https://uri-five.com", None, vec!["tag3", "tag4"]),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         for (uri, title, tags) in uris {
             let draft_bookmark =
@@ -1441,9 +2602,7 @@ mod tests {
             ("https://uri-five.com", None, vec!["tag3", "tag4"]),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         for (uri, title, tags) in uris {
             let draft_bookmark =
@@ -1479,6 +2638,231 @@ mod tests {
         ");
     }
 
+    #[tokio::test]
+    async fn counting_bookmarks_with_any_of_the_tags_counts_each_bookmark_once() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let with_shared_tags =
+            count_bookmarks_with_any_tag(&fx.pool, &["github".into(), "crate".into()])
+                .await
+                .expect("bookmarks should've been counted");
+        let with_absent_tag = count_bookmarks_with_any_tag(&fx.pool, &["absent".into()])
+            .await
+            .expect("bookmarks should've been counted");
+        let with_no_tags = count_bookmarks_with_any_tag(&fx.pool, &[])
+            .await
+            .expect("bookmarks should've been counted");
+
+        // THEN
+        assert_eq!(with_shared_tags, 4);
+        assert_eq!(with_absent_tag, 0);
+        assert_eq!(with_no_tags, 0);
+    }
+
+    #[tokio::test]
+    async fn counting_bookmarks_with_any_of_the_tags_matches_nested_tags_and_aliases() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let uris = [
+            ("https://rust-lang.org", vec!["lang/rust"]),
+            ("https://go.dev", vec!["lang/go", "tools"]),
+            ("https://example.com", vec!["lang-old"]),
+        ];
+        for (uri, tags) in uris {
+            let draft_bookmark =
+                DraftBookmark::try_from(PotentialBookmark::from((uri, None, &tags)))
+                    .expect("draft bookmark should've been initialized");
+            create_or_update_bookmark(
+                &fx.pool,
+                &draft_bookmark,
+                NOW,
+                SaveBookmarkOptions::default(),
+            )
+            .await
+            .expect("bookmark should've been saved");
+        }
+        let alias = Tag::try_from("languages").expect("alias should've been parsed");
+        let tag = Tag::try_from("lang").expect("tag should've been parsed");
+        create_or_update_tag_alias(&fx.pool, &alias, &tag)
+            .await
+            .expect("tag alias should've been saved");
+
+        // WHEN
+        let with_nested_tags = count_bookmarks_with_any_tag(&fx.pool, &["lang".into()])
+            .await
+            .expect("bookmarks should've been counted");
+        let with_alias = count_bookmarks_with_any_tag(&fx.pool, &["languages".into()])
+            .await
+            .expect("bookmarks should've been counted");
+
+        // THEN
+        assert_eq!(with_nested_tags, 2);
+        assert_eq!(with_alias, 2);
+    }
+
+    #[tokio::test]
+    async fn getting_domain_tag_counts_leaves_out_the_excluded_uri() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let counts =
+            get_domain_tag_counts(&fx.pool, "github.com", "https://github.com/clap-rs/clap")
+                .await
+                .expect("tag counts should've been fetched");
+
+        // THEN
+        assert_eq!(counts.num_bookmarks, 2);
+        assert_yaml_snapshot!(counts.tags, @"
+        - name: crate
+          num_bookmarks: 1
+        - name: github
+          num_bookmarks: 2
+        - name: serde
+          num_bookmarks: 1
+        - name: sql
+          num_bookmarks: 1
+        - name: sqlite
+          num_bookmarks: 1
+        ");
+    }
+
+    #[tokio::test]
+    async fn getting_co_occurring_tag_counts_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let counts = get_co_occurring_tag_counts(
+            &fx.pool,
+            &["crate".into(), "error-handling".into()],
+            "https://crates.io/crates/anyhow",
+        )
+        .await
+        .expect("tag counts should've been fetched");
+
+        // THEN
+        assert_eq!(counts.num_bookmarks, 2);
+        assert_yaml_snapshot!(counts.tags, @"
+        - name: github
+          num_bookmarks: 2
+        - name: sql
+          num_bookmarks: 1
+        - name: sqlite
+          num_bookmarks: 1
+        ");
+    }
+
+    #[tokio::test]
+    async fn getting_bookmark_records_returns_timestamps_in_order_of_creation() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let uris = [
+            (
+                "https://uri-one.com",
+                Some("uri one"),
+                vec!["tag1", "tag2"],
+                300,
+            ),
+            ("https://uri-two.com", None, vec!["tag2"], 100),
+            ("https://uri-three.com", None, vec![], 200),
+        ];
+
+        for (uri, title, tags, now) in uris {
+            let draft_bookmark =
+                DraftBookmark::try_from(PotentialBookmark::from((uri, title, &tags)))
+                    .expect("draft bookmark should be initialized");
+            create_or_update_bookmark(
+                &fx.pool,
+                &draft_bookmark,
+                now,
+                SaveBookmarkOptions::default(),
+            )
+            .await
+            .expect("bookmark should be saved in db");
+        }
+
+        // WHEN
+        let records = get_bookmark_records(&fx.pool, &BookmarkFilter::default())
+            .await
+            .expect("bookmark records should've been fetched");
+
+        // THEN
+        assert_yaml_snapshot!(records, @r#"
+        - uri: "https://uri-two.com"
+          title: ~
+          tags: tag2
+          notes: ~
+          created_at: 100
+          updated_at: 100
+        - uri: "https://uri-three.com"
+          title: ~
+          tags: ~
+          notes: ~
+          created_at: 200
+          updated_at: 200
+        - uri: "https://uri-one.com"
+          title: uri one
+          tags: "tag1,tag2"
+          notes: ~
+          created_at: 300
+          updated_at: 300
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_bookmark_records_with_filters_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let records = get_bookmark_records(
+            &fx.pool,
+            &BookmarkFilter::default()
+                .with_uri_pattern(Some("crates.io".into()))
+                .with_title_pattern(Some("page".into()))
+                .with_tags(vec!["crate".into(), "github".into()], TagMatch::All),
+        )
+        .await
+        .expect("bookmark records should've been fetched");
+
+        // THEN
+        let uris = records.iter().map(|r| r.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://crates.io/crates/anyhow"
+        "#);
+    }
+
+    #[tokio::test]
+    async fn getting_all_bookmarks_matching_a_filter_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        save_test_bookmarks(&fx.pool).await;
+
+        // WHEN
+        let bookmarks = get_all_bookmarks_matching(
+            &fx.pool,
+            &BookmarkFilter::default().with_tags(vec!["github".into()], TagMatch::All),
+        )
+        .await
+        .expect("bookmarks should've been fetched");
+
+        // THEN
+        let uris = bookmarks.iter().map(|b| b.uri.as_str()).collect::<Vec<_>>();
+        assert_yaml_snapshot!(uris, @r#"
+        - "https://github.com/launchbadge/sqlx"
+        - "https://github.com/serde-rs/serde"
+        - "https://crates.io/crates/anyhow"
+        - "https://crates.io/crates/thiserror"
+        "#);
+    }
+
     #[test]
     fn literal_like_substring_pattern_escapes_like_metacharacters() {
         assert_eq!(
@@ -1,5 +1,7 @@
 use super::DBError;
+use sqlx::sqlite::SqliteConnectOptions;
 use sqlx::{Pool, Sqlite, SqlitePool, migrate::MigrateDatabase};
+use std::str::FromStr;
 
 pub async fn get_db_pool(uri: &str) -> Result<Pool<Sqlite>, DBError> {
     let db_exists = Sqlite::database_exists(uri)
@@ -12,7 +14,11 @@ pub async fn get_db_pool(uri: &str) -> Result<Pool<Sqlite>, DBError> {
             .map_err(DBError::CouldntCreateDatabase)?;
     }
 
-    let db = SqlitePool::connect(uri)
+    // REGEXP is used for regex based filters
+    let options = SqliteConnectOptions::from_str(uri)
+        .map_err(DBError::CouldntConnectToDB)?
+        .with_regexp();
+    let db = SqlitePool::connect_with(options)
         .await
         .map_err(DBError::CouldntConnectToDB)?;
 
@@ -26,7 +32,10 @@ pub async fn get_db_pool(uri: &str) -> Result<Pool<Sqlite>, DBError> {
 
 #[cfg(test)]
 pub(super) async fn get_in_memory_db_pool() -> Result<Pool<Sqlite>, DBError> {
-    let db = SqlitePool::connect("sqlite://:memory:")
+    let options = SqliteConnectOptions::from_str("sqlite://:memory:")
+        .map_err(DBError::CouldntConnectToDB)?
+        .with_regexp();
+    let db = SqlitePool::connect_with(options)
         .await
         .map_err(DBError::CouldntConnectToDB)?;
 
@@ -1,4 +1,11 @@
+use sqlx::sqlite::SqliteConnectOptions;
 use sqlx::{Error as SqlxError, Pool, Sqlite, SqlitePool};
+use std::str::FromStr;
+
+// a fixed point in time (2025-02-18 23:18:17 UTC) for tests to save
+// bookmarks at, so that snapshots of their timestamps stay stable
+#[cfg(test)]
+pub(super) const NOW: i64 = 1739920697;
 
 #[cfg(test)]
 pub(super) struct DBPoolFixture {
@@ -18,7 +25,8 @@ impl DBPoolFixture {
 
 #[allow(unused)]
 async fn get_in_memory_db_pool() -> Result<Pool<Sqlite>, SqlxError> {
-    let db = SqlitePool::connect("sqlite://:memory:").await?;
+    let options = SqliteConnectOptions::from_str("sqlite://:memory:")?.with_regexp();
+    let db = SqlitePool::connect_with(options).await?;
 
     sqlx::migrate!().run(&db).await?;
 
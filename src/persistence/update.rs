@@ -1,19 +1,59 @@
 use super::errors::DBError;
 use crate::domain::Tag;
-use sqlx::{Pool, Sqlite};
+use sqlx::{Pool, QueryBuilder, Sqlite, Transaction};
+
+// leaves room for the tags bound alongside each batch of URIs
+const UPDATE_BATCH_SIZE: usize = 900;
 
 pub async fn rename_tag_name(
     pool: &Pool<Sqlite>,
     source_tag: String,
     target_tag: Tag,
 ) -> Result<u64, DBError> {
-    let new_tag_name = target_tag.name();
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(DBError::CouldntBeginTransaction)?;
+
+    let rows_affected = rename_tag_in_tx(&mut tx, &source_tag, target_tag.name()).await?;
 
+    tx.commit()
+        .await
+        .map_err(DBError::CouldntCommitTransaction)?;
+
+    Ok(rows_affected)
+}
+
+// renames several tags at once; either all of them get renamed, or none do
+pub async fn rename_tag_names(
+    pool: &Pool<Sqlite>,
+    renames: &[(String, Tag)],
+) -> Result<u64, DBError> {
     let mut tx = pool
         .begin()
         .await
         .map_err(DBError::CouldntBeginTransaction)?;
 
+    let mut rows_affected = 0;
+    for (source_tag, target_tag) in renames {
+        rows_affected += rename_tag_in_tx(&mut tx, source_tag, target_tag.name()).await?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(DBError::CouldntCommitTransaction)?;
+
+    Ok(rows_affected)
+}
+
+// if the new tag already exists, bookmarks with the source tag get the new one
+// instead, and the source tag is deleted; aliases of the source tag are
+// carried over to the new one
+async fn rename_tag_in_tx(
+    tx: &mut Transaction<'_, Sqlite>,
+    source_tag: &str,
+    new_tag_name: &str,
+) -> Result<u64, DBError> {
     let result = {
         let maybe_original_tag_id = sqlx::query!(
             "
@@ -26,7 +66,7 @@ WHERE
 ",
             source_tag
         )
-        .fetch_optional(&mut *tx)
+        .fetch_optional(&mut **tx)
         .await
         .map_err(|e| DBError::CouldntExecuteQuery("check if original tag exists".into(), e))?
         .map(|r| r.id);
@@ -36,6 +76,36 @@ WHERE
             None => return Ok(0),
         };
 
+        // aliases follow the tag they stand for; ones that would now stand for
+        // themselves are dropped
+        sqlx::query!(
+            "
+UPDATE
+    tag_aliases
+SET
+    tag = ?
+WHERE
+    tag = ?
+",
+            new_tag_name,
+            source_tag
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("repoint tag aliases".into(), e))?;
+
+        sqlx::query!(
+            "
+DELETE FROM
+    tag_aliases
+WHERE
+    alias = tag
+"
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("delete redundant tag aliases".into(), e))?;
+
         let maybe_new_tag_id = sqlx::query!(
             "
 SELECT
@@ -47,7 +117,7 @@ WHERE
 ",
             new_tag_name
         )
-        .fetch_optional(&mut *tx)
+        .fetch_optional(&mut **tx)
         .await
         .map_err(|e| DBError::CouldntExecuteQuery("check if new tag exists".into(), e))?
         .map(|r| r.id);
@@ -73,7 +143,7 @@ AND NOT EXISTS (
                     original_tag_id,
                     new_tag_id,
                 )
-                .execute(&mut *tx)
+                .execute(&mut **tx)
                 .await
                 .map_err(|e| {
                     DBError::CouldntExecuteQuery("replace tag id in bookmark_tags".to_string(), e)
@@ -88,7 +158,7 @@ WHERE
 ",
                     original_tag_id,
                 )
-                .execute(&mut *tx)
+                .execute(&mut **tx)
                 .await
                 .map_err(|e| DBError::CouldntExecuteQuery("delete original tag".to_string(), e))?
             }
@@ -104,29 +174,283 @@ WHERE
                 new_tag_name,
                 original_tag_id,
             )
-            .execute(&mut *tx)
+            .execute(&mut **tx)
             .await
             .map_err(|e| DBError::CouldntExecuteQuery("rename tag".to_string(), e))?,
         }
     };
 
+    Ok(result.rows_affected())
+}
+
+// adds tags to bookmarks in a single transaction; bookmarks that didn't have
+// all of the tags get their last update time set to now. Returns the number of
+// bookmarks that changed.
+pub async fn add_tags_to_bookmarks(
+    pool: &Pool<Sqlite>,
+    uris: &[String],
+    tags: &[Tag],
+    now: i64,
+) -> Result<u64, DBError> {
+    let tag_names = tags
+        .iter()
+        .map(|t| t.name().to_string())
+        .collect::<Vec<_>>();
+    if uris.is_empty() || tag_names.is_empty() {
+        return Ok(0);
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(DBError::CouldntBeginTransaction)?;
+
+    for tag_name in &tag_names {
+        sqlx::query!(
+            "
+INSERT INTO
+    tags (name)
+VALUES
+    (?) ON CONFLICT (name) DO NOTHING
+",
+            tag_name
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DBError::CouldntExecuteQuery("insert tag".into(), e))?;
+    }
+
+    let mut rows_affected = 0;
+    for uri_chunk in uris.chunks(UPDATE_BATCH_SIZE) {
+        let mut query_builder = QueryBuilder::<Sqlite>::new(
+            r#"
+UPDATE
+    bookmarks
+SET
+    updated_at = "#,
+        );
+        query_builder.push_bind(now).push(
+            r#"
+WHERE
+    uri IN "#,
+        );
+        push_values(uri_chunk, &mut query_builder);
+        query_builder.push(
+            r#"
+    AND EXISTS (
+        SELECT
+            1
+        FROM
+            tags t
+        WHERE
+            t.name IN "#,
+        );
+        push_values(&tag_names, &mut query_builder);
+        query_builder.push(
+            r#"
+            AND NOT EXISTS (
+                SELECT
+                    1
+                FROM
+                    bookmark_tags bt
+                WHERE
+                    bt.bookmark_id = bookmarks.id
+                    AND bt.tag_id = t.id
+            )
+    )
+"#,
+        );
+
+        rows_affected += query_builder
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DBError::CouldntExecuteQuery("update bookmarks gaining tags".into(), e))?
+            .rows_affected();
+
+        let mut query_builder = QueryBuilder::<Sqlite>::new(
+            r#"
+INSERT INTO
+    bookmark_tags (bookmark_id, tag_id)
+SELECT
+    b.id,
+    t.id
+FROM
+    bookmarks b,
+    tags t
+WHERE
+    b.uri IN "#,
+        );
+        push_values(uri_chunk, &mut query_builder);
+        query_builder.push(
+            r#"
+    AND t.name IN "#,
+        );
+        push_values(&tag_names, &mut query_builder);
+        query_builder.push(
+            r#"
+ON CONFLICT (bookmark_id, tag_id) DO NOTHING
+"#,
+        );
+
+        query_builder
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DBError::CouldntExecuteQuery("insert bookmark-tag pairs".into(), e))?;
+    }
+
     tx.commit()
         .await
         .map_err(DBError::CouldntCommitTransaction)?;
 
-    Ok(result.rows_affected())
+    Ok(rows_affected)
+}
+
+// removes tags from bookmarks in a single transaction; bookmarks that had any
+// of the tags get their last update time set to now, and tags that end up
+// unused are deleted. Returns the number of bookmarks that changed.
+pub async fn remove_tags_from_bookmarks(
+    pool: &Pool<Sqlite>,
+    uris: &[String],
+    tags: &[Tag],
+    now: i64,
+) -> Result<u64, DBError> {
+    let tag_names = tags
+        .iter()
+        .map(|t| t.name().to_string())
+        .collect::<Vec<_>>();
+    if uris.is_empty() || tag_names.is_empty() {
+        return Ok(0);
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(DBError::CouldntBeginTransaction)?;
+
+    let mut rows_affected = 0;
+    for uri_chunk in uris.chunks(UPDATE_BATCH_SIZE) {
+        let mut query_builder = QueryBuilder::<Sqlite>::new(
+            r#"
+UPDATE
+    bookmarks
+SET
+    updated_at = "#,
+        );
+        query_builder.push_bind(now).push(
+            r#"
+WHERE
+    uri IN "#,
+        );
+        push_values(uri_chunk, &mut query_builder);
+        query_builder.push(
+            r#"
+    AND EXISTS (
+        SELECT
+            1
+        FROM
+            bookmark_tags bt
+            JOIN tags t ON bt.tag_id = t.id
+        WHERE
+            bt.bookmark_id = bookmarks.id
+            AND t.name IN "#,
+        );
+        push_values(&tag_names, &mut query_builder);
+        query_builder.push(
+            r#"
+    )
+"#,
+        );
+
+        rows_affected += query_builder
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DBError::CouldntExecuteQuery("update bookmarks losing tags".into(), e))?
+            .rows_affected();
+
+        let mut query_builder = QueryBuilder::<Sqlite>::new(
+            r#"
+DELETE FROM
+    bookmark_tags
+WHERE
+    bookmark_id IN (
+        SELECT
+            id
+        FROM
+            bookmarks
+        WHERE
+            uri IN "#,
+        );
+        push_values(uri_chunk, &mut query_builder);
+        query_builder.push(
+            r#"
+    )
+    AND tag_id IN (
+        SELECT
+            id
+        FROM
+            tags
+        WHERE
+            name IN "#,
+        );
+        push_values(&tag_names, &mut query_builder);
+        query_builder.push(
+            r#"
+    )
+"#,
+        );
+
+        query_builder
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DBError::CouldntExecuteQuery("delete bookmark-tag pairs".into(), e))?;
+    }
+
+    sqlx::query!(
+        "
+DELETE FROM
+    tags
+WHERE
+    id NOT IN (
+        SELECT
+            tag_id
+        FROM
+            bookmark_tags
+    )
+",
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| DBError::CouldntExecuteQuery("clean up unused tags".into(), e))?;
+
+    tx.commit()
+        .await
+        .map_err(DBError::CouldntCommitTransaction)?;
+
+    Ok(rows_affected)
+}
+
+fn push_values(values: &[String], query_builder: &mut QueryBuilder<'_, Sqlite>) {
+    query_builder.push("(");
+    let mut values_builder = query_builder.separated(", ");
+    for value in values {
+        values_builder.push_bind(value.clone());
+    }
+    values_builder.push_unseparated(")");
 }
 
 #[cfg(test)]
 mod tests {
 
-    use std::time::{SystemTime, UNIX_EPOCH};
-
     use super::*;
     use crate::domain::{DraftBookmark, PotentialBookmark, Tag};
-    use crate::persistence::test_fixtures::DBPoolFixture;
+    use crate::persistence::test_fixtures::{DBPoolFixture, NOW};
     use crate::persistence::{
-        SaveBookmarkOptions, create_or_update_bookmark, get_bookmark_with_exact_uri, get_tags,
+        SaveBookmarkOptions, create_or_update_bookmark, create_or_update_tag_alias,
+        get_bookmark_with_exact_uri, get_tag_aliases, get_tags,
     };
     use insta::assert_yaml_snapshot;
 
@@ -146,9 +470,7 @@ mod tests {
         )))
         .expect("draft bookmark should be initialized");
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         create_or_update_bookmark(
             &fx.pool,
@@ -190,9 +512,7 @@ mod tests {
             ("https://uri-five.com", None, vec!["tag3"]),
         ];
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         for (uri, title, tags) in uris {
             let draft_bookmark =
@@ -239,6 +559,9 @@ mod tests {
         uri: "https://uri-one.com"
         title: ~
         tags: "tag2,tag3"
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
         "#);
 
         let bookmark_two = get_bookmark_with_exact_uri(&fx.pool, "https://uri-two.com")
@@ -249,6 +572,9 @@ mod tests {
         uri: "https://uri-two.com"
         title: ~
         tags: "tag2,tag4"
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
         "#);
 
         let bookmark_three = get_bookmark_with_exact_uri(&fx.pool, "https://uri-three.com")
@@ -259,6 +585,9 @@ mod tests {
         uri: "https://uri-three.com"
         title: ~
         tags: tag3
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
         "#);
 
         let bookmark_four = get_bookmark_with_exact_uri(&fx.pool, "https://uri-four.com")
@@ -269,6 +598,9 @@ mod tests {
         uri: "https://uri-four.com"
         title: ~
         tags: tag3
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
         "#);
 
         let bookmark_five = get_bookmark_with_exact_uri(&fx.pool, "https://uri-five.com")
@@ -279,6 +611,9 @@ mod tests {
         uri: "https://uri-five.com"
         title: ~
         tags: tag3
+        notes: ~
+        created_at: 1739920697
+        updated_at: 1739920697
         "#);
     }
 
@@ -294,9 +629,7 @@ mod tests {
         )))
         .expect("draft bookmark should be initialized");
 
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let now = since_the_epoch.as_secs() as i64;
+        let now = NOW;
 
         create_or_update_bookmark(
             &fx.pool,
@@ -316,4 +649,174 @@ mod tests {
         // THEN
         assert_eq!(rows_affected, 0);
     }
+
+    #[tokio::test]
+    async fn renaming_several_tags_at_once_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let uris = [
+            ("https://uri-one.com", vec!["lang/rust", "lang/go"]),
+            ("https://uri-two.com", vec!["lang/rust", "languages/rust"]),
+            ("https://uri-three.com", vec!["tools"]),
+        ];
+
+        for (uri, tags) in uris {
+            let draft_bookmark =
+                DraftBookmark::try_from(PotentialBookmark::from((uri, None, &tags)))
+                    .expect("draft bookmark should be initialized");
+            create_or_update_bookmark(
+                &fx.pool,
+                &draft_bookmark,
+                NOW,
+                SaveBookmarkOptions::default(),
+            )
+            .await
+            .expect("bookmark should be saved in db");
+        }
+
+        let renames = [("lang/go", "languages/go"), ("lang/rust", "languages/rust")]
+            .into_iter()
+            .map(|(source, target)| {
+                (
+                    source.to_string(),
+                    Tag::try_from(target).expect("new tag should've been created"),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // WHEN
+        rename_tag_names(&fx.pool, &renames)
+            .await
+            .expect("result should've been a success");
+
+        // THEN
+        let tags = get_tags(&fx.pool)
+            .await
+            .expect("tags should've been fetched");
+        assert_yaml_snapshot!(tags, @"
+        - languages/go
+        - languages/rust
+        - tools
+        ");
+
+        let bookmark_two = get_bookmark_with_exact_uri(&fx.pool, "https://uri-two.com")
+            .await
+            .expect("bookmark should've been fetched")
+            .expect("bookmark should've been present");
+        assert_eq!(bookmark_two.tags.as_deref(), Some("languages/rust"));
+    }
+
+    #[tokio::test]
+    async fn renaming_a_tag_carries_its_aliases_over() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let draft_bookmark = DraftBookmark::try_from(PotentialBookmark::from((
+            "https://github.com/dhth/bmm",
+            None,
+            &vec!["javascript"],
+        )))
+        .expect("draft bookmark should be initialized");
+        create_or_update_bookmark(
+            &fx.pool,
+            &draft_bookmark,
+            NOW,
+            SaveBookmarkOptions::default(),
+        )
+        .await
+        .expect("bookmark should be saved in db");
+        for alias in ["js", "ecmascript"] {
+            create_or_update_tag_alias(
+                &fx.pool,
+                &Tag::try_from(alias).expect("alias should've been created"),
+                &Tag::try_from("javascript").expect("tag should've been created"),
+            )
+            .await
+            .expect("alias should've been saved");
+        }
+        let new_tag = Tag::try_from("ecmascript").expect("new tag should've been created");
+
+        // WHEN
+        rename_tag_name(&fx.pool, "javascript".into(), new_tag)
+            .await
+            .expect("result should've been a success");
+
+        // THEN
+        let aliases = get_tag_aliases(&fx.pool)
+            .await
+            .expect("aliases should've been fetched");
+        assert_yaml_snapshot!(aliases, @"
+        - alias: js
+          tag: ecmascript
+        ");
+    }
+
+    #[tokio::test]
+    async fn adding_and_removing_tags_on_several_bookmarks_works() {
+        // GIVEN
+        let fx = DBPoolFixture::new().await;
+        let uris = [
+            ("https://uri-one.com", vec!["rust"]),
+            ("https://uri-two.com", vec!["rust", "cli"]),
+            ("https://uri-three.com", vec!["go"]),
+        ];
+        for (uri, tags) in &uris {
+            let draft_bookmark =
+                DraftBookmark::try_from(PotentialBookmark::from((*uri, None, tags)))
+                    .expect("draft bookmark should be initialized");
+            create_or_update_bookmark(
+                &fx.pool,
+                &draft_bookmark,
+                NOW,
+                SaveBookmarkOptions::default(),
+            )
+            .await
+            .expect("bookmark should be saved in db");
+        }
+        let changed_uris = vec![
+            "https://uri-one.com".to_string(),
+            "https://uri-two.com".to_string(),
+        ];
+        let cli = vec![Tag::try_from("cli").expect("tag should've been created")];
+        let rust = vec![Tag::try_from("rust").expect("tag should've been created")];
+
+        // WHEN
+        let num_gained = add_tags_to_bookmarks(&fx.pool, &changed_uris, &cli, NOW + 10)
+            .await
+            .expect("tags should've been added");
+        let num_lost = remove_tags_from_bookmarks(&fx.pool, &changed_uris, &rust, NOW + 20)
+            .await
+            .expect("tags should've been removed");
+
+        // THEN
+        assert_eq!(num_gained, 1);
+        assert_eq!(num_lost, 2);
+
+        let mut bookmarks = vec![];
+        for (uri, _) in &uris {
+            let bookmark = get_bookmark_with_exact_uri(&fx.pool, uri)
+                .await
+                .expect("bookmark should've been fetched")
+                .expect("bookmark should've been present");
+            bookmarks.push((bookmark.uri, bookmark.tags, bookmark.updated_at - NOW));
+        }
+        assert_yaml_snapshot!(bookmarks, @r#"
+        - - "https://uri-one.com"
+          - cli
+          - 20
+        - - "https://uri-two.com"
+          - cli
+          - 20
+        - - "https://uri-three.com"
+          - go
+          - 0
+        "#);
+
+        let tags = get_tags(&fx.pool)
+            .await
+            .expect("tags should've been fetched");
+        assert_yaml_snapshot!(tags, @"
+        - cli
+        - go
+        ");
+    }
 }
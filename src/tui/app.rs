@@ -79,6 +79,9 @@ impl AppTui {
             TuiContext::Search(q) => {
                 initial_commands.push(Command::SearchBookmarks(q.clone()));
             }
+            TuiContext::FuzzySearch(q) => {
+                initial_commands.push(Command::FuzzySearchBookmarks(q.clone()));
+            }
             TuiContext::Tags => {
                 initial_commands.push(Command::FetchTags);
             }
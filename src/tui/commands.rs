@@ -1,9 +1,10 @@
-use crate::persistence::SearchTerms;
+use crate::domain::SearchQuery;
 
 #[derive(Clone, Debug)]
 pub(super) enum Command {
     OpenInBrowser(String),
-    SearchBookmarks(SearchTerms),
+    SearchBookmarks(SearchQuery),
+    FuzzySearchBookmarks(String),
     FetchTags,
     FetchBookmarksForTag(String),
     CopyContentToClipboard(String),
@@ -1,7 +1,11 @@
 use super::commands::Command;
 use super::message::{Message, UrlsOpenedResult};
 use crate::common::DEFAULT_LIMIT;
-use crate::persistence::{get_bookmarks, get_bookmarks_by_query, get_tags_with_stats};
+use crate::domain::fuzzy_rank;
+use crate::persistence::{
+    BookmarkFilter, Page, SortField, TagMatch, get_all_bookmarks_matching, get_bookmarks,
+    get_bookmarks_by_query, get_tags_with_stats,
+};
 use arboard::Clipboard;
 use sqlx::{Pool, Sqlite};
 use tokio::sync::mpsc::Sender;
@@ -26,11 +30,34 @@ pub(super) async fn handle_command(
         Command::SearchBookmarks(search_query) => {
             let pool = pool.clone();
             tokio::spawn(async move {
-                let result = get_bookmarks_by_query(&pool, &search_query, DEFAULT_LIMIT).await;
+                let result = get_bookmarks_by_query(
+                    &pool,
+                    &search_query,
+                    &BookmarkFilter::default(),
+                    &Page {
+                        sort_field: SortField::Relevance,
+                        ..Page::new(DEFAULT_LIMIT)
+                    },
+                )
+                .await;
                 let message = Message::SearchFinished(result);
                 let _ = event_tx.try_send(message);
             });
         }
+        Command::FuzzySearchBookmarks(query) => {
+            let pool = pool.clone();
+            tokio::spawn(async move {
+                let result = get_all_bookmarks_matching(&pool, &BookmarkFilter::default())
+                    .await
+                    .map(|bookmarks| {
+                        let mut matches = fuzzy_rank(bookmarks, &query);
+                        matches.truncate(DEFAULT_LIMIT as usize);
+                        matches
+                    });
+                let message = Message::FuzzySearchFinished(result);
+                let _ = event_tx.try_send(message);
+            });
+        }
         Command::FetchTags => {
             let pool = pool.clone();
             tokio::spawn(async move {
@@ -42,7 +69,8 @@ pub(super) async fn handle_command(
         Command::FetchBookmarksForTag(tag) => {
             let pool = pool.clone();
             tokio::spawn(async move {
-                let result = get_bookmarks(&pool, None, None, vec![tag], DEFAULT_LIMIT).await;
+                let filter = BookmarkFilter::default().with_tags(vec![tag], TagMatch::All);
+                let result = get_bookmarks(&pool, &filter, &Page::new(DEFAULT_LIMIT)).await;
                 let message = Message::BookmarksForTagFetched(result);
                 let _ = event_tx.try_send(message);
             });
@@ -1,6 +1,6 @@
 use super::common::ActivePane;
 use super::model::Model;
-use crate::domain::{SavedBookmark, TagStats};
+use crate::domain::{FuzzyMatch, SavedBookmark, TagStats};
 use crate::persistence::DBError;
 use ratatui::crossterm::event::{Event, KeyCode, KeyEventKind};
 use std::io::Error as IOError;
@@ -14,12 +14,14 @@ pub enum Message {
     OpenInBrowser,
     UrlsOpenedInBrowser(UrlsOpenedResult),
     SearchFinished(Result<Vec<SavedBookmark>, DBError>),
+    FuzzySearchFinished(Result<Vec<FuzzyMatch>, DBError>),
     TagsFetched(Result<Vec<TagStats>, DBError>),
     ShowView(ActivePane),
     SearchInputGotEvent(Event),
     CopyURIToClipboard,
     CopyURIsToClipboard,
     SubmitSearch,
+    ToggleFuzzySearch,
     ShowBookmarksForTag,
     BookmarksForTagFetched(Result<Vec<SavedBookmark>, DBError>),
     ContentCopiedToClipboard(Result<(), String>),
@@ -67,6 +69,7 @@ pub fn get_event_handling_msg(model: &Model, event: Event) -> Option<Message> {
                     ActivePane::SearchInput => match key_event.code {
                         KeyCode::Esc => Some(Message::GoBackOrQuit),
                         KeyCode::Enter => Some(Message::SubmitSearch),
+                        KeyCode::Tab => Some(Message::ToggleFuzzySearch),
                         KeyCode::Down => Some(Message::GoToNextListItem),
                         KeyCode::Up => Some(Message::GoToPreviousListItem),
                         _ => Some(Message::SearchInputGotEvent(event)),
@@ -1,11 +1,8 @@
 use super::{commands::Command, common::*};
-use crate::{
-    domain::{SavedBookmark, TagStats},
-    persistence::SearchTerms,
-};
+use crate::domain::{FuzzyMatch, SavedBookmark, SearchQuery, TagStats};
 use ratatui::{
     style::Style,
-    text::Line,
+    text::{Line, Span},
     widgets::{ListItem, ListState},
 };
 use sqlx::{Pool, Sqlite};
@@ -22,6 +19,8 @@ pub(crate) enum RunningState {
 pub(crate) struct BookmarkItem {
     pub(crate) bookmark: SavedBookmark,
     pub(crate) status: bool,
+    // positions of the characters in the uri that matched a fuzzy search
+    pub(crate) highlights: Vec<usize>,
 }
 
 #[derive(Debug)]
@@ -72,7 +71,8 @@ impl UserMessage {
 
 pub enum TuiContext {
     Initial,
-    Search(SearchTerms),
+    Search(SearchQuery),
+    FuzzySearch(String),
     Tags,
 }
 
@@ -110,14 +110,10 @@ impl From<Vec<SavedBookmark>> for BookmarkItems {
     }
 }
 
-impl From<(Vec<SavedBookmark>, usize)> for BookmarkItems {
-    fn from(value: (Vec<SavedBookmark>, usize)) -> Self {
-        let bookmarks = value.0;
+impl From<(Vec<BookmarkItem>, usize)> for BookmarkItems {
+    fn from(value: (Vec<BookmarkItem>, usize)) -> Self {
+        let items = value.0;
         let index = value.1;
-        let items = bookmarks
-            .into_iter()
-            .map(|bookmark| BookmarkItem::new(bookmark, false))
-            .collect();
         let state = ListState::default().with_selected(Some(index));
 
         Self { items, state }
@@ -133,21 +129,66 @@ impl From<Vec<TagStats>> for TagItems {
 }
 
 impl BookmarkItem {
-    fn new(bookmark: SavedBookmark, status: bool) -> Self {
-        Self { bookmark, status }
+    pub(super) fn new(bookmark: SavedBookmark, status: bool) -> Self {
+        Self {
+            bookmark,
+            status,
+            highlights: vec![],
+        }
+    }
+}
+
+impl From<FuzzyMatch> for BookmarkItem {
+    fn from(fuzzy_match: FuzzyMatch) -> Self {
+        Self {
+            bookmark: fuzzy_match.bookmark,
+            status: false,
+            highlights: fuzzy_match.uri_indices,
+        }
     }
 }
 
 impl From<&BookmarkItem> for ListItem<'_> {
     fn from(value: &BookmarkItem) -> Self {
-        let line = match value.status {
-            false => Line::from(value.bookmark.uri.clone()),
-            true => Line::styled(
-                format!("> {}", value.bookmark.uri.clone()),
-                Style::new().fg(COLOR_TWO),
-            ),
+        let (prefix, style) = match value.status {
+            false => ("", Style::new()),
+            true => ("> ", Style::new().fg(COLOR_TWO)),
         };
-        ListItem::new(line)
+
+        if value.highlights.is_empty() {
+            return ListItem::new(Line::styled(
+                format!("{prefix}{}", value.bookmark.uri),
+                style,
+            ));
+        }
+
+        // consecutive characters that are either all highlighted or all not
+        // highlighted are grouped into a single span
+        let mut spans = vec![Span::styled(prefix, style)];
+        let mut chunk = String::new();
+        let mut chunk_highlighted = false;
+        for (i, c) in value.bookmark.uri.chars().enumerate() {
+            let highlighted = value.highlights.binary_search(&i).is_ok();
+            if highlighted != chunk_highlighted && !chunk.is_empty() {
+                spans.push(highlight_span(
+                    std::mem::take(&mut chunk),
+                    chunk_highlighted,
+                    style,
+                ));
+            }
+            chunk_highlighted = highlighted;
+            chunk.push(c);
+        }
+        spans.push(highlight_span(chunk, chunk_highlighted, style));
+
+        ListItem::new(Line::from(spans))
+    }
+}
+
+fn highlight_span(content: String, highlighted: bool, style: Style) -> Span<'static> {
+    match highlighted {
+        true => Span::styled(content, style.bold().fg(PRIMARY_COLOR)),
+        false => Span::styled(content, style),
     }
 }
 
@@ -168,6 +209,7 @@ pub(super) struct Model {
     pub(super) render_counter: u64,
     pub(super) event_counter: u64,
     pub(super) search_input: Input,
+    pub(super) fuzzy_search: bool,
     pub(super) initial: bool,
     pub(super) terminal_dimensions: TerminalDimensions,
     pub(super) terminal_too_small: bool,
@@ -183,12 +225,13 @@ impl Model {
         let debug = std::env::var("BMM_DEBUG").unwrap_or_default().trim() == "1";
 
         let active_pane = match context {
-            TuiContext::Search(_) => ActivePane::List,
+            TuiContext::Search(_) | TuiContext::FuzzySearch(_) => ActivePane::List,
             TuiContext::Tags => ActivePane::TagsList,
             TuiContext::Initial => ActivePane::SearchInput,
         };
 
         let initial = matches!(context, TuiContext::Initial);
+        let fuzzy_search = matches!(context, TuiContext::FuzzySearch(_));
 
         let terminal_too_small = terminal_dimensions.width < MIN_TERMINAL_WIDTH
             || terminal_dimensions.height < MIN_TERMINAL_HEIGHT;
@@ -203,6 +246,7 @@ impl Model {
             render_counter: 0,
             event_counter: 0,
             search_input: Input::default(),
+            fuzzy_search,
             initial,
             terminal_dimensions,
             terminal_too_small,
@@ -290,6 +334,22 @@ impl Model {
         };
     }
 
+    pub(super) fn show_search_results(&mut self, items: Vec<BookmarkItem>) {
+        if items.is_empty() {
+            self.user_message = Some(UserMessage::info("no bookmarks found for query"));
+            self.bookmark_items = BookmarkItems::from(vec![]);
+            return;
+        }
+
+        // keep the cursor where it was, as long as there are enough results
+        let index = self
+            .bookmark_items
+            .state
+            .selected()
+            .map_or(0, |i| i.min(items.len() - 1));
+        self.bookmark_items = BookmarkItems::from((items, index));
+    }
+
     pub(super) fn get_cmd_to_open_selection_in_browser(&self) -> Option<Command> {
         let url = {
             let i = self.bookmark_items.state.selected()?;
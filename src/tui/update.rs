@@ -2,7 +2,8 @@ use super::commands::Command;
 use super::common::*;
 use super::message::{Message, UrlsOpenedResult};
 use super::model::*;
-use crate::persistence::SearchTerms;
+use crate::domain::SearchQuery;
+use std::time::{SystemTime, UNIX_EPOCH};
 use tui_input::backend::crossterm::EventHandler;
 
 pub fn update(model: &mut Model, msg: Message) -> Vec<Command> {
@@ -30,23 +31,17 @@ pub fn update(model: &mut Model, msg: Message) -> Vec<Command> {
         Message::GoToFirstListItem => model.select_first_list_item(),
         Message::GoToLastListItem => model.select_last_list_item(),
         Message::SearchFinished(result) => match result {
-            Ok(bookmarks) => {
-                if bookmarks.is_empty() {
-                    model.user_message = Some(UserMessage::info("no bookmarks found for query"));
-                    model.bookmark_items = BookmarkItems::from(vec![]);
-                } else {
-                    let bookmarks_len = bookmarks.len();
-                    if let Some(current_index) = model.bookmark_items.state.selected() {
-                        if current_index < bookmarks_len {
-                            model.bookmark_items = BookmarkItems::from((bookmarks, current_index));
-                        } else {
-                            model.bookmark_items =
-                                BookmarkItems::from((bookmarks, bookmarks_len - 1));
-                        }
-                    } else {
-                        model.bookmark_items = BookmarkItems::from(bookmarks);
-                    }
-                }
+            Ok(bookmarks) => model.show_search_results(
+                bookmarks
+                    .into_iter()
+                    .map(|bookmark| BookmarkItem::new(bookmark, false))
+                    .collect(),
+            ),
+            Err(e) => model.user_message = Some(UserMessage::error(&format!("{e}"))),
+        },
+        Message::FuzzySearchFinished(result) => match result {
+            Ok(matches) => {
+                model.show_search_results(matches.into_iter().map(BookmarkItem::from).collect())
             }
             Err(e) => model.user_message = Some(UserMessage::error(&format!("{e}"))),
         },
@@ -60,12 +55,28 @@ pub fn update(model: &mut Model, msg: Message) -> Vec<Command> {
         Message::SearchInputGotEvent(event) => {
             model.search_input.handle_event(&event);
         }
+        Message::SubmitSearch if model.fuzzy_search => {
+            let search_query = model.search_input.value().trim();
+            if search_query.is_empty() {
+                model.user_message = Some(UserMessage::error("fuzzy search query is empty"));
+            } else {
+                cmds.push(Command::FuzzySearchBookmarks(search_query.to_string()));
+                model.initial = false;
+                model.search_input.reset();
+                model.active_pane = ActivePane::List;
+            }
+        }
+        Message::ToggleFuzzySearch => model.fuzzy_search = !model.fuzzy_search,
         Message::SubmitSearch => {
             let search_query = model.search_input.value();
-            match SearchTerms::try_from(search_query) {
-                Ok(search_terms) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or_default();
+            match SearchQuery::parse(search_query, now) {
+                Ok(parsed_query) => {
                     if !search_query.is_empty() {
-                        cmds.push(Command::SearchBookmarks(search_terms));
+                        cmds.push(Command::SearchBookmarks(parsed_query));
                         if model.initial {
                             model.initial = false;
                         }
@@ -4,7 +4,7 @@ use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Layout, Rect},
     style::Style,
-    text::{Line, Span},
+    text::{Line, Span, Text},
     widgets::{Block, List, ListDirection, ListItem, Padding, Paragraph},
 };
 
@@ -79,7 +79,7 @@ b:::::::::::::::b    m::::m   m::::m   m::::m  m::::m   m::::m   m::::m
 bbbbbbbbbbbbbbbb     mmmmmm   mmmmmm   mmmmmm  mmmmmm   mmmmmm   mmmmmm
 
 
-type your search query and press enter
+type your search query and press enter (tab toggles fuzzy search)
 "#;
 
     let top_padding = if terminal_height > 26 {
@@ -197,11 +197,15 @@ fn render_status_line(model: &Model, frame: &mut Frame, chunk: Rect) {
 }
 
 fn render_search_input(model: &Model, frame: &mut Frame, chunk: Rect) {
+    let title = match model.fuzzy_search {
+        true => " fuzzy search? ",
+        false => " search query? ",
+    };
     let input = Paragraph::new(model.search_input.value())
         .style(Style::default().fg(COLOR_THREE))
         .block(
             Block::bordered()
-                .title(" search query? ")
+                .title(title)
                 .title_style(Style::new().bold().bg(COLOR_THREE).fg(FG_COLOR)),
         );
     frame.render_widget(input, chunk);
@@ -250,6 +254,7 @@ fn render_bookmarks_details(model: &Model, frame: &mut Frame, chunk: Rect) {
                 r#"URI   : {}
 Title : {}
 Tags  : {}
+Notes : {}
 "#,
                 bookmark_item.bookmark.uri,
                 bookmark_item
@@ -261,8 +266,22 @@ Tags  : {}
                     .bookmark
                     .tags
                     .as_deref()
+                    .unwrap_or("<NOT SET>"),
+                // the details pane only has room for a single line of notes
+                bookmark_item
+                    .bookmark
+                    .notes
+                    .as_deref()
+                    .map(|n| n.split_whitespace().collect::<Vec<_>>().join(" "))
+                    .as_deref()
                     .unwrap_or("<NOT SET>")
             );
+            let mut details = Text::from(details);
+            if let Some(snippet) = bookmark_item.bookmark.snippet.as_deref() {
+                let mut spans = vec![Span::raw("Match : ")];
+                spans.extend(snippet_spans(snippet));
+                details.push_line(Line::from(spans));
+            }
             let details = Paragraph::new(details)
                 .block(
                     Block::bordered()
@@ -279,6 +298,27 @@ Tags  : {}
     }
 }
 
+// matched words in snippets are wrapped in "**"; every odd part after splitting
+// on it is a match. Snippets are collapsed onto a single line, as the details
+// pane only has room for one
+fn snippet_spans(snippet: &str) -> Vec<Span<'static>> {
+    snippet
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split("**")
+        .enumerate()
+        .filter(|(_, part)| !part.is_empty())
+        .map(|(i, part)| {
+            if i % 2 == 1 {
+                Span::styled(part.to_string(), Style::new().bold().fg(PRIMARY_COLOR))
+            } else {
+                Span::raw(part.to_string())
+            }
+        })
+        .collect()
+}
+
 fn render_tag_details(model: &Model, frame: &mut Frame, chunk: Rect) {
     let maybe_selected = model.tag_items.state.selected();
 
@@ -370,8 +410,8 @@ fn render_list_view(model: &mut Model, frame: &mut Frame, search: bool) {
                     .direction(ratatui::layout::Direction::Vertical)
                     .constraints(vec![
                         Constraint::Length(2),
-                        Constraint::Min(11),
-                        Constraint::Length(7),
+                        Constraint::Min(10),
+                        Constraint::Length(8),
                         Constraint::Length(3),
                         Constraint::Length(1),
                     ])
@@ -388,8 +428,8 @@ fn render_list_view(model: &mut Model, frame: &mut Frame, search: bool) {
                     .direction(ratatui::layout::Direction::Vertical)
                     .constraints(vec![
                         Constraint::Length(2),
-                        Constraint::Min(14),
-                        Constraint::Length(7),
+                        Constraint::Min(13),
+                        Constraint::Length(8),
                         Constraint::Length(1),
                     ])
                     .split(frame.area());
@@ -2,6 +2,17 @@ use insta_cmd::get_cargo_bin;
 use std::{ffi::OsStr, path::PathBuf, process::Command};
 use tempfile::{TempDir, tempdir};
 
+#[allow(unused)]
+pub const TIMESTAMP_FILTERS: [(&str, &str); 4] = [
+    (r#"(ADD_DATE|LAST_MODIFIED)="\d+""#, r#"$1="[TIMESTAMP]""#),
+    (
+        r#""(created_at|updated_at)": \d+"#,
+        r#""$1": "[TIMESTAMP]""#,
+    ),
+    (r"(?m),\d+,\d+$", ",[TIMESTAMP],[TIMESTAMP]"),
+    (r"\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2} UTC", "[DATE-TIME]"),
+];
+
 pub struct Fixture {
     _bin_path: PathBuf,
     _temp_dir: TempDir,
@@ -28,6 +39,15 @@ impl Fixture {
         }
     }
 
+    pub fn temp_file_path(&self, file_name: &str) -> String {
+        self._temp_dir
+            .path()
+            .join(file_name)
+            .to_str()
+            .expect("temporary directory path is not valid utf-8")
+            .to_string()
+    }
+
     pub fn base_cmd(&self) -> Command {
         Command::new(&self._bin_path)
     }
@@ -1,6 +1,6 @@
 mod common;
 
-use common::Fixture;
+use common::{Fixture, TIMESTAMP_FILTERS};
 use insta_cmd::assert_cmd_snapshot;
 use tempfile::tempdir;
 
@@ -48,19 +48,24 @@ fn xdg_data_home_is_respected() {
     Error: couldn't show bookmark details: bookmark doesn't exist
     ");
 
-    assert_cmd_snapshot!(cmd, @r"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Bookmark details
-    ---
-
-    Title: sqlx - crates.io: Rust Package Registry
-    URI  : https://crates.io/crates/sqlx
-    Tags : crates,rust
-
-    ----- stderr -----
-    ");
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : sqlx - crates.io: Rust Package Registry
+        URI    : https://crates.io/crates/sqlx
+        Tags   : crates,rust
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
 }
 
 //------------//
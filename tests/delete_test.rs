@@ -19,7 +19,7 @@ fn shows_help() {
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @"
+    assert_cmd_snapshot!(cmd, @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -32,6 +32,7 @@ fn shows_help() {
 
     Options:
       -p, --pattern           Treat provided values as URI patterns instead of exact URIs
+      -r, --regex             Treat provided values as regular expressions to match URIs on instead of exact URIs
       -y, --yes               Whether to skip confirmation
           --db-path <STRING>  Override bmm's database location (default: <DATA_DIR>/bmm/bmm.db)
           --debug             Output debug information without doing anything
@@ -44,6 +45,9 @@ fn shows_help() {
       Delete bookmarks matching URI patterns:
         bmm delete --pattern example.com github.com
 
+      Delete bookmarks whose URIs match regular expressions:
+        bmm delete --regex '^https://docs\.rs/[^/]+/latest'
+
       Delete without confirmation:
         bmm delete --yes https://example.com
 
@@ -155,6 +159,44 @@ fn deleting_bookmarks_by_multiple_patterns_works() {
     ");
 }
 
+#[test]
+fn deleting_bookmarks_by_regex_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut save_cmd = fx.cmd(["save-all", URI_ONE, URI_TWO, URI_THREE]);
+    assert_cmd_snapshot!(save_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    saved 3 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["delete", "--yes", "--regex", "/[a-z]mm$"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    deleted 2 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["list"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/hours
+
+    ----- stderr -----
+    ");
+}
+
 #[test]
 fn deleting_by_pattern_treats_like_metacharacters_literally() {
     // GIVEN
@@ -368,3 +410,69 @@ fn input_other_than_y_does_not_confirm_deletion() {
     ----- stderr -----
     ");
 }
+
+//------------//
+//  FAILURES  //
+//------------//
+
+#[test]
+fn deleting_bookmarks_fails_for_an_invalid_regex() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut save_cmd = fx.cmd(["save", URI_ONE]);
+    assert_cmd_snapshot!(save_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["delete", "--yes", "--regex", "dhth/(bmm"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't delete bookmarks: invalid regex: regex parse error:
+        dhth/(bmm
+             ^
+    error: unclosed group
+    ");
+
+    let mut list_cmd = fx.cmd(["list"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/bmm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn deleting_bookmarks_fails_if_both_pattern_and_regex_are_provided() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["delete", "--pattern", "--regex", "bmm"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--pattern' cannot be used with '--regex'
+
+    Usage: bmm delete --pattern --db-path <STRING> <URI>...
+
+    For more information, try '--help'.
+    ");
+}
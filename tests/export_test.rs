@@ -0,0 +1,313 @@
+mod common;
+
+use common::{Fixture, TIMESTAMP_FILTERS};
+use insta_cmd::assert_cmd_snapshot;
+
+//-------------//
+//  SUCCESSES  //
+//-------------//
+
+#[test]
+fn exporting_bookmarks_as_html_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["export", "--tags", "tools"]);
+
+    // WHEN
+    // THEN
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        <!DOCTYPE NETSCAPE-Bookmark-file-1>
+        <!-- This is an automatically generated file.
+             It will be read and overwritten.
+             DO NOT EDIT! -->
+        <META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+        <TITLE>Bookmarks</TITLE>
+        <H1>Bookmarks</H1>
+        <DL><p>
+            <DT><A HREF="https://github.com/dhth/omm" ADD_DATE="[TIMESTAMP]" LAST_MODIFIED="[TIMESTAMP]" TAGS="productivity,tools">GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line</A>
+            <DT><A HREF="https://github.com/dhth/hours" ADD_DATE="[TIMESTAMP]" LAST_MODIFIED="[TIMESTAMP]" TAGS="productivity,tools">GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds</A>
+            <DT><A HREF="https://github.com/dhth/bmm" ADD_DATE="[TIMESTAMP]" LAST_MODIFIED="[TIMESTAMP]" TAGS="tools">GitHub - dhth/bmm: get to your bookmarks in a flash</A>
+        </DL><p>
+
+        ----- stderr -----
+        "#);
+    });
+}
+
+#[test]
+fn exporting_bookmarks_to_a_file_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+    let output_path = fx.temp_file_path("bookmarks.html");
+
+    let mut cmd = fx.cmd(["export", "--output", &output_path]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    exported 4 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn importing_an_html_export_restores_bookmark_details() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+    let output_path = fx.temp_file_path("bookmarks.html");
+    let mut export_cmd = fx.cmd(["export", "--output", &output_path]);
+    assert_cmd_snapshot!(export_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    exported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let other_fx = Fixture::new();
+    let mut reimport_cmd = other_fx.cmd(["import", &output_path]);
+    assert_cmd_snapshot!(reimport_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = other_fx.cmd(["list", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        [
+          {
+            "uri": "https://crates.io/crates/sqlx",
+            "title": "sqlx - crates.io: Rust Package Registry",
+            "tags": "crates,rust",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          },
+          {
+            "uri": "https://github.com/dhth/omm",
+            "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
+            "tags": "productivity,tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          },
+          {
+            "uri": "https://github.com/dhth/hours",
+            "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
+            "tags": "productivity,tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          },
+          {
+            "uri": "https://github.com/dhth/bmm",
+            "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
+            "tags": "tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          }
+        ]
+
+        ----- stderr -----
+        "#);
+    });
+}
+
+#[test]
+fn exporting_bookmarks_as_json_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.jsonl"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["export", "--uri", "hours", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "version": 1,
+      "bookmarks": [
+        {
+          "uri": "https://github.com/dhth/hours",
+          "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
+          "tags": [
+            "productivity",
+            "tools"
+          ],
+          "notes": null,
+          "created_at": 1739920661,
+          "updated_at": 1739920670
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn exporting_bookmarks_as_jsonl_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid-versioned.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["export", "--tags", "tools", "--format", "jsonl"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"uri":"https://github.com/dhth/omm","title":"GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line","tags":["productivity","tools"],"notes":null,"created_at":1739920615,"updated_at":1739920646}
+    {"uri":"https://github.com/dhth/hours","title":"GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds","tags":["productivity","tools"],"notes":null,"created_at":1739920661,"updated_at":1739920670}
+    {"uri":"https://github.com/dhth/bmm","title":"GitHub - dhth/bmm: get to your bookmarks in a flash","tags":["tools"],"notes":null,"created_at":1739920697,"updated_at":1739920739}
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn importing_a_jsonl_export_preserves_timestamps() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.jsonl"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+    let output_path = fx.temp_file_path("bookmarks.jsonl");
+    let mut export_cmd = fx.cmd(["export", "--format", "jsonl", "--output", &output_path]);
+    assert_cmd_snapshot!(export_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    exported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let other_fx = Fixture::new();
+    let mut reimport_cmd = other_fx.cmd(["import", &output_path]);
+    assert_cmd_snapshot!(reimport_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = other_fx.cmd(["export", "--format", "jsonl"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"uri":"https://crates.io/crates/sqlx","title":"sqlx - crates.io: Rust Package Registry","tags":["crates","rust"],"notes":null,"created_at":1739897020,"updated_at":1739897041}
+    {"uri":"https://github.com/dhth/omm","title":"GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line","tags":["productivity","tools"],"notes":null,"created_at":1739920615,"updated_at":1739920646}
+    {"uri":"https://github.com/dhth/hours","title":"GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds","tags":["productivity","tools"],"notes":null,"created_at":1739920661,"updated_at":1739920670}
+    {"uri":"https://github.com/dhth/bmm","title":"GitHub - dhth/bmm: get to your bookmarks in a flash","tags":["tools"],"notes":null,"created_at":1739920697,"updated_at":1739920739}
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn exporting_when_no_bookmarks_match_writes_an_empty_list() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["export", "--uri", "nonexistent"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    <!DOCTYPE NETSCAPE-Bookmark-file-1>
+    <!-- This is an automatically generated file.
+         It will be read and overwritten.
+         DO NOT EDIT! -->
+    <META HTTP-EQUIV="Content-Type" CONTENT="text/html; charset=UTF-8">
+    <TITLE>Bookmarks</TITLE>
+    <H1>Bookmarks</H1>
+    <DL><p>
+    </DL><p>
+
+    ----- stderr -----
+    "#);
+}
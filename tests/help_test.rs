@@ -31,6 +31,7 @@ fn shows_help() {
 
     Commands:
       import    Import bookmarks from various sources
+      export    Export bookmarks to a file that browsers and other tools can import
       delete    Delete bookmarks
       list      List bookmarks based on several kinds of queries
       save      Save/update a bookmark
@@ -1,6 +1,6 @@
 mod common;
 
-use common::Fixture;
+use common::{Fixture, TIMESTAMP_FILTERS};
 use insta_cmd::assert_cmd_snapshot;
 
 //-------------//
@@ -25,6 +25,136 @@ fn importing_from_an_html_file_works() {
     ");
 }
 
+#[test]
+fn importing_from_an_html_file_with_folders_as_tags_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/valid.html",
+        "--folders-as-tags",
+    ]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(list_cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "uri": "https://github.com/dhth/bmm",
+        "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
+        "tags": "tools",
+        "notes": null,
+        "created_at": 1739920697,
+        "updated_at": 1739920739
+      },
+      {
+        "uri": "https://github.com/dhth/hours",
+        "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
+        "tags": "productivity,tools",
+        "notes": null,
+        "created_at": 1739920661,
+        "updated_at": 1739920670
+      },
+      {
+        "uri": "https://github.com/dhth/omm",
+        "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
+        "tags": "productivity,tools",
+        "notes": null,
+        "created_at": 1739920615,
+        "updated_at": 1739920646
+      },
+      {
+        "uri": "https://crates.io/crates/sqlx",
+        "title": "sqlx - crates.io: Rust Package Registry",
+        "tags": "crates,productivity,rust",
+        "notes": null,
+        "created_at": 1739897020,
+        "updated_at": 1739897041
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn importing_an_html_export_preserves_notes() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut save_cmd = fx.cmd([
+        "save",
+        "https://github.com/dhth/bmm",
+        "--tags",
+        "tools",
+        "--notes",
+        "a bookmarks manager for the command line",
+    ]);
+    assert!(
+        save_cmd
+            .output()
+            .expect("save command should've run")
+            .status
+            .success()
+    );
+    let path = fx.temp_file_path("bookmarks.html");
+    let mut export_cmd = fx.cmd(["export", "--output", &path]);
+    assert!(
+        export_cmd
+            .output()
+            .expect("export command should've run")
+            .status
+            .success()
+    );
+
+    let import_fx = Fixture::new();
+    let mut import_cmd = import_fx.cmd(["import", &path]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 1 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = import_fx.cmd(["list", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(list_cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        [
+          {
+            "uri": "https://github.com/dhth/bmm",
+            "title": null,
+            "tags": "tools",
+            "notes": "a bookmarks manager for the command line",
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          }
+        ]
+
+        ----- stderr -----
+        "#);
+    });
+}
+
 #[test]
 fn importing_from_an_invalid_html_file_doesnt_fail() {
     // GIVEN
@@ -33,109 +163,686 @@ fn importing_from_an_invalid_html_file_doesnt_fail() {
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 0 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn force_importing_from_an_html_file_with_some_invalid_attrs_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/valid-with-some-invalid-attributes.html",
+        "--ignore-attribute-errors",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn importing_from_a_valid_json_file_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn importing_from_a_json_file_with_only_mandatory_details_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/only-mandatory.json"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 2 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn force_importing_from_a_json_file_with_some_invalid_attrs_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/valid-with-some-invalid-attributes.json",
+        "--ignore-attribute-errors",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn importing_from_a_valid_txt_file_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/valid.txt"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn importing_from_a_firefox_places_database_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/places.sqlite"]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut export_cmd = fx.cmd(["export", "--format", "jsonl"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(export_cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"uri":"https://crates.io/crates/sqlx","title":"sqlx - crates.io: Rust Package Registry","tags":["rust"],"notes":null,"created_at":1739897020,"updated_at":1739897041}
+    {"uri":"https://github.com/dhth/omm","title":"GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line","tags":["tools"],"notes":null,"created_at":1739920615,"updated_at":1739920646}
+    {"uri":"https://github.com/dhth/hours","title":"GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds","tags":["tools"],"notes":null,"created_at":1739920661,"updated_at":1739920670}
+    {"uri":"https://github.com/dhth/bmm","title":"GitHub - dhth/bmm: get to your bookmarks in a flash","tags":["tools"],"notes":null,"created_at":1739920697,"updated_at":1739920739}
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn importing_from_a_firefox_places_database_with_an_explicit_format_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let path = fx.temp_file_path("places-backup.db");
+    std::fs::copy("tests/static/import/places.sqlite", &path)
+        .expect("database should've been copied");
+    let mut cmd = fx.cmd(["import", path.as_str(), "--format", "firefox"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn importing_from_a_firefox_places_database_with_folders_as_tags_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/places.sqlite",
+        "--folders-as-tags",
+    ]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(list_cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "uri": "https://github.com/dhth/bmm",
+        "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
+        "tags": "tools",
+        "notes": null,
+        "created_at": 1739920697,
+        "updated_at": 1739920739
+      },
+      {
+        "uri": "https://github.com/dhth/hours",
+        "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
+        "tags": "productivity,tools",
+        "notes": null,
+        "created_at": 1739920661,
+        "updated_at": 1739920670
+      },
+      {
+        "uri": "https://github.com/dhth/omm",
+        "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
+        "tags": "productivity,tools",
+        "notes": null,
+        "created_at": 1739920615,
+        "updated_at": 1739920646
+      },
+      {
+        "uri": "https://crates.io/crates/sqlx",
+        "title": "sqlx - crates.io: Rust Package Registry",
+        "tags": "productivity,rust,rust-crates",
+        "notes": null,
+        "created_at": 1739897020,
+        "updated_at": 1739897041
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn importing_from_a_buku_database_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/buku/bookmarks.db"]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(list_cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        [
+          {
+            "uri": "https://crates.io/crates/sqlx",
+            "title": "sqlx - crates.io: Rust Package Registry",
+            "tags": "crates,rust",
+            "notes": "the async SQL toolkit bmm uses",
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          },
+          {
+            "uri": "https://github.com/dhth/omm",
+            "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
+            "tags": "productivity,tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          },
+          {
+            "uri": "https://github.com/dhth/hours",
+            "title": null,
+            "tags": null,
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          },
+          {
+            "uri": "https://github.com/dhth/bmm",
+            "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
+            "tags": "tools",
+            "notes": "get to your bookmarks in a flash",
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          }
+        ]
+
+        ----- stderr -----
+        "#);
+    });
+}
+
+#[test]
+fn importing_from_a_buku_database_with_an_explicit_format_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let path = fx.temp_file_path("buku-backup.sqlite");
+    std::fs::copy("tests/static/import/buku/bookmarks.db", &path)
+        .expect("database should've been copied");
+    let mut cmd = fx.cmd(["import", path.as_str(), "--format", "buku"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn importing_from_a_chromium_bookmarks_file_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/chromium/Bookmarks",
+        "--folders-as-tags",
+    ]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 3 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut export_cmd = fx.cmd(["export", "--format", "jsonl"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(export_cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"uri":"https://crates.io/crates/sqlx","title":"sqlx - crates.io: Rust Package Registry","tags":["productivity","rust-crates"],"notes":null,"created_at":1739897020,"updated_at":1739897020}
+    {"uri":"https://github.com/dhth/omm","title":"GitHub - dhth/omm","tags":["productivity"],"notes":null,"created_at":1739920615,"updated_at":1739920615}
+    {"uri":"https://github.com/dhth/bmm","title":"GitHub - dhth/bmm: get to your bookmarks in a flash","tags":[],"notes":null,"created_at":1739920697,"updated_at":1739920800}
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn importing_with_an_explicit_format_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let path = fx.temp_file_path("chromium-bookmarks.bak");
+    std::fs::copy("tests/static/import/chromium/Bookmarks", &path)
+        .expect("bookmarks file should've been copied");
+    let mut cmd = fx.cmd(["import", path.as_str(), "--format", "chromium"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 3 bookmarks
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn importing_from_a_csv_file_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/valid.csv"]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 3 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--format", "delimited"]);
+
+    // WHEN
+    // THEN
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(list_cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        uri,title,tags,notes,created_at,updated_at
+        https://github.com/dhth/bmm,GitHub - dhth/bmm: get to your bookmarks in a flash,tools,,[TIMESTAMP],[TIMESTAMP]
+        https://github.com/dhth/omm,GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line,"productivity,tools",,[TIMESTAMP],[TIMESTAMP]
+        https://github.com/dhth/hours,,,,[TIMESTAMP],[TIMESTAMP]
+
+        ----- stderr -----
+        "#);
+    });
+}
+
+#[test]
+fn importing_from_bmms_delimited_output_works() {
+    // GIVEN
+    let source = Fixture::new();
+    let mut cmd = source.cmd(["import", "tests/static/import/valid.html"]);
+    cmd.output().expect("import command should've run");
+    let mut list_cmd = source.cmd(["list", "--format", "delimited"]);
+    let output = list_cmd.output().expect("list command should've run");
+    let csv_path = source.temp_file_path("bookmarks.csv");
+    std::fs::write(&csv_path, output.stdout).expect("csv file should've been written");
+
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", &csv_path]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--format", "delimited"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(list_cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    uri,title,tags,notes,created_at,updated_at
+    https://github.com/dhth/bmm,GitHub - dhth/bmm: get to your bookmarks in a flash,tools,,1739920697,1739920739
+    https://github.com/dhth/hours,GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds,"productivity,tools",,1739920661,1739920670
+    https://github.com/dhth/omm,GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line,"productivity,tools",,1739920615,1739920646
+    https://crates.io/crates/sqlx,sqlx - crates.io: Rust Package Registry,"crates,rust",,1739897020,1739897041
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn importing_from_a_tsv_file_with_aliased_columns_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/pocket.tsv"]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 2 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut export_cmd = fx.cmd(["export", "--format", "jsonl"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(export_cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"uri":"https://github.com/dhth/omm","title":"GitHub - dhth/omm","tags":["productivity","tools"],"notes":null,"created_at":1739920615,"updated_at":1739920615}
+    {"uri":"https://github.com/dhth/bmm","title":"GitHub - dhth/bmm","tags":["tools"],"notes":null,"created_at":1739920697,"updated_at":1739920697}
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn importing_from_a_pinboard_export_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/pinboard.json",
+        "--format",
+        "pinboard",
+    ]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 2 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut export_cmd = fx.cmd(["export", "--format", "jsonl"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(export_cmd, @r#"
     success: true
     exit_code: 0
     ----- stdout -----
-    imported 0 bookmarks
+    {"uri":"https://github.com/dhth/omm","title":"GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line","tags":["productivity","tools"],"notes":null,"created_at":1739920615,"updated_at":1739920615}
+    {"uri":"https://github.com/dhth/bmm","title":"GitHub - dhth/bmm: get to your bookmarks in a flash","tags":["bookmarks","tools"],"notes":null,"created_at":1739920697,"updated_at":1739920697}
 
     ----- stderr -----
-    ");
+    "#);
 }
 
 #[test]
-fn force_importing_from_an_html_file_with_some_invalid_attrs_works() {
+fn importing_from_a_pocket_html_export_works() {
     // GIVEN
     let fx = Fixture::new();
     let mut cmd = fx.cmd([
         "import",
-        "tests/static/import/valid-with-some-invalid-attributes.html",
-        "--ignore-attribute-errors",
+        "tests/static/import/pocket.html",
+        "--format",
+        "pocket",
     ]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 2 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut export_cmd = fx.cmd(["export", "--format", "jsonl"]);
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(export_cmd, @r#"
     success: true
     exit_code: 0
     ----- stdout -----
-    imported 4 bookmarks
+    {"uri":"https://github.com/dhth/omm","title":"GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line","tags":[],"notes":null,"created_at":1739920615,"updated_at":1739920615}
+    {"uri":"https://github.com/dhth/bmm","title":"GitHub - dhth/bmm: get to your bookmarks in a flash","tags":["bookmarks","tools"],"notes":null,"created_at":1739920697,"updated_at":1739920697}
 
     ----- stderr -----
-    ");
+    "#);
 }
 
 #[test]
-fn importing_from_a_valid_json_file_works() {
+fn importing_from_stdin_works() {
     // GIVEN
     let fx = Fixture::new();
-    let mut cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    let mut cmd = fx.cmd(["import", "-", "--format", "jsonl"]);
+    let input = std::fs::read_to_string("tests/static/import/valid.jsonl")
+        .expect("fixture should've been read");
+    assert_cmd_snapshot!(cmd.pass_stdin(input), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut export_cmd = fx.cmd(["export", "--format", "jsonl"]);
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(export_cmd, @r#"
     success: true
     exit_code: 0
     ----- stdout -----
-    imported 4 bookmarks
+    {"uri":"https://crates.io/crates/sqlx","title":"sqlx - crates.io: Rust Package Registry","tags":["crates","rust"],"notes":null,"created_at":1739897020,"updated_at":1739897041}
+    {"uri":"https://github.com/dhth/omm","title":"GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line","tags":["productivity","tools"],"notes":null,"created_at":1739920615,"updated_at":1739920646}
+    {"uri":"https://github.com/dhth/hours","title":"GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds","tags":["productivity","tools"],"notes":null,"created_at":1739920661,"updated_at":1739920670}
+    {"uri":"https://github.com/dhth/bmm","title":"GitHub - dhth/bmm: get to your bookmarks in a flash","tags":["tools"],"notes":null,"created_at":1739920697,"updated_at":1739920739}
 
     ----- stderr -----
-    ");
+    "#);
 }
 
 #[test]
-fn importing_from_a_json_file_with_only_mandatory_details_works() {
+fn importing_html_from_stdin_works() {
     // GIVEN
     let fx = Fixture::new();
-    let mut cmd = fx.cmd(["import", "tests/static/import/only-mandatory.json"]);
+    let mut cmd = fx.cmd(["import", "-", "--format", "html", "--folders-as-tags"]);
+    let input = std::fs::read_to_string("tests/static/import/valid.html")
+        .expect("fixture should've been read");
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(cmd.pass_stdin(input), @"
     success: true
     exit_code: 0
     ----- stdout -----
-    imported 2 bookmarks
+    imported 4 bookmarks
 
     ----- stderr -----
     ");
 }
 
 #[test]
-fn force_importing_from_a_json_file_with_some_invalid_attrs_works() {
+fn importing_a_large_file_saves_bookmarks_in_chunks() {
     // GIVEN
     let fx = Fixture::new();
-    let mut cmd = fx.cmd([
-        "import",
-        "tests/static/import/valid-with-some-invalid-attributes.json",
-        "--ignore-attribute-errors",
-    ]);
-
-    // WHEN
-    // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    let path = fx.temp_file_path("bookmarks.txt");
+    let content = (1..=2500)
+        .map(|i| format!("https://example.com/{i}\n"))
+        .collect::<String>();
+    std::fs::write(&path, content).expect("file should've been written");
+    let mut cmd = fx.cmd(["import", &path]);
+    assert_cmd_snapshot!(cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
-    imported 4 bookmarks
+    imported 2500 bookmarks
 
     ----- stderr -----
+    saved 1000 bookmarks so far...
+    saved 2000 bookmarks so far...
     ");
+
+    let mut list_cmd = fx.cmd(["list", "--limit", "5000"]);
+
+    // WHEN
+    let output = list_cmd.output().expect("list command should've run");
+
+    // THEN
+    let stdout = String::from_utf8(output.stdout).expect("output should've been valid utf-8");
+    assert_eq!(stdout.lines().count(), 2500);
 }
 
 #[test]
-fn importing_from_a_valid_txt_file_works() {
+fn importing_in_dry_run_mode_works() {
     // GIVEN
     let fx = Fixture::new();
-    let mut cmd = fx.cmd(["import", "tests/static/import/valid.txt"]);
+    let mut cmd = fx.cmd(["import", "tests/static/import/valid.jsonl", "--dry-run"]);
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "uri": "https://crates.io/crates/sqlx",
+        "title": "sqlx - crates.io: Rust Package Registry",
+        "tags": [
+          "crates",
+          "rust"
+        ],
+        "created_at": 1739897020,
+        "updated_at": 1739897041
+      },
+      {
+        "uri": "https://github.com/dhth/omm",
+        "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
+        "tags": [
+          "productivity",
+          "tools"
+        ],
+        "created_at": 1739920615,
+        "updated_at": 1739920646
+      },
+      {
+        "uri": "https://github.com/dhth/hours",
+        "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
+        "tags": [
+          "productivity",
+          "tools"
+        ],
+        "created_at": 1739920661,
+        "updated_at": 1739920670
+      },
+      {
+        "uri": "https://github.com/dhth/bmm",
+        "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
+        "tags": [
+          "tools"
+        ],
+        "created_at": 1739920697,
+        "updated_at": 1739920739
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["list"]);
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(list_cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
-    imported 4 bookmarks
 
     ----- stderr -----
     ");
@@ -176,19 +883,24 @@ fn importing_extends_previously_saved_info() {
     ");
 
     let mut show_cmd = fx.cmd(["show", uri]);
-    assert_cmd_snapshot!(show_cmd, @r"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Bookmark details
-    ---
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(show_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
 
-    Title: GitHub - dhth/bmm: get to your bookmarks in a flash
-    URI  : https://github.com/dhth/bmm
-    Tags : productivity,tools
+        Title  : GitHub - dhth/bmm: get to your bookmarks in a flash
+        URI    : https://github.com/dhth/bmm
+        Tags   : productivity,tools
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
 
-    ----- stderr -----
-    ");
+        ----- stderr -----
+        ");
+    });
 }
 
 #[test]
@@ -226,16 +938,108 @@ fn importing_resets_previously_saved_info_if_requested() {
     ");
 
     let mut show_cmd = fx.cmd(["show", uri]);
-    assert_cmd_snapshot!(show_cmd, @r"
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(show_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : <NOT SET>
+        URI    : https://github.com/dhth/omm
+        Tags   : <NOT SET>
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
+}
+
+#[test]
+fn importing_with_a_json_report_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/valid.json",
+        "--report",
+        "json",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "summary": {
+        "created": 4,
+        "updated": 0,
+        "unchanged": 0,
+        "corrected": 0,
+        "skipped": 0
+      },
+      "bookmarks": [
+        {
+          "uri": "https://crates.io/crates/sqlx",
+          "outcome": "created"
+        },
+        {
+          "uri": "https://github.com/dhth/omm",
+          "outcome": "created"
+        },
+        {
+          "uri": "https://github.com/dhth/hours",
+          "outcome": "created"
+        },
+        {
+          "uri": "https://github.com/dhth/bmm",
+          "outcome": "created"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn importing_with_a_plain_report_lists_unchanged_bookmarks() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/valid.html",
+        "--report",
+        "plain",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
-    Bookmark details
-    ---
+    unchanged  https://crates.io/crates/sqlx
+    unchanged  https://github.com/dhth/omm
+    unchanged  https://github.com/dhth/hours
+    unchanged  https://github.com/dhth/bmm
 
-    Title: <NOT SET>
-    URI  : https://github.com/dhth/omm
-    Tags : <NOT SET>
+    created: 0, updated: 0, unchanged: 4, corrected: 0, skipped: 0
 
     ----- stderr -----
     ");
@@ -347,3 +1151,204 @@ fn importing_from_a_json_file_fails_if_missing_uri_even_when_forced() {
     ]
     "#);
 }
+
+#[test]
+fn importing_from_an_invalid_firefox_places_database_fails() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/not-a-database.sqlite"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't import bookmarks: couldn't read Firefox bookmarks database: error returned from database: (code: 26) file is not a database
+
+    Suggestion: ensure the file is a Firefox places.sqlite database (found in your Firefox profile directory)
+    ");
+}
+
+#[test]
+fn importing_from_an_invalid_buku_database_fails() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "import",
+        "tests/static/import/not-a-database.sqlite",
+        "--format",
+        "buku",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't import bookmarks: couldn't read buku database: error returned from database: (code: 26) file is not a database
+
+    Suggestion: ensure the file is a buku database (usually found at ~/.local/share/buku/bookmarks.db)
+    ");
+}
+
+#[test]
+fn importing_a_large_file_keeps_chunks_saved_before_a_validation_error() {
+    // GIVEN
+    let fx = Fixture::new();
+    let path = fx.temp_file_path("bookmarks.txt");
+    let content = (1..=1500)
+        .map(|i| match i {
+            1200 => "example.com/1200\n".to_string(),
+            i => format!("https://example.com/{i}\n"),
+        })
+        .collect::<String>();
+    std::fs::write(&path, content).expect("file should've been written");
+    let mut cmd = fx.cmd(["import", &path]);
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    saved 1000 bookmarks so far...
+    1000 bookmarks were saved before the error below occurred; they stay saved, and running the same command again once the error is fixed is safe
+    Error: couldn't import bookmarks: there was 1 validation error
+
+    - entry 1200: couldn't parse provided uri value: relative URL without a base
+
+    Possible workaround: running with -i/--ignore-attribute-errors might fix some attribute errors.
+    If a title is too long, it'll will be trimmed, and some invalid tags might be transformed to fit bmm's requirements.
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--limit", "5000"]);
+
+    // WHEN
+    let output = list_cmd.output().expect("list command should've run");
+
+    // THEN
+    let stdout = String::from_utf8(output.stdout).expect("output should've been valid utf-8");
+    assert_eq!(stdout.lines().count(), 1000);
+}
+
+#[test]
+fn importing_a_large_json_file_keeps_chunks_saved_before_a_validation_error() {
+    // GIVEN
+    let fx = Fixture::new();
+    let path = fx.temp_file_path("bookmarks.json");
+    let entries = (1..=1500)
+        .map(|i| match i {
+            1200 => r#"{"uri": "example.com/1200"}"#.to_string(),
+            i => format!(r#"{{"uri": "https://example.com/{i}"}}"#),
+        })
+        .collect::<Vec<_>>();
+    std::fs::write(&path, format!("[\n{}\n]\n", entries.join(",\n")))
+        .expect("file should've been written");
+    let mut cmd = fx.cmd(["import", &path]);
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    saved 1000 bookmarks so far...
+    1000 bookmarks were saved before the error below occurred; they stay saved, and running the same command again once the error is fixed is safe
+    Error: couldn't import bookmarks: there was 1 validation error
+
+    - entry 1200: couldn't parse provided uri value: relative URL without a base
+
+    Possible workaround: running with -i/--ignore-attribute-errors might fix some attribute errors.
+    If a title is too long, it'll will be trimmed, and some invalid tags might be transformed to fit bmm's requirements.
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--limit", "5000"]);
+
+    // WHEN
+    let output = list_cmd.output().expect("list command should've run");
+
+    // THEN
+    let stdout = String::from_utf8(output.stdout).expect("output should've been valid utf-8");
+    assert_eq!(stdout.lines().count(), 1000);
+}
+
+#[test]
+fn importing_from_stdin_without_a_format_fails() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "-"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd.pass_stdin("https://github.com/dhth/bmm\n"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't import bookmarks: the format of the input needs to be provided via --format when reading from stdin
+
+    Suggestion: specify the format, eg. "curl -s https://example.com/bookmarks.html | bmm import - --format html"
+    "#);
+}
+
+#[test]
+fn importing_a_database_from_stdin_fails() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "-", "--format", "firefox"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd.pass_stdin(""), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't import bookmarks: firefox input can't be read from stdin; provide the path to the file instead
+    ");
+}
+
+#[test]
+fn importing_from_a_file_with_an_unsupported_extension_fails() {
+    // GIVEN
+    let fx = Fixture::new();
+    let path = fx.temp_file_path("chromium-bookmarks.bak");
+    std::fs::copy("tests/static/import/chromium/Bookmarks", &path)
+        .expect("bookmarks file should've been copied");
+    let mut cmd = fx.cmd(["import", path.as_str()]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't import bookmarks: file format "bak" not supported (supported formats: ["buku", "chromium", "csv", "firefox", "html", "json", "jsonl", "pinboard", "pocket", "tsv", "txt"])
+    "#);
+}
+
+#[test]
+fn importing_from_a_csv_file_without_a_uri_column_fails() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["import", "tests/static/import/no-uri-column.csv"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't import bookmarks: couldn't find a column for URIs in the header row (expected one of: ["uri", "url", "link", "href"])
+    "#);
+}
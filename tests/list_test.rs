@@ -1,6 +1,6 @@
 mod common;
 
-use common::Fixture;
+use common::{Fixture, TIMESTAMP_FILTERS};
 use insta_cmd::assert_cmd_snapshot;
 
 const URI_ONE: &str = "https://github.com/dhth/bmm";
@@ -121,7 +121,7 @@ fn listing_by_uri_treats_like_metacharacters_literally() {
 }
 
 #[test]
-fn listing_bookmarks_fetches_all_data_for_each_bookmark() {
+fn listing_bookmarks_by_regex_works() {
     // GIVEN
     let fx = Fixture::new();
     let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
@@ -134,34 +134,80 @@ fn listing_bookmarks_fetches_all_data_for_each_bookmark() {
     ----- stderr -----
     ");
 
-    let mut cmd = fx.cmd(["list", "--tags", "tools", "--format", "json"]);
+    let mut cmd = fx.cmd([
+        "list",
+        "--regex",
+        "--uri",
+        r"^https://github\.com/dhth/[a-z]mm$",
+        "--title",
+        "(?i)task manager|toolkit",
+    ]);
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r#"
+    assert_cmd_snapshot!(cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
-    [
-      {
-        "uri": "https://github.com/dhth/omm",
-        "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
-        "tags": "productivity,tools"
-      },
-      {
-        "uri": "https://github.com/dhth/hours",
-        "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
-        "tags": "productivity,tools"
-      },
-      {
-        "uri": "https://github.com/dhth/bmm",
-        "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
-        "tags": "tools"
-      }
-    ]
+    https://github.com/dhth/omm
 
     ----- stderr -----
-    "#);
+    ");
+}
+
+#[test]
+fn listing_bookmarks_fetches_all_data_for_each_bookmark() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--tags", "tools", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        [
+          {
+            "uri": "https://github.com/dhth/omm",
+            "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
+            "tags": "productivity,tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          },
+          {
+            "uri": "https://github.com/dhth/hours",
+            "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
+            "tags": "productivity,tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          },
+          {
+            "uri": "https://github.com/dhth/bmm",
+            "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
+            "tags": "tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          }
+        ]
+
+        ----- stderr -----
+        "#);
+    });
 }
 
 #[test]
@@ -182,28 +228,64 @@ fn listing_bookmarks_in_json_format_works() {
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r#"
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        [
+          {
+            "uri": "https://github.com/dhth/hours",
+            "title": null,
+            "tags": null,
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]"
+          }
+        ]
+
+        ----- stderr -----
+        "#);
+    });
+}
+
+#[test]
+fn listing_bookmarks_in_delimited_format_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
     success: true
     exit_code: 0
     ----- stdout -----
-    [
-      {
-        "uri": "https://github.com/dhth/hours",
-        "title": null,
-        "tags": null
-      }
-    ]
+    imported 4 bookmarks
 
     ----- stderr -----
-    "#);
+    ");
+
+    let mut cmd = fx.cmd(["list", "--uri", "hours", "--format", "delimited"]);
+
+    // WHEN
+    // THEN
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        uri,title,tags,notes,created_at,updated_at
+        https://github.com/dhth/hours,GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds,"productivity,tools",,[TIMESTAMP],[TIMESTAMP]
+
+        ----- stderr -----
+        "#);
+    });
 }
 
 #[test]
-fn listing_bookmarks_in_delimited_format_works() {
+fn listing_bookmarks_added_in_a_time_range_works() {
     // GIVEN
     let fx = Fixture::new();
-    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
-    assert_cmd_snapshot!(import_cmd, @r"
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -212,17 +294,513 @@ fn listing_bookmarks_in_delimited_format_works() {
     ----- stderr -----
     ");
 
-    let mut cmd = fx.cmd(["list", "--uri", "hours", "--format", "delimited"]);
+    let mut cmd = fx.cmd([
+        "list",
+        "--since",
+        "2025-02-18T23:00:00Z",
+        "--until",
+        "2025-02-18T23:18:17Z",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/hours
+    https://github.com/dhth/omm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_modified_in_a_time_range_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--since", "1739920670", "--modified"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/bmm
+    https://github.com/dhth/hours
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_added_relative_to_now_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut save_cmd = fx.cmd(["save", "https://github.com/dhth/outtasync"]);
+    assert_cmd_snapshot!(save_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--since", "1d"]);
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r#"
+    assert_cmd_snapshot!(cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
-    uri,title,tags
-    https://github.com/dhth/hours,GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds,"productivity,tools"
+    https://github.com/dhth/outtasync
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_sorted_by_an_attribute_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--sort", "tag-count"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://crates.io/crates/sqlx
+    https://github.com/dhth/omm
+    https://github.com/dhth/hours
+    https://github.com/dhth/bmm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_in_a_given_order_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--sort", "uri", "--order", "desc"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/omm
+    https://github.com/dhth/hours
+    https://github.com/dhth/bmm
+    https://crates.io/crates/sqlx
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn paging_through_listed_bookmarks_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut first_page_cmd = fx.cmd(["list", "--sort", "uri", "--limit", "3", "--page", "1"]);
+    assert_cmd_snapshot!(first_page_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://crates.io/crates/sqlx
+    https://github.com/dhth/bmm
+    https://github.com/dhth/hours
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--sort", "uri", "--limit", "3", "--page", "2"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/omm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_with_an_offset_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--sort", "created", "--offset", "2"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/omm
+    https://crates.io/crates/sqlx
 
     ----- stderr -----
-    "#);
+    ");
+}
+
+#[test]
+fn listing_bookmarks_with_any_of_the_tags_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--tags", "rust,productivity", "--any-tag"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/hours
+    https://github.com/dhth/omm
+    https://crates.io/crates/sqlx
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_without_excluded_tags_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--exclude-tags", "productivity"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/bmm
+    https://crates.io/crates/sqlx
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_by_domain_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--domain", "github.com"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/bmm
+    https://github.com/dhth/hours
+    https://github.com/dhth/omm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_untagged_bookmarks_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+    let mut save_cmd = fx.cmd(["save", "https://example.com"]);
+    assert_cmd_snapshot!(save_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--untagged"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://example.com
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_with_combined_filters_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "list",
+        "--domain",
+        "github.com",
+        "--tags",
+        "tools",
+        "--exclude-tags",
+        "productivity",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/bmm
+
+    ----- stderr -----
+    ");
+}
+
+//------------//
+//  FAILURES  //
+//------------//
+
+#[test]
+fn listing_bookmarks_fails_for_an_invalid_time_bound() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["list", "--since", "yesterday"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'yesterday' for '--since <TIME>': expected a date (eg. 2025-02-18), an RFC 3339 date-time, a unix timestamp, or a relative time (eg. 12h, 3d, 2w)
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn listing_bookmarks_fails_if_both_offset_and_page_are_provided() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["list", "--offset", "10", "--page", "2"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--offset <INTEGER>' cannot be used with '--page <INTEGER>'
+
+    Usage: bmm list --offset <INTEGER> --db-path <STRING>
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn listing_bookmarks_fails_for_page_zero() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["list", "--page", "0"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '0' for '--page <INTEGER>': 0 is not in 1..=4294967295
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn listing_untagged_bookmarks_fails_if_tags_are_provided() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["list", "--untagged", "--tags", "tools"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--untagged' cannot be used with '--tags <STRING,STRING..>'
+
+    Usage: bmm list --untagged --db-path <STRING>
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn listing_bookmarks_fails_if_any_tag_is_provided_without_tags() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["list", "--any-tag"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --tags <STRING,STRING..>
+
+    Usage: bmm list --tags <STRING,STRING..> --any-tag --db-path <STRING>
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn listing_bookmarks_fails_for_an_invalid_regex() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["list", "--regex", "--uri", "github.com/(dhth"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't list bookmarks: invalid regex: regex parse error:
+        github.com/(dhth
+                   ^
+    error: unclosed group
+    ");
 }
@@ -1,6 +1,6 @@
 mod common;
 
-use common::Fixture;
+use common::{Fixture, TIMESTAMP_FILTERS};
 use insta_cmd::assert_cmd_snapshot;
 
 const URI_ONE: &str = "https://github.com/dhth/bmm";
@@ -111,19 +111,24 @@ fn saving_multiple_bookmarks_extends_previously_saved_tags() {
     ");
 
     let mut show_cmd = fx.cmd(["show", URI_ONE]);
-    assert_cmd_snapshot!(show_cmd, @r"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Bookmark details
-    ---
-
-    Title: bmm's github page
-    URI  : https://github.com/dhth/bmm
-    Tags : productivity,tools
-
-    ----- stderr -----
-    ");
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(show_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : bmm's github page
+        URI    : https://github.com/dhth/bmm
+        Tags   : productivity,tools
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
 }
 
 #[test]
@@ -168,19 +173,24 @@ fn saving_multiple_bookmarks_resets_previously_saved_tags_if_requested() {
     ");
 
     let mut show_cmd = fx.cmd(["show", URI_ONE]);
-    assert_cmd_snapshot!(show_cmd, @r"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Bookmark details
-    ---
-
-    Title: bmm's github page
-    URI  : https://github.com/dhth/bmm
-    Tags : tools
-
-    ----- stderr -----
-    ");
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(show_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : bmm's github page
+        URI    : https://github.com/dhth/bmm
+        Tags   : tools
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
 }
 
 #[test]
@@ -221,6 +231,73 @@ fn force_saving_multiple_bookmarks_with_invalid_tags_works() {
     ");
 }
 
+#[test]
+fn saving_a_large_number_of_bookmarks_from_stdin_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["save-all", "--stdin"]);
+    let input = (1..=1500)
+        .map(|i| format!("https://example.com/{i}\n"))
+        .collect::<String>();
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd.pass_stdin(input), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    saved 1500 bookmarks
+
+    ----- stderr -----
+    saved 1000 bookmarks so far...
+    ");
+}
+
+#[test]
+fn saving_multiple_bookmarks_with_a_report_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["save-all", URI_ONE, URI_TWO, "--tags", "tag1"]);
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    saved 2 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "save-all",
+        URI_ONE,
+        URI_TWO,
+        URI_THREE,
+        "--tags",
+        "tag1,invalid tag",
+        "--ignore-attribute-errors",
+        "--report",
+        "plain",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    updated    https://github.com/dhth/bmm
+                 - tag "invalid tag" changed to "invalid-tag"
+    updated    https://github.com/dhth/omm
+                 - tag "invalid tag" changed to "invalid-tag"
+    created    https://github.com/dhth/hours
+                 - tag "invalid tag" changed to "invalid-tag"
+
+    created: 1, updated: 2, unchanged: 0, corrected: 3, skipped: 0
+
+    ----- stderr -----
+    "#);
+}
+
 //------------//
 //  FAILURES  //
 //------------//
@@ -1,6 +1,6 @@
 mod common;
 
-use common::Fixture;
+use common::{Fixture, TIMESTAMP_FILTERS};
 use insta_cmd::assert_cmd_snapshot;
 
 const URI_ONE: &str = "https://github.com/dhth/bmm";
@@ -60,15 +60,17 @@ fn saving_a_new_bookmark_with_title_and_tags_works() {
     ");
 
     let mut list_cmd = fx.cmd(["list", "--format", "delimited"]);
-    assert_cmd_snapshot!(list_cmd, @r#"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    uri,title,tags
-    https://github.com/dhth/bmm,bmm's github page,"productivity,tools"
-
-    ----- stderr -----
-    "#);
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(list_cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        uri,title,tags,notes,created_at,updated_at
+        https://github.com/dhth/bmm,bmm's github page,"productivity,tools",,[TIMESTAMP],[TIMESTAMP]
+
+        ----- stderr -----
+        "#);
+    });
 }
 
 #[test]
@@ -104,15 +106,17 @@ fn extending_tags_for_a_saved_bookmark_works() {
     ");
 
     let mut list_cmd = fx.cmd(["list", "--format", "delimited"]);
-    assert_cmd_snapshot!(list_cmd, @r#"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    uri,title,tags
-    https://github.com/dhth/bmm,bmm's github page,"bookmarks,productivity,tools"
-
-    ----- stderr -----
-    "#);
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(list_cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        uri,title,tags,notes,created_at,updated_at
+        https://github.com/dhth/bmm,bmm's github page,"bookmarks,productivity,tools",,[TIMESTAMP],[TIMESTAMP]
+
+        ----- stderr -----
+        "#);
+    });
 }
 
 #[test]
@@ -154,27 +158,72 @@ fn resetting_properties_on_bookmark_update_works() {
     ");
 
     let mut list_cmd = fx.cmd(["list", "--format", "delimited"]);
-    assert_cmd_snapshot!(list_cmd, @r#"
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(list_cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        uri,title,tags,notes,created_at,updated_at
+        https://github.com/dhth/bmm,,"bookmarks,cli",,[TIMESTAMP],[TIMESTAMP]
+
+        ----- stderr -----
+        "#);
+    });
+}
+
+#[test]
+fn force_saving_a_new_bookmark_with_a_long_title_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let title = "a".repeat(501);
+    let mut cmd = fx.cmd([
+        "save",
+        URI_ONE,
+        "--title",
+        title.as_str(),
+        "--ignore-attribute-errors",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r"
     success: true
     exit_code: 0
     ----- stdout -----
-    uri,title,tags
-    https://github.com/dhth/bmm,,"bookmarks,cli"
 
     ----- stderr -----
-    "#);
+    ");
+
+    let mut show_cmd = fx.cmd(["show", URI_ONE]);
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(show_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
+        URI    : https://github.com/dhth/bmm
+        Tags   : <NOT SET>
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
 }
 
 #[test]
-fn force_saving_a_new_bookmark_with_a_long_title_works() {
+fn force_saving_a_new_bookmark_with_invalid_tags_works() {
     // GIVEN
     let fx = Fixture::new();
-    let title = "a".repeat(501);
     let mut cmd = fx.cmd([
         "save",
         URI_ONE,
-        "--title",
-        title.as_str(),
+        "--tags",
+        "tag1,invalid tag, another    invalid\t\ttag ",
         "--ignore-attribute-errors",
     ]);
 
@@ -189,54 +238,270 @@ fn force_saving_a_new_bookmark_with_a_long_title_works() {
     ");
 
     let mut show_cmd = fx.cmd(["show", URI_ONE]);
-    assert_cmd_snapshot!(show_cmd, @r"
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(show_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : <NOT SET>
+        URI    : https://github.com/dhth/bmm
+        Tags   : another-invalid-tag,invalid-tag,tag1
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
+}
+
+#[test]
+fn saving_a_bookmark_with_notes_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "save",
+        URI_ONE,
+        "--notes",
+        "handy for getting to bookmarks quickly",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut show_cmd = fx.cmd(["show", URI_ONE]);
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(show_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : <NOT SET>
+        URI    : https://github.com/dhth/bmm
+        Tags   : <NOT SET>
+        Notes  : handy for getting to bookmarks quickly
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
+}
+
+#[test]
+fn updating_a_bookmark_keeps_its_notes_unless_reset() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut create_cmd = fx.cmd(["save", URI_ONE, "--notes", "worth a read"]);
+    assert_cmd_snapshot!(create_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut update_cmd = fx.cmd(["save", URI_ONE, "--tags", "tools"]);
+    assert_cmd_snapshot!(update_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--format", "delimited"]);
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(list_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        uri,title,tags,notes,created_at,updated_at
+        https://github.com/dhth/bmm,,tools,worth a read,[TIMESTAMP],[TIMESTAMP]
+
+        ----- stderr -----
+        ");
+    });
+
+    let mut reset_cmd = fx.cmd([
+        "save",
+        URI_ONE,
+        "--tags",
+        "tools",
+        "--reset-missing-details",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(reset_cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
-    Bookmark details
-    ---
 
-    Title: aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
-    URI  : https://github.com/dhth/bmm
-    Tags : <NOT SET>
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["list", "--format", "delimited"]);
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(list_cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        uri,title,tags,notes,created_at,updated_at
+        https://github.com/dhth/bmm,,tools,,[TIMESTAMP],[TIMESTAMP]
+
+        ----- stderr -----
+        ");
+    });
+}
+
+#[test]
+fn saving_a_bookmark_with_suggestions_lists_suggested_tags() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["save", "https://github.com/dhth/ecsv", "--suggest"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    suggested tags:
+      - tools (on 3 of 3 bookmarks from the same domain)
+      - productivity (on 2 of 3 bookmarks from the same domain)
 
     ----- stderr -----
     ");
 }
 
 #[test]
-fn force_saving_a_new_bookmark_with_invalid_tags_works() {
+fn suggestions_when_saving_a_bookmark_leave_out_its_tags() {
     // GIVEN
     let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
     let mut cmd = fx.cmd([
         "save",
-        URI_ONE,
+        "https://github.com/dhth/ecsv",
         "--tags",
-        "tag1,invalid tag, another    invalid\t\ttag ",
-        "--ignore-attribute-errors",
+        "tools",
+        "--suggest",
     ]);
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
+    suggested tags:
+      - productivity (on 2 of 3 bookmarks from the same domain)
 
     ----- stderr -----
     ");
+}
 
-    let mut show_cmd = fx.cmd(["show", URI_ONE]);
-    assert_cmd_snapshot!(show_cmd, @r"
+#[test]
+fn saving_a_bookmark_with_suggestions_reports_when_there_are_none() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["save", "https://docs.rs/sqlx", "--suggest"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    no tags to suggest
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn saving_a_bookmark_via_the_editor_with_suggestions_prefills_tags() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
     success: true
     exit_code: 0
     ----- stdout -----
-    Bookmark details
-    ---
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
 
-    Title: <NOT SET>
-    URI  : https://github.com/dhth/bmm
-    Tags : another-invalid-tag,invalid-tag,tag1
+    // an editor that leaves the file as is saves the pre-filled details
+    let mut cmd = fx.cmd([
+        "save",
+        "https://github.com/dhth/ecsv",
+        "--editor",
+        "--suggest",
+    ]);
+    cmd.env("BMM_EDITOR", "true");
+
+    // WHEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // THEN
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    crates (1 bookmark)
+    productivity (3 bookmarks)
+    rust (1 bookmark)
+    tools (4 bookmarks)
 
     ----- stderr -----
     ");
@@ -287,7 +552,7 @@ fn saving_a_new_bookmark_with_an_invalid_tag_fails() {
     ----- stdout -----
 
     ----- stderr -----
-    Error: couldn't save bookmark: tags ["invalid tag", " another    invalid\t\ttag "] are invalid (valid regex: ^[a-zA-Z0-9_-]{1,30}$)
+    Error: couldn't save bookmark: tags ["invalid tag", " another    invalid\t\ttag "] are invalid (valid regex: ^[a-zA-Z0-9_-]{1,30}(/[a-zA-Z0-9_-]{1,30}){0,4}$)
 
     Possible workaround: running with -i/--ignore-attribute-errors might fix some attribute errors.
     If a title is too long, it'll will be trimmed, and some invalid tags might be transformed to fit bmm's requirements.
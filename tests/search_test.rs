@@ -1,6 +1,6 @@
 mod common;
 
-use common::Fixture;
+use common::{Fixture, TIMESTAMP_FILTERS};
 use insta_cmd::assert_cmd_snapshot;
 
 //-------------//
@@ -81,13 +81,13 @@ fn searching_bookmarks_by_tags_works() {
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(cmd, @"
     success: true
     exit_code: 0
     ----- stdout -----
-    https://github.com/dhth/omm
-    https://github.com/dhth/hours
     https://github.com/dhth/bmm
+    https://github.com/dhth/hours
+    https://github.com/dhth/omm
 
     ----- stderr -----
     ");
@@ -111,30 +111,44 @@ fn search_shows_all_details_for_each_bookmark() {
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r#"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    [
-      {
-        "uri": "https://github.com/dhth/omm",
-        "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
-        "tags": "productivity,tools"
-      },
-      {
-        "uri": "https://github.com/dhth/hours",
-        "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
-        "tags": "productivity,tools"
-      },
-      {
-        "uri": "https://github.com/dhth/bmm",
-        "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
-        "tags": "tools"
-      }
-    ]
-
-    ----- stderr -----
-    "#);
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @r#"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        [
+          {
+            "uri": "https://github.com/dhth/bmm",
+            "title": "GitHub - dhth/bmm: get to your bookmarks in a flash",
+            "tags": "tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]",
+            "snippet": "**tools**"
+          },
+          {
+            "uri": "https://github.com/dhth/hours",
+            "title": "GitHub - dhth/hours: A no-frills time tracking toolkit for command line nerds",
+            "tags": "productivity,tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]",
+            "snippet": "productivity **tools**"
+          },
+          {
+            "uri": "https://github.com/dhth/omm",
+            "title": "GitHub - dhth/omm: on-my-mind: a keyboard-driven task manager for the command line",
+            "tags": "productivity,tools",
+            "notes": null,
+            "created_at": "[TIMESTAMP]",
+            "updated_at": "[TIMESTAMP]",
+            "snippet": "productivity **tools**"
+          }
+        ]
+
+        ----- stderr -----
+        "#);
+    });
 }
 
 #[test]
@@ -172,6 +186,385 @@ fn searching_bookmarks_by_multiple_terms_works() {
     ");
 }
 
+#[test]
+fn searching_bookmarks_by_notes_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut save_cmd = fx.cmd([
+        "save",
+        "https://github.com/dhth/bmm",
+        "--notes",
+        "recommended by a colleague",
+    ]);
+    assert_cmd_snapshot!(save_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+    let mut save_cmd = fx.cmd(["save", "https://github.com/dhth/omm"]);
+    assert_cmd_snapshot!(save_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "colleague"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/bmm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn searching_bookmarks_in_a_time_range_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "github", "--until", "2025-02-18T23:17:00Z"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/omm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn searching_bookmarks_sorted_by_title_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.html"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "github", "--sort", "title", "--offset", "1"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/hours
+    https://github.com/dhth/omm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn searching_bookmarks_ranks_results_by_relevance() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut save_cmd = fx.cmd([
+        "save",
+        "https://example.com/notes",
+        "--notes",
+        "has a few examples of rust code",
+    ]);
+    assert_cmd_snapshot!(save_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+    let mut save_cmd = fx.cmd([
+        "save",
+        "https://example.com/title",
+        "--title",
+        "learning rust",
+    ]);
+    assert_cmd_snapshot!(save_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "rust"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://example.com/title
+    https://example.com/notes
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn searching_bookmarks_matches_the_start_of_words() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "produc", "track"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/hours
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn searching_bookmarks_with_snippets_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "command", "line", "--snippets"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/hours
+        ...A no-frills time tracking toolkit for **command** **line** nerds
+    https://github.com/dhth/omm
+        ...a keyboard-driven task manager for the **command** **line**
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn searching_bookmarks_with_qualifiers_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "search",
+        r#"tag:productivity -tag:rust site:github.com title:"command line""#,
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/omm
+    https://github.com/dhth/hours
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn searching_bookmarks_with_or_groups_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "(tag:rust OR flash OR track) -hours"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/bmm
+    https://crates.io/crates/sqlx
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn searching_bookmarks_by_time_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "before:2000-01-01 OR tag:crates"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://crates.io/crates/sqlx
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn fuzzy_searching_bookmarks_matches_abbreviations() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "--fuzzy", "sqlx crtio"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://crates.io/crates/sqlx
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn fuzzy_searching_bookmarks_ranks_results_by_score() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["search", "--fuzzy", "dhthrs"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/hours
+    https://github.com/dhth/bmm
+    https://github.com/dhth/omm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn fuzzy_searching_bookmarks_respects_limit_and_offset() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "search", "--fuzzy", "dhthrs", "--limit", "1", "--offset", "1",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/bmm
+
+    ----- stderr -----
+    ");
+}
+
 //------------//
 //  FAILURES  //
 //------------//
@@ -191,18 +584,21 @@ fn searching_bookmarks_fails_if_search_terms_exceeds_limit() {
     ");
 
     let mut cmd = fx.cmd(["search"]);
-    cmd.args((1..=11).map(|i| format!("term-{i}")).collect::<Vec<_>>());
+    cmd.args((1..=33).map(|i| format!("term-{i}")).collect::<Vec<_>>());
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(cmd, @r#"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Error: couldn't search bookmarks: search query is invalid: too many terms (maximum allowed: 10)
-    ");
+    Error: couldn't search bookmarks: search query is invalid: too many conditions (maximum allowed: 32)
+
+    Suggestion: combine terms, quoted phrases, and qualifiers (tag:, title:, uri:, site:, before:, after:), eg.
+        bmm search 'rust tag:cli -tag:old (site:github.com OR title:"command line")'
+    "#);
 }
 
 #[test]
@@ -223,12 +619,142 @@ fn searching_bookmarks_fails_if_search_query_empty() {
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(cmd, @r#"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
     Error: couldn't search bookmarks: search query is invalid: query is empty
+
+    Suggestion: combine terms, quoted phrases, and qualifiers (tag:, title:, uri:, site:, before:, after:), eg.
+        bmm search 'rust tag:cli -tag:old (site:github.com OR title:"command line")'
+    "#);
+}
+
+#[test]
+fn searching_bookmarks_in_a_time_range_fails_in_tui_mode() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["search", "github", "--since", "1w", "--tui"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--since <TIME>' cannot be used with '--tui'
+
+    Usage: bmm search --since <TIME> --db-path <STRING> <TERM>...
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn searching_bookmarks_by_modification_time_fails_in_tui_mode() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["search", "github", "--modified", "--tui"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--modified' cannot be used with '--tui'
+
+    Usage: bmm search --modified --db-path <STRING> <TERM>...
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn searching_bookmarks_with_snippets_fails_in_tui_mode() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["search", "rust", "--snippets", "--tui"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--snippets' cannot be used with '--tui'
+
+    Usage: bmm search --snippets --db-path <STRING> <TERM>...
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+fn searching_bookmarks_fails_for_an_invalid_query() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["search", "rust", r#"title:"command line"#]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't search bookmarks: search query is invalid: quote at position 12 is never closed
+
+    Suggestion: combine terms, quoted phrases, and qualifiers (tag:, title:, uri:, site:, before:, after:), eg.
+        bmm search 'rust tag:cli -tag:old (site:github.com OR title:"command line")'
+    "#);
+}
+
+#[test]
+fn fuzzy_searching_bookmarks_fails_if_query_empty() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["search", "--fuzzy", " "]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't search bookmarks: fuzzy search query is empty
+    ");
+}
+
+#[test]
+fn fuzzy_searching_bookmarks_fails_with_sort() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["search", "--fuzzy", "--sort", "title", "rust"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--fuzzy' cannot be used with '--sort <FIELD>'
+
+    Usage: bmm search --fuzzy --db-path <STRING> <TERM>...
+
+    For more information, try '--help'.
     ");
 }
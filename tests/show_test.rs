@@ -1,6 +1,6 @@
 mod common;
 
-use common::Fixture;
+use common::{Fixture, TIMESTAMP_FILTERS};
 use insta_cmd::assert_cmd_snapshot;
 
 const URI: &str = "https://crates.io/crates/sqlx";
@@ -27,19 +27,24 @@ fn showing_bookmarks_details_works() {
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Bookmark details
-    ---
-
-    Title: sqlx - crates.io: Rust Package Registry
-    URI  : https://crates.io/crates/sqlx
-    Tags : crates,rust
-
-    ----- stderr -----
-    ");
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : sqlx - crates.io: Rust Package Registry
+        URI    : https://crates.io/crates/sqlx
+        Tags   : crates,rust
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
 }
 
 #[test]
@@ -59,19 +64,24 @@ fn show_details_output_marks_attributes_that_are_missing() {
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    Bookmark details
-    ---
-
-    Title: <NOT SET>
-    URI  : https://crates.io/crates/sqlx
-    Tags : <NOT SET>
-
-    ----- stderr -----
-    ");
+    insta::with_settings!({filters => TIMESTAMP_FILTERS.to_vec()}, {
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Bookmark details
+        ---
+
+        Title  : <NOT SET>
+        URI    : https://crates.io/crates/sqlx
+        Tags   : <NOT SET>
+        Notes  : <NOT SET>
+        Added  : [DATE-TIME]
+        Updated: [DATE-TIME]
+
+        ----- stderr -----
+        ");
+    });
 }
 
 //------------//
@@ -147,12 +147,1515 @@ fn renaming_tags_works() {
     ");
 }
 
+#[test]
+fn listing_nested_tags_as_a_tree_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/nested-tags.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "list", "--tree"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    lang (3 bookmarks)
+    ├── go (1 bookmark)
+    └── rust (2 bookmarks)
+        └── async (1 bookmark)
+    tools (2 bookmarks)
+    └── cli (1 bookmark)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_nested_tags_as_a_tree_in_json_format_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/nested-tags.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "list", "--tree", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "lang",
+        "num_bookmarks": 3,
+        "children": [
+          {
+            "name": "lang/go",
+            "num_bookmarks": 1
+          },
+          {
+            "name": "lang/rust",
+            "num_bookmarks": 2,
+            "children": [
+              {
+                "name": "lang/rust/async",
+                "num_bookmarks": 1
+              }
+            ]
+          }
+        ]
+      },
+      {
+        "name": "tools",
+        "num_bookmarks": 2,
+        "children": [
+          {
+            "name": "tools/cli",
+            "num_bookmarks": 1
+          }
+        ]
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn listing_nested_tags_as_a_tree_in_delimited_format_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/nested-tags.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "list", "--tree", "--format", "delimited"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    name,num_bookmarks
+    lang,3
+    lang/go,1
+    lang/rust,2
+    lang/rust/async,1
+    tools,2
+    tools/cli,1
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_bookmarks_by_a_parent_tag_matches_nested_tags() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/nested-tags.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--tags", "lang/rust"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://www.rust-lang.org
+    https://tokio.rs
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn renaming_a_tag_subtree_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/nested-tags.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "rename", "--subtree", "lang", "programming"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["tags", "list"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    programming/go
+    programming/rust
+    programming/rust/async
+    tools
+    tools/cli
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn adding_and_listing_tag_aliases_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut add_cmd = fx.cmd(["tags", "alias", "add", "util", "tools"]);
+    assert_cmd_snapshot!(add_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+    let mut add_another_cmd = fx.cmd(["tags", "alias", "add", "prod", "productivity"]);
+    assert_cmd_snapshot!(add_another_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "alias", "list"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    prod -> productivity
+    util -> tools
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn listing_tag_aliases_in_json_format_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut alias_cmd = fx.cmd(["tags", "alias", "add", "util", "tools"]);
+    assert_cmd_snapshot!(alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "alias", "list", "--format", "json"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "alias": "util",
+        "tag": "tools"
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn removing_tag_aliases_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut add_cmd = fx.cmd(["tags", "alias", "add", "util", "tools"]);
+    assert_cmd_snapshot!(add_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+    let mut add_another_cmd = fx.cmd(["tags", "alias", "add", "prod", "productivity"]);
+    assert_cmd_snapshot!(add_another_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "alias", "remove", "util"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    removed 1 alias
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["tags", "alias", "list"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    prod -> productivity
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn saving_a_bookmark_resolves_tag_aliases() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut alias_cmd = fx.cmd(["tags", "alias", "add", "util", "tools"]);
+    assert_cmd_snapshot!(alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "save",
+        "https://github.com/dhth/bmm",
+        "--tags",
+        "util,tools,cli",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["tags", "list"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cli
+    tools
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn filtering_bookmarks_by_a_tag_alias_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+    let mut alias_cmd = fx.cmd(["tags", "alias", "add", "util", "tools"]);
+    assert_cmd_snapshot!(alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["list", "--tags", "util"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://github.com/dhth/omm
+    https://github.com/dhth/hours
+    https://github.com/dhth/bmm
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn applying_tag_aliases_folds_existing_tags_into_canonical_ones() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+    let mut alias_cmd = fx.cmd(["tags", "alias", "add", "productivity", "tools"]);
+    assert_cmd_snapshot!(alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+    let mut another_alias_cmd = fx.cmd(["tags", "alias", "add", "crates", "lang/rust"]);
+    assert_cmd_snapshot!(another_alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "alias", "apply"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    folded 2 tags into their canonical tags
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    lang/rust (1 bookmark)
+    rust (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn adding_tags_to_bookmarks_matching_a_query_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "add",
+        "cli,dhth",
+        "--where",
+        "uri:github.com/dhth -uri:bmm",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd.pass_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Will add tags "cli", "dhth" to 2 bookmarks:
+      - https://github.com/dhth/hours
+      - https://github.com/dhth/omm
+
+    Type "y" to confirm.
+    updated 2 bookmarks
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cli (2 bookmarks)
+    crates (1 bookmark)
+    dhth (2 bookmarks)
+    productivity (2 bookmarks)
+    rust (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn adding_tags_skips_bookmarks_that_already_have_them() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "add",
+        "productivity",
+        "--where",
+        "tag:tools",
+        "--yes",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    updated 1 bookmark
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    crates (1 bookmark)
+    productivity (3 bookmarks)
+    rust (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn removing_tags_from_bookmarks_matching_a_query_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "remove",
+        "productivity,rust",
+        "--where",
+        "uri:omm OR title:crates.io",
+        "--yes",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    updated 2 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    crates (1 bookmark)
+    productivity (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn changing_tags_in_bulk_in_dry_run_mode_doesnt_change_anything() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "remove",
+        "tools",
+        "--where",
+        "tag:productivity",
+        "--dry-run",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Would remove tag "tools" from 2 bookmarks:
+      - https://github.com/dhth/hours
+      - https://github.com/dhth/omm
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    crates (1 bookmark)
+    productivity (2 bookmarks)
+    rust (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn changing_tags_in_bulk_can_be_cancelled() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "add", "archived", "--where", "tag:tools"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd.pass_stdin("n\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Will add tag "archived" to 3 bookmarks:
+      - https://github.com/dhth/bmm
+      - https://github.com/dhth/hours
+      - https://github.com/dhth/omm
+
+    Type "y" to confirm.
+    cancelled
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    crates (1 bookmark)
+    productivity (2 bookmarks)
+    rust (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn changing_tags_in_bulk_reports_when_nothing_would_change() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "remove", "rust", "--where", "tag:tools", "--yes"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    no bookmarks would change
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn merging_tags_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "merge", "crates", "productivity", "--into", "tools"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd.pass_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Will merge 2 tags into "tools":
+      - crates (1 bookmark) -> tools
+      - productivity (2 bookmarks) -> tools
+
+    Resulting tags:
+      - tools (4 bookmarks)
+
+    Number of tags: 4 -> 2
+
+    Type "y" to confirm.
+    merged 2 tags into "tools"
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    rust (1 bookmark)
+    tools (4 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn merging_tags_carries_their_aliases_over() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+    let mut alias_cmd = fx.cmd(["tags", "alias", "add", "prod", "productivity"]);
+    assert_cmd_snapshot!(alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+    let mut another_alias_cmd = fx.cmd(["tags", "alias", "add", "cr", "crates"]);
+    assert_cmd_snapshot!(another_alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut merge_cmd = fx.cmd(["tags", "merge", "crates", "productivity", "--into", "tools"]);
+    assert_cmd_snapshot!(merge_cmd.pass_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Will merge 2 tags into "tools":
+      - crates (1 bookmark) -> tools
+      - productivity (2 bookmarks) -> tools
+
+    Resulting tags:
+      - tools (4 bookmarks)
+
+    Number of tags: 4 -> 2
+
+    Type "y" to confirm.
+    merged 2 tags into "tools"
+
+    ----- stderr -----
+    "#);
+
+    let mut cmd = fx.cmd(["tags", "alias", "list"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cr -> tools
+    prod -> tools
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn merging_tags_with_mixed_case_source_tags_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags", "merge", "Crates", " RUST ", "--into", "tools", "--yes",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    merged 2 tags into "tools"
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    productivity
+    tools
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn merging_tags_into_a_new_tag_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "merge",
+        "crates",
+        "rust",
+        "--into",
+        "rust-lang",
+        "--yes",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    merged 2 tags into "rust-lang"
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    productivity (2 bookmarks)
+    rust-lang (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn merging_tags_in_dry_run_mode_doesnt_change_anything() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "merge",
+        "productivity",
+        "tools",
+        "--into",
+        "cli",
+        "--dry-run",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Would merge 2 tags into "cli":
+      - productivity (2 bookmarks) -> cli
+      - tools (3 bookmarks) -> cli
+
+    Resulting tags:
+      - cli (3 bookmarks)
+
+    Number of tags: 4 -> 3
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    crates (1 bookmark)
+    productivity (2 bookmarks)
+    rust (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn renaming_tags_matching_a_pattern_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "rename",
+        "--pattern",
+        "^(rust|crates)$",
+        "--to",
+        "lang/$1",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd.pass_stdin("y\n"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Will rename 2 tags:
+      - crates (1 bookmark) -> lang/crates
+      - rust (1 bookmark) -> lang/rust
+
+    Resulting tags:
+      - lang/crates (1 bookmark)
+      - lang/rust (1 bookmark)
+
+    Number of tags: 4 -> 4
+
+    Type "y" to confirm.
+    renamed 2 tags
+
+    ----- stderr -----
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    lang/crates (1 bookmark)
+    lang/rust (1 bookmark)
+    productivity (2 bookmarks)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn renaming_tags_matching_a_pattern_in_dry_run_mode_doesnt_change_anything() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "rename", "--pattern", "s$", "--to", "", "--dry-run"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Would rename 2 tags:
+      - crates (1 bookmark) -> crate
+      - tools (3 bookmarks) -> tool
+
+    Resulting tags:
+      - crate (1 bookmark)
+      - tool (3 bookmarks)
+
+    Number of tags: 4 -> 4
+
+    ----- stderr -----
+    ");
+
+    let mut list_cmd = fx.cmd(["tags", "list", "--show-stats"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    crates (1 bookmark)
+    productivity (2 bookmarks)
+    rust (1 bookmark)
+    tools (3 bookmarks)
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn renaming_tags_matching_a_pattern_reports_when_nothing_would_change() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "rename",
+        "--pattern",
+        "^py-(.*)",
+        "--to",
+        "python-$1",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    no tags would change
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn suggesting_tags_for_a_uri_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "suggest", "https://www.github.com/dhth/ecsv"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    tools
+    productivity
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn suggesting_tags_uses_tags_that_appear_together() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "suggest",
+        "https://docs.rs/sqlx",
+        "--tags",
+        "rust",
+        "--format",
+        "json",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "tag": "crates",
+        "num_bookmarks": 1,
+        "out_of": 1,
+        "source": "co_occurrence"
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+}
+
+#[test]
+fn suggesting_tags_in_delimited_format_works() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "suggest",
+        "https://github.com/dhth/ecsv",
+        "--tags",
+        "productivity",
+        "--limit",
+        "1",
+        "--format",
+        "delimited",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    tag,num_bookmarks,out_of,source
+    tools,3,3,domain
+
+    ----- stderr -----
+    ");
+}
+
 //------------//
 //  FAILURES  //
 //------------//
 
 #[test]
-fn deleting_tags_fails_if_tag_doesnt_exist() {
+fn deleting_tags_fails_if_tag_doesnt_exist() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "delete", "--yes", "productivity", "absent"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't delete tag(s): tags do not exist: ["absent"]
+    "#);
+}
+
+#[test]
+fn renaming_tags_fails_if_tag_doesnt_exist() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "rename", "absent", "target"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't rename tag: no such tag
+    ");
+}
+
+#[test]
+fn renaming_a_tag_fails_if_target_is_an_alias() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+    let mut alias_cmd = fx.cmd(["tags", "alias", "add", "util", "tools"]);
+    assert_cmd_snapshot!(alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "rename", "crates", "util"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't rename tag: "util" is an alias; tags can't be renamed to an alias
+    "#);
+}
+
+#[test]
+fn renaming_a_tag_subtree_fails_if_target_is_nested_under_source() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/nested-tags.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "rename", "--subtree", "lang", "lang/all"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't rename tag: tags can't be moved under themselves
+    ");
+}
+
+#[test]
+fn renaming_a_tag_subtree_fails_if_renamed_tags_would_be_too_deep() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/nested-tags.json"]);
+    assert_cmd_snapshot!(import_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "rename", "--subtree", "lang", "a/b/c/d"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't rename tag: tag "a/b/c/d/rust/async" would be invalid after renaming (valid regex: ^[a-zA-Z0-9_-]{1,30}(/[a-zA-Z0-9_-]{1,30}){0,4}$)
+    "#);
+
+    let mut list_cmd = fx.cmd(["tags", "list"]);
+    assert_cmd_snapshot!(list_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    lang/go
+    lang/rust
+    lang/rust/async
+    tools
+    tools/cli
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn adding_a_tag_alias_fails_if_tag_is_an_alias_itself() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut alias_cmd = fx.cmd(["tags", "alias", "add", "util", "tools"]);
+    assert_cmd_snapshot!(alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "alias", "add", "utl", "util"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't manage tag aliases: "util" is an alias itself; aliases can only point to canonical tags
+    "#);
+}
+
+#[test]
+fn adding_a_tag_alias_fails_if_alias_is_invalid() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["tags", "alias", "add", "in valid", "tools"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't manage tag aliases: alias is invalid (valid regex: ^[a-zA-Z0-9_-]{1,30}(/[a-zA-Z0-9_-]{1,30}){0,4}$)
+    ");
+}
+
+#[test]
+fn removing_tag_aliases_fails_if_alias_doesnt_exist() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["tags", "alias", "remove", "absent"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't manage tag aliases: aliases do not exist: ["absent"]
+    "#);
+}
+
+#[test]
+fn changing_tags_in_bulk_fails_for_an_invalid_query() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["tags", "add", "cli", "--where", "(tag:tools", "--yes"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't change tags: query is invalid: parenthesis at position 1 is never closed
+
+    Suggestion: combine terms, quoted phrases, and qualifiers (tag:, title:, uri:, site:, before:, after:), eg.
+        bmm search 'rust tag:cli -tag:old (site:github.com OR title:"command line")'
+    "#);
+}
+
+#[test]
+fn changing_tags_in_bulk_fails_for_invalid_tags() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "tags",
+        "add",
+        "cli,in valid",
+        "--where",
+        "tag:tools",
+        "--yes",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't change tags: tags ["in valid"] are invalid (valid regex: ^[a-zA-Z0-9_-]{1,30}(/[a-zA-Z0-9_-]{1,30}){0,4}$)
+    "#);
+}
+
+#[test]
+fn merging_tags_fails_if_a_source_tag_doesnt_exist() {
     // GIVEN
     let fx = Fixture::new();
     let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
@@ -165,7 +1668,9 @@ fn deleting_tags_fails_if_tag_doesnt_exist() {
     ----- stderr -----
     ");
 
-    let mut cmd = fx.cmd(["tags", "delete", "--yes", "productivity", "absent"]);
+    let mut cmd = fx.cmd([
+        "tags", "merge", "crates", "absent", "--into", "rust", "--yes",
+    ]);
 
     // WHEN
     // THEN
@@ -175,12 +1680,12 @@ fn deleting_tags_fails_if_tag_doesnt_exist() {
     ----- stdout -----
 
     ----- stderr -----
-    Error: couldn't delete tag(s): tags do not exist: ["absent"]
+    Error: couldn't merge tags: tags do not exist: ["absent"]
     "#);
 }
 
 #[test]
-fn renaming_tags_fails_if_tag_doesnt_exist() {
+fn merging_tags_fails_if_target_is_an_alias() {
     // GIVEN
     let fx = Fixture::new();
     let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
@@ -190,19 +1695,207 @@ fn renaming_tags_fails_if_tag_doesnt_exist() {
     ----- stdout -----
     imported 4 bookmarks
 
+    ----- stderr -----
+    ");
+    let mut alias_cmd = fx.cmd(["tags", "alias", "add", "util", "tools"]);
+    assert_cmd_snapshot!(alias_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
     ----- stderr -----
     ");
 
-    let mut cmd = fx.cmd(["tags", "rename", "absent", "target"]);
+    let mut cmd = fx.cmd(["tags", "merge", "crates", "rust", "--into", "util", "--yes"]);
 
     // WHEN
     // THEN
-    assert_cmd_snapshot!(cmd, @r"
+    assert_cmd_snapshot!(cmd, @r#"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
-    Error: couldn't rename tag: no such tag
+    Error: couldn't merge tags: "util" is an alias; tags can't be merged into an alias
+    "#);
+}
+
+#[test]
+fn merging_tags_fails_if_a_source_tag_is_invalid() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["tags", "merge", "in valid", "--into", "tools", "--yes"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't merge tags: tags ["in valid"] are invalid (valid regex: ^[a-zA-Z0-9_-]{1,30}(/[a-zA-Z0-9_-]{1,30}){0,4}$)
+    "#);
+}
+
+#[test]
+fn merging_tags_fails_if_only_the_target_tag_is_provided() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "merge", "rust", "--into", "rust", "--yes"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't merge tags: there are no tags to merge other than the target tag
+    ");
+}
+
+#[test]
+fn renaming_tags_matching_a_pattern_fails_for_an_invalid_regex() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "tags",
+        "rename",
+        "--pattern",
+        "(rust",
+        "--to",
+        "lang",
+        "--yes",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't rename tag: invalid regex: regex parse error:
+        (rust
+        ^
+    error: unclosed group
+    ");
+}
+
+#[test]
+fn renaming_tags_matching_a_pattern_fails_if_renamed_tags_would_be_invalid() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut import_cmd = fx.cmd(["import", "tests/static/import/valid.json"]);
+    assert_cmd_snapshot!(import_cmd, @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    imported 4 bookmarks
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd([
+        "tags",
+        "rename",
+        "--pattern",
+        "^rust$",
+        "--to",
+        "rust lang",
+        "--yes",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't rename tag: tag "rust lang" would be invalid after renaming (valid regex: ^[a-zA-Z0-9_-]{1,30}(/[a-zA-Z0-9_-]{1,30}){0,4}$)
+    "#);
+}
+
+#[test]
+fn renaming_tags_matching_a_pattern_fails_if_renames_overlap() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut save_cmd = fx.cmd(["save", "https://github.com/dhth/bmm", "--tags", "a,aa"]);
+    assert_cmd_snapshot!(save_cmd, @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let mut cmd = fx.cmd(["tags", "rename", "--pattern", "^a", "--to", "aa", "--yes"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't rename tag: tag "aa" would be renamed, and have other tags renamed to it at the same time
+    "#);
+}
+
+#[test]
+fn suggesting_tags_fails_for_an_invalid_uri() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd(["tags", "suggest", "github.com/dhth/ecsv"]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't suggest tags: couldn't parse provided uri value: relative URL without a base
     ");
 }
+
+#[test]
+fn suggesting_tags_fails_for_invalid_tags() {
+    // GIVEN
+    let fx = Fixture::new();
+    let mut cmd = fx.cmd([
+        "tags",
+        "suggest",
+        "https://github.com/dhth/ecsv",
+        "--tags",
+        "in valid",
+    ]);
+
+    // WHEN
+    // THEN
+    assert_cmd_snapshot!(cmd, @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: couldn't suggest tags: tags ["in valid"] are invalid (valid regex: ^[a-zA-Z0-9_-]{1,30}(/[a-zA-Z0-9_-]{1,30}){0,4}$)
+    "#);
+}